@@ -1,55 +1,360 @@
 mod console;
 pub use console::*;
 
+pub mod arrays;
+pub mod format;
+pub mod fs;
+pub mod hashmaps;
+pub mod json;
+pub mod math;
 pub mod numbers;
+pub mod process;
+pub mod strings;
+pub mod time;
 
-use crate::{Environment, Object};
+use crate::{Environment, Object, Objects};
 use sflynlang_parser::{
     ast::{DataType, DataTypes},
-    Error, Position,
+    Error, OrderedMap, Position,
 };
 
-pub fn check_builtin(
-    key: String,
+/// Evaluate a builtin method with its receiver and arguments. The methods that
+/// mutate the receiver (like `push`) update its shared values in place.
+pub type MethodEval = fn(
+    &Object,
+    Vec<Object>,
+    Position,
+    &mut Environment,
+) -> Result<Object, Error>;
+
+/// A method of the builtin data types, shared by the typechecker and the
+/// evaluator.
+pub struct Method {
+    /// Name of the method.
+    pub name: &'static str,
+
+    /// Get the function data type of the method when the receiver has it.
+    pub get_type: fn(&DataType, Position) -> Option<DataType>,
+
+    /// Evaluate the method.
+    pub eval: MethodEval,
+}
+
+/// The builtin methods registry.
+const METHODS: &[&[Method]] = &[
+    arrays::METHODS,
+    hashmaps::METHODS,
+    numbers::METHODS,
+    strings::METHODS,
+];
+
+/// Get a builtin method of the receiver data type and its function data type.
+pub fn get_method(
+    receiver: &DataType,
+    key: &str,
+    position: Position,
+) -> Option<(&'static Method, DataType)> {
+    METHODS
+        .iter()
+        .flat_map(|methods| methods.iter())
+        .filter(|method| method.name == key)
+        .find_map(|method| {
+            (method.get_type)(receiver, position.clone())
+                .map(|method_type| (method, method_type))
+        })
+}
+
+/// Evaluate a function of a builtin namespace with its arguments.
+pub type FunctionEval =
+    fn(Vec<Object>, Position, &mut Environment) -> Result<Object, Error>;
+
+/// A function of a builtin namespace (like `Math.sqrt`).
+#[derive(Debug)]
+pub struct Function {
+    /// Name of the function.
+    pub name: &'static str,
+
+    /// Get the function data type.
+    pub get_type: fn(Position) -> DataType,
+
+    /// Evaluate the function.
+    pub eval: FunctionEval,
+}
+
+/// A builtin namespace of functions and number constants (like `Math`).
+pub struct Namespace {
+    /// Name of the namespace.
+    pub name: &'static str,
+
+    /// Functions of the namespace.
+    pub functions: &'static [Function],
+
+    /// Number constants of the namespace.
+    pub constants: &'static [(&'static str, f64)],
+}
+
+/// The builtin namespaces registry.
+const NAMESPACES: &[&Namespace] = &[
+    &fs::NAMESPACE,
+    &json::NAMESPACE,
+    &math::NAMESPACE,
+    &process::NAMESPACE,
+    &time::NAMESPACE,
+];
+
+/// Check if the name is a builtin namespace.
+pub fn is_namespace(name: &str) -> bool {
+    NAMESPACES.iter().any(|namespace| namespace.name == name)
+}
+
+/// Get the hashmap data type of a builtin namespace for the typechecker.
+pub fn get_namespace_type(name: &str) -> Option<DataType> {
+    let namespace =
+        NAMESPACES.iter().find(|namespace| namespace.name == name)?;
+    let position = Position::new(0, 0, 1, 1);
+    let mut data_types: OrderedMap<Box<DataType>> = OrderedMap::new();
+
+    for function in namespace.functions.iter() {
+        data_types.insert(
+            String::from(function.name),
+            Box::new((function.get_type)(position.clone())),
+        );
+    }
+
+    for (constant_name, _) in namespace.constants.iter() {
+        data_types.insert(
+            String::from(*constant_name),
+            Box::new(DataType::new(position.clone(), DataTypes::Number)),
+        );
+    }
+
+    Some(DataType::new(position, DataTypes::HashMap(data_types)))
+}
+
+/// Get the definition of a builtin data type (like `Date`).
+pub fn get_type_definition(name: &str) -> Option<DataType> {
+    match name {
+        "Date" => Some(time::get_date_type(Position::new(0, 0, 1, 1))),
+        _ => None,
+    }
+}
+
+/// Get the hashmap object of a builtin namespace for the evaluator.
+pub fn get_namespace_object(name: &str) -> Option<Object> {
+    let namespace =
+        NAMESPACES.iter().find(|namespace| namespace.name == name)?;
+    let position = Position::new(0, 0, 1, 1);
+    let mut objects: OrderedMap<Object> = OrderedMap::new();
+
+    for function in namespace.functions.iter() {
+        objects.insert(
+            String::from(function.name),
+            Object::new(position.clone(), Objects::Builtin(function)),
+        );
+    }
+
+    for (constant_name, value) in namespace.constants.iter() {
+        objects.insert(
+            String::from(*constant_name),
+            Object::new(position.clone(), Objects::Number(*value)),
+        );
+    }
+
+    Some(Object::new(position, Objects::new_hashmap(objects)))
+}
+
+/// Get the string value of a builtin argument.
+pub fn get_string_argument(
+    arguments: &[Object],
+    index: usize,
+    name: &str,
+    position: Position,
+) -> Result<String, Error> {
+    let argument = arguments.get(index).ok_or_else(|| {
+        Error::new_missing_argument(position, String::from(name))
+    })?;
+
+    argument.get_node().get_string().ok_or_else(|| {
+        Error::new_expect_type(
+            argument.get_position(),
+            "string",
+            &argument.to_data_type().to_string(),
+        )
+    })
+}
+
+/// Get the number value of a builtin argument.
+pub fn get_number_argument(
+    arguments: &[Object],
+    index: usize,
+    name: &str,
+    position: Position,
+) -> Result<f64, Error> {
+    let argument = arguments.get(index).ok_or_else(|| {
+        Error::new_missing_argument(position, String::from(name))
+    })?;
+
+    argument.get_node().get_number().ok_or_else(|| {
+        Error::new_expect_type(
+            argument.get_position(),
+            "number",
+            &argument.to_data_type().to_string(),
+        )
+    })
+}
+
+/// Get the index between zero and the length of an index that can start from
+/// the end (like `-1`), ignoring its decimals.
+pub fn get_index(index: f64, length: usize) -> usize {
+    if index < 0.0 {
+        return length.saturating_sub(-index as usize);
+    }
+
+    (index as usize).min(length)
+}
+
+/// Create a function data type for a builtin.
+pub fn new_function_type(
     position: Position,
     arguments: Vec<DataType>,
+    return_type: DataTypes,
+) -> DataType {
+    DataType::new(
+        position.clone(),
+        DataTypes::Function(
+            arguments,
+            Box::new(DataType::new(position, return_type)),
+        ),
+    )
+}
+
+/// Create a function argument data type for a builtin.
+pub fn new_argument(
+    position: Position,
+    name: &str,
+    data_type: DataTypes,
+    is_optional: bool,
+) -> DataType {
+    DataType::new(
+        position.clone(),
+        DataTypes::Argument {
+            name: String::from(name),
+            data_type: Box::new(DataType::new(position, data_type)),
+            is_optional,
+        },
+    )
+}
+
+/// Get the function data type of a builtin.
+pub fn get_builtin_type(
+    key: String,
+    position: Position,
 ) -> Result<DataType, Error> {
     match key.as_str() {
-        "print" | "debug" => {
-            if arguments.len() != 1 {
-                return Err(Error::new_expect_arguments(
-                    position,
-                    1,
-                    arguments.len(),
-                ));
-            }
-
-            if arguments[0].node == DataTypes::String {
-                Ok(DataType::new(position, DataTypes::Void))
-            } else {
-                Err(Error::new_expect_type(
-                    position,
-                    "string",
-                    &arguments[0].node.to_string(),
-                ))
-            }
+        // (...values: unknown[], separator?: string) => void
+        "print" | "println" | "eprint" | "eprintln" | "debug" => {
+            Ok(new_function_type(
+                position.clone(),
+                vec![
+                    DataType::new(
+                        position.clone(),
+                        DataTypes::Spread(Box::new(new_argument(
+                            position.clone(),
+                            "values",
+                            DataTypes::Array(Box::new(DataType::new(
+                                position.clone(),
+                                DataTypes::Unknown,
+                            ))),
+                            false,
+                        ))),
+                    ),
+                    new_argument(
+                        position,
+                        "separator",
+                        DataTypes::String,
+                        true,
+                    ),
+                ],
+                DataTypes::Void,
+            ))
+        }
+
+        // (template: string, ...values: unknown[]) => string
+        "format" => Ok(new_function_type(
+            position.clone(),
+            vec![
+                new_argument(
+                    position.clone(),
+                    "template",
+                    DataTypes::String,
+                    false,
+                ),
+                DataType::new(
+                    position.clone(),
+                    DataTypes::Spread(Box::new(new_argument(
+                        position.clone(),
+                        "values",
+                        DataTypes::Array(Box::new(DataType::new(
+                            position,
+                            DataTypes::Unknown,
+                        ))),
+                        false,
+                    ))),
+                ),
+            ],
+            DataTypes::String,
+        )),
+
+        // (prompt: string) => string
+        "input" => Ok(new_function_type(
+            position.clone(),
+            vec![new_argument(position, "prompt", DataTypes::String, false)],
+            DataTypes::String,
+        )),
+
+        // () => string?
+        "readLine" => Ok(new_function_type(
+            position.clone(),
+            Vec::new(),
+            DataTypes::Option(Box::new(DataType::new(
+                position,
+                DataTypes::String,
+            ))),
+        )),
+
+        // () => string
+        "readAll" => {
+            Ok(new_function_type(position, Vec::new(), DataTypes::String))
         }
 
         _ => Err(Error::new_unknown_identifier(position, key)),
     }
 }
 
+/// Evaluate a builtin function with its positional (including the spread
+/// values) and named arguments.
 pub fn eval_builtin(
     key: String,
     position: Position,
     arguments: Vec<Object>,
+    named_arguments: OrderedMap<Object>,
     environment: &mut Environment,
 ) -> Result<Object, Error> {
     match key.as_str() {
-        "print" => console::print(arguments, position),
-        "debug" => {
-            console::debug(arguments, position, environment.is_debug_mode())
-        }
+        "print" => console::print(arguments, named_arguments, position),
+        "println" => console::println(arguments, named_arguments, position),
+        "eprint" => console::eprint(arguments, named_arguments, position),
+        "eprintln" => console::eprintln(arguments, named_arguments, position),
+        "debug" => console::debug(
+            arguments,
+            named_arguments,
+            position,
+            environment.is_debug_mode(),
+        ),
+        "format" => format::format(arguments, named_arguments, position),
+        "input" => console::input(arguments, position, environment),
+        "readLine" => console::read_line(position, environment),
+        "readAll" => console::read_all(position, environment),
         _ => Err(Error::new_unknown_token(position)),
     }
 }
@@ -0,0 +1,987 @@
+use crate::{
+    builtins::{
+        get_index, get_number_argument, get_string_argument, new_argument,
+        new_function_type, Method,
+    },
+    evaluator, Environment, Object, Objects,
+};
+use sflynlang_parser::{
+    ast::{DataType, DataTypes},
+    Error, Position,
+};
+use std::{cell::RefCell, cmp::Ordering, rc::Rc};
+
+pub const METHODS: &[Method] = &[
+    Method {
+        name: "concat",
+        get_type: concat_type,
+        eval: concat,
+    },
+    Method {
+        name: "every",
+        get_type: every_type,
+        eval: every,
+    },
+    Method {
+        name: "filter",
+        get_type: filter_type,
+        eval: filter,
+    },
+    Method {
+        name: "find",
+        get_type: find_type,
+        eval: find,
+    },
+    Method {
+        name: "includes",
+        get_type: includes_type,
+        eval: includes,
+    },
+    Method {
+        name: "join",
+        get_type: join_type,
+        eval: join,
+    },
+    Method {
+        name: "length",
+        get_type: length_type,
+        eval: length,
+    },
+    Method {
+        name: "map",
+        get_type: map_type,
+        eval: map,
+    },
+    Method {
+        name: "pop",
+        get_type: pop_type,
+        eval: pop,
+    },
+    Method {
+        name: "push",
+        get_type: push_type,
+        eval: push,
+    },
+    Method {
+        name: "reduce",
+        get_type: reduce_type,
+        eval: reduce,
+    },
+    Method {
+        name: "reverse",
+        get_type: reverse_type,
+        eval: reverse,
+    },
+    Method {
+        name: "slice",
+        get_type: slice_type,
+        eval: slice,
+    },
+    Method {
+        name: "some",
+        get_type: some_type,
+        eval: some,
+    },
+    Method {
+        name: "sort",
+        get_type: sort_type,
+        eval: sort,
+    },
+];
+
+/// Name of the type parameter of the generic methods (like `map`).
+const TYPE_PARAMETER: &str = "U";
+
+/// Get the items data type when the receiver is an array.
+fn get_items_type(receiver: &DataType) -> Option<DataTypes> {
+    receiver.node.get_array().map(|items_type| items_type.node)
+}
+
+/// Get the function data type of an array method.
+fn new_method_type(
+    position: Position,
+    arguments: Vec<(&str, DataTypes, bool)>,
+    return_type: DataTypes,
+) -> DataType {
+    new_function_type(
+        position.clone(),
+        arguments
+            .into_iter()
+            .map(|(name, data_type, is_optional)| {
+                new_argument(position.clone(), name, data_type, is_optional)
+            })
+            .collect(),
+        return_type,
+    )
+}
+
+/// Get the function data type of a method with the `U` type parameter.
+fn new_generic_method_type(
+    position: Position,
+    arguments: Vec<(&str, DataTypes, bool)>,
+    return_type: DataTypes,
+) -> DataType {
+    DataType::new(
+        position.clone(),
+        DataTypes::Parameterized(
+            vec![DataType::new(position.clone(), new_type_parameter())],
+            Box::new(new_method_type(position, arguments, return_type)),
+        ),
+    )
+}
+
+/// Get the function data type of a callback argument.
+fn new_callback_type(
+    position: Position,
+    arguments: Vec<(&str, DataTypes)>,
+    return_type: DataTypes,
+) -> DataTypes {
+    new_function_type(
+        position.clone(),
+        arguments
+            .into_iter()
+            .map(|(name, data_type)| {
+                new_argument(position.clone(), name, data_type, false)
+            })
+            .collect(),
+        return_type,
+    )
+    .node
+}
+
+/// Get the `U` type parameter.
+fn new_type_parameter() -> DataTypes {
+    DataTypes::Parameter(String::from(TYPE_PARAMETER), None)
+}
+
+/// Get the array data type of the items data type.
+fn new_array_type(position: Position, items_type: DataTypes) -> DataTypes {
+    DataTypes::Array(Box::new(DataType::new(position, items_type)))
+}
+
+/// Get the items of the receiver.
+fn get_receiver(receiver: &Object) -> Result<Vec<Object>, Error> {
+    receiver.get_node().get_array().ok_or_else(|| {
+        Error::new_expect_type(
+            receiver.get_position(),
+            "array",
+            &receiver.to_data_type().to_string(),
+        )
+    })
+}
+
+/// Get the shared items of the receiver to mutate them.
+fn get_receiver_ref(
+    receiver: &Object,
+) -> Result<Rc<RefCell<Vec<Object>>>, Error> {
+    receiver.get_node().get_array_ref().ok_or_else(|| {
+        Error::new_expect_type(
+            receiver.get_position(),
+            "array",
+            &receiver.to_data_type().to_string(),
+        )
+    })
+}
+
+/// Get the function object of a callback argument.
+fn get_callback_argument(
+    arguments: &[Object],
+    index: usize,
+    name: &str,
+    position: Position,
+) -> Result<Object, Error> {
+    let argument = arguments.get(index).ok_or_else(|| {
+        Error::new_missing_argument(position, String::from(name))
+    })?;
+
+    if argument.get_node().get_function().is_none()
+        && argument.get_node().get_builtin().is_none()
+    {
+        return Err(Error::new_expect_type(
+            argument.get_position(),
+            "function",
+            &argument.to_data_type().to_string(),
+        ));
+    }
+
+    Ok(argument.clone())
+}
+
+/// Call the callback with the item and check if it returns `true`.
+fn is_callback_trusthy(
+    position: Position,
+    callback: &Object,
+    item: &Object,
+    environment: &mut Environment,
+) -> Result<bool, Error> {
+    Ok(evaluator::call_function(
+        position,
+        callback,
+        vec![item.clone()],
+        environment,
+    )?
+    .get_node()
+    .is_trusthy())
+}
+
+/// Get the array object of the items.
+fn new_array(position: Position, items: Vec<Object>) -> Object {
+    Object::new(position, Objects::new_array(items))
+}
+
+/// Get the `null` object of a missing item.
+fn new_null(position: Position) -> Object {
+    Object::new(
+        position.clone(),
+        Objects::Null(Box::new(Object::new(position, Objects::Unknown))),
+    )
+}
+
+/// Compare the numbers in a total order (like the IEEE 754 `totalOrder`
+/// predicate), placing the positive `NaN` values after the infinity.
+fn compare_numbers(value: f64, other_value: f64) -> Ordering {
+    let get_key = |value: f64| {
+        let bits = value.to_bits() as i64;
+
+        bits ^ (((bits >> 63) as u64) >> 1) as i64
+    };
+
+    get_key(value).cmp(&get_key(other_value))
+}
+
+/// Check if the items of the data type have an order without comparator.
+fn is_sortable_type(data_type: &DataTypes) -> bool {
+    match data_type {
+        DataTypes::Argument { data_type, .. } => {
+            is_sortable_type(&data_type.node)
+        }
+        DataTypes::Array(value_type) | DataTypes::Option(value_type) => {
+            is_sortable_type(&value_type.node)
+        }
+        DataTypes::Boolean
+        | DataTypes::Number
+        | DataTypes::NumberLiteral(_)
+        | DataTypes::String
+        | DataTypes::StringLiteral(_) => true,
+        DataTypes::Tuple(values) | DataTypes::Union(values) => {
+            values.iter().all(|value| is_sortable_type(&value.node))
+        }
+        _ => false,
+    }
+}
+
+/// Get the rank of the kind of an item, which orders the items of different
+/// kinds (like the members of an union).
+fn get_item_rank(item: &Object) -> u8 {
+    match item.get_node() {
+        Objects::Null(_) => 0,
+        Objects::Boolean(_) => 1,
+        Objects::Number(_) => 2,
+        Objects::String(_) => 3,
+        _ => 4,
+    }
+}
+
+/// Compare the items when there is no comparator: `null` goes first, then
+/// the booleans (`false` before `true`), the numbers and the strings by their
+/// values and the arrays and the tuples by their items.
+fn compare_items(item: &Object, other: &Object) -> Ordering {
+    match (item.get_node(), other.get_node()) {
+        (Objects::Boolean(value), Objects::Boolean(other_value)) => {
+            value.cmp(&other_value)
+        }
+        (Objects::Number(value), Objects::Number(other_value)) => {
+            compare_numbers(value, other_value)
+        }
+        (Objects::String(value), Objects::String(other_value)) => {
+            value.cmp(&other_value)
+        }
+        (Objects::Array(values), Objects::Array(other_values)) => {
+            compare_values(&values.borrow(), &other_values.borrow())
+        }
+        (Objects::Tuple(values), Objects::Tuple(other_values)) => {
+            compare_values(&values, &other_values)
+        }
+        _ => get_item_rank(item).cmp(&get_item_rank(other)),
+    }
+}
+
+/// Compare the values of two arrays or tuples by their items, then by their
+/// lengths.
+fn compare_values(values: &[Object], other_values: &[Object]) -> Ordering {
+    values
+        .iter()
+        .zip(other_values.iter())
+        .map(|(value, other_value)| compare_items(value, other_value))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| values.len().cmp(&other_values.len()))
+}
+
+/// Sort the items with a stable merge sort, which accepts comparisons that
+/// are not a consistent order (like random ones) and stops at the first
+/// comparison error.
+fn merge_sort<F>(
+    items: Vec<Object>,
+    compare: &mut F,
+) -> Result<Vec<Object>, Error>
+where
+    F: FnMut(&Object, &Object) -> Result<Ordering, Error>,
+{
+    if items.len() < 2 {
+        return Ok(items);
+    }
+
+    let mut left = items;
+    let right = left.split_off(left.len() / 2);
+    let left = merge_sort(left, compare)?;
+    let right = merge_sort(right, compare)?;
+    let mut sorted: Vec<Object> = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    while let (Some(item), Some(other)) = (left.peek(), right.peek()) {
+        // The left item goes first when they are equal to keep the order.
+        let next = if compare(other, item)? == Ordering::Less {
+            right.next()
+        } else {
+            left.next()
+        };
+
+        sorted.extend(next);
+    }
+
+    sorted.extend(left);
+    sorted.extend(right);
+
+    Ok(sorted)
+}
+
+/// (other: T[]) => T[]
+fn concat_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position.clone(),
+        vec![(
+            "other",
+            new_array_type(position.clone(), items_type.clone()),
+            false,
+        )],
+        new_array_type(position, items_type),
+    ))
+}
+
+fn concat(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let mut items = get_receiver(receiver)?;
+    let other = arguments.first().ok_or_else(|| {
+        Error::new_missing_argument(position.clone(), String::from("other"))
+    })?;
+
+    items.extend(get_receiver(other)?);
+
+    Ok(new_array(position, items))
+}
+
+/// (callback: (value: T) => boolean) => boolean
+fn every_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position.clone(),
+        vec![(
+            "callback",
+            new_callback_type(
+                position,
+                vec![("value", items_type)],
+                DataTypes::Boolean,
+            ),
+            false,
+        )],
+        DataTypes::Boolean,
+    ))
+}
+
+fn every(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let callback =
+        get_callback_argument(&arguments, 0, "callback", position.clone())?;
+
+    for item in items.iter() {
+        if !is_callback_trusthy(position.clone(), &callback, item, environment)?
+        {
+            return Ok(Object::new(position, Objects::Boolean(false)));
+        }
+    }
+
+    Ok(Object::new(position, Objects::Boolean(true)))
+}
+
+/// (callback: (value: T) => boolean) => T[]
+fn filter_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position.clone(),
+        vec![(
+            "callback",
+            new_callback_type(
+                position.clone(),
+                vec![("value", items_type.clone())],
+                DataTypes::Boolean,
+            ),
+            false,
+        )],
+        new_array_type(position, items_type),
+    ))
+}
+
+fn filter(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let callback =
+        get_callback_argument(&arguments, 0, "callback", position.clone())?;
+    let mut filtered_items: Vec<Object> = Vec::new();
+
+    for item in items.into_iter() {
+        if is_callback_trusthy(position.clone(), &callback, &item, environment)?
+        {
+            filtered_items.push(item);
+        }
+    }
+
+    Ok(new_array(position, filtered_items))
+}
+
+/// (callback: (value: T) => boolean) => T?
+fn find_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position.clone(),
+        vec![(
+            "callback",
+            new_callback_type(
+                position.clone(),
+                vec![("value", items_type.clone())],
+                DataTypes::Boolean,
+            ),
+            false,
+        )],
+        DataTypes::Option(Box::new(DataType::new(position, items_type))),
+    ))
+}
+
+/// Get the first item for which the callback returns `true` or `null` when
+/// there is none.
+fn find(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let callback =
+        get_callback_argument(&arguments, 0, "callback", position.clone())?;
+
+    for item in items.into_iter() {
+        if is_callback_trusthy(position.clone(), &callback, &item, environment)?
+        {
+            return Ok(item);
+        }
+    }
+
+    Ok(new_null(position))
+}
+
+/// (value: T) => boolean
+fn includes_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position,
+        vec![("value", items_type, false)],
+        DataTypes::Boolean,
+    ))
+}
+
+fn includes(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let value = arguments.first().ok_or_else(|| {
+        Error::new_missing_argument(position.clone(), String::from("value"))
+    })?;
+
+    Ok(Object::new(
+        position,
+        Objects::Boolean(
+            items.iter().any(|item| item.get_node() == value.get_node()),
+        ),
+    ))
+}
+
+/// (separator: string) => string
+fn join_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position,
+        vec![("separator", DataTypes::String, false)],
+        DataTypes::String,
+    ))
+}
+
+fn join(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let separator =
+        get_string_argument(&arguments, 0, "separator", position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::String(
+            items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(separator.as_str()),
+        ),
+    ))
+}
+
+/// () => number
+fn length_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    get_items_type(receiver)?;
+
+    Some(new_method_type(position, Vec::new(), DataTypes::Number))
+}
+
+fn length(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+
+    Ok(Object::new(position, Objects::Number(items.len() as f64)))
+}
+
+/// <U>(callback: (value: T) => U) => U[]
+fn map_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_generic_method_type(
+        position.clone(),
+        vec![(
+            "callback",
+            new_callback_type(
+                position.clone(),
+                vec![("value", items_type)],
+                new_type_parameter(),
+            ),
+            false,
+        )],
+        new_array_type(position, new_type_parameter()),
+    ))
+}
+
+fn map(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let callback =
+        get_callback_argument(&arguments, 0, "callback", position.clone())?;
+    let mut mapped_items: Vec<Object> = Vec::new();
+
+    for item in items.into_iter() {
+        mapped_items.push(evaluator::call_function(
+            position.clone(),
+            &callback,
+            vec![item],
+            environment,
+        )?);
+    }
+
+    Ok(new_array(position, mapped_items))
+}
+
+/// () => T?
+fn pop_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position.clone(),
+        Vec::new(),
+        DataTypes::Option(Box::new(DataType::new(position, items_type))),
+    ))
+}
+
+/// Remove the last item of the receiver and get it or `null` when the array
+/// is empty.
+fn pop(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let item = get_receiver_ref(receiver)?.borrow_mut().pop();
+
+    Ok(item.unwrap_or_else(|| new_null(position)))
+}
+
+/// (value: T) => number
+fn push_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position,
+        vec![("value", items_type, false)],
+        DataTypes::Number,
+    ))
+}
+
+/// Add the value to the end of the receiver and get its new length.
+fn push(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver_ref(receiver)?;
+    let value = arguments.first().ok_or_else(|| {
+        Error::new_missing_argument(position.clone(), String::from("value"))
+    })?;
+
+    items.borrow_mut().push(value.clone());
+
+    let length = items.borrow().len() as f64;
+
+    Ok(Object::new(position, Objects::Number(length)))
+}
+
+/// <U>(callback: (accumulator: U, value: T) => U, initial: U) => U
+fn reduce_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_generic_method_type(
+        position.clone(),
+        vec![
+            (
+                "callback",
+                new_callback_type(
+                    position,
+                    vec![
+                        ("accumulator", new_type_parameter()),
+                        ("value", items_type),
+                    ],
+                    new_type_parameter(),
+                ),
+                false,
+            ),
+            ("initial", new_type_parameter(), false),
+        ],
+        new_type_parameter(),
+    ))
+}
+
+fn reduce(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let callback =
+        get_callback_argument(&arguments, 0, "callback", position.clone())?;
+    let mut accumulator = arguments.get(1).cloned().ok_or_else(|| {
+        Error::new_missing_argument(position.clone(), String::from("initial"))
+    })?;
+
+    for item in items.into_iter() {
+        accumulator = evaluator::call_function(
+            position.clone(),
+            &callback,
+            vec![accumulator, item],
+            environment,
+        )?;
+    }
+
+    Ok(accumulator)
+}
+
+/// () => T[]
+fn reverse_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position.clone(),
+        Vec::new(),
+        new_array_type(position, items_type),
+    ))
+}
+
+/// Get a copy of the receiver with the items in the reverse order.
+fn reverse(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let mut items = get_receiver(receiver)?;
+
+    items.reverse();
+
+    Ok(new_array(position, items))
+}
+
+/// (start: number, end?: number) => T[]
+fn slice_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+
+    Some(new_method_type(
+        position.clone(),
+        vec![
+            ("start", DataTypes::Number, false),
+            ("end", DataTypes::Number, true),
+        ],
+        new_array_type(position, items_type),
+    ))
+}
+
+/// Get the items between the start and the end indexes, counting the negative
+/// indexes from the end.
+fn slice(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let length = items.len();
+
+    let start = get_index(
+        get_number_argument(&arguments, 0, "start", position.clone())?,
+        length,
+    );
+
+    let end = if arguments.len() > 1 {
+        get_index(
+            get_number_argument(&arguments, 1, "end", position.clone())?,
+            length,
+        )
+    } else {
+        length
+    };
+
+    Ok(new_array(
+        position,
+        items
+            .into_iter()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .collect(),
+    ))
+}
+
+/// (callback: (value: T) => boolean) => boolean
+fn some_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    every_type(receiver, position)
+}
+
+fn some(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+    let callback =
+        get_callback_argument(&arguments, 0, "callback", position.clone())?;
+
+    for item in items.iter() {
+        if is_callback_trusthy(position.clone(), &callback, item, environment)?
+        {
+            return Ok(Object::new(position, Objects::Boolean(true)));
+        }
+    }
+
+    Ok(Object::new(position, Objects::Boolean(false)))
+}
+
+/// (comparator?: (a: T, b: T) => number) => T[]
+///
+/// The comparator is required when the items do not have an order.
+fn sort_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let items_type = get_items_type(receiver)?;
+    let is_optional = is_sortable_type(&items_type);
+
+    Some(new_method_type(
+        position.clone(),
+        vec![(
+            "comparator",
+            new_callback_type(
+                position.clone(),
+                vec![("a", items_type.clone()), ("b", items_type.clone())],
+                DataTypes::Number,
+            ),
+            is_optional,
+        )],
+        new_array_type(position, items_type),
+    ))
+}
+
+/// Get a sorted copy of the receiver. The comparator returns a negative number
+/// when `a` goes before `b`, a positive one when it goes after and zero when
+/// they are equal. Without comparator, the items are sorted by their values.
+fn sort(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let items = get_receiver(receiver)?;
+
+    if arguments.is_empty() {
+        let items = merge_sort(items, &mut |item, other| {
+            Ok(compare_items(item, other))
+        })?;
+
+        return Ok(new_array(position, items));
+    }
+
+    let comparator =
+        get_callback_argument(&arguments, 0, "comparator", position.clone())?;
+    let items = merge_sort(items, &mut |item, other| {
+        let result = evaluator::call_function(
+            position.clone(),
+            &comparator,
+            vec![item.clone(), other.clone()],
+            environment,
+        )?;
+
+        // A `NaN` result keeps the order of the items.
+        Ok(result
+            .get_node()
+            .get_number()
+            .and_then(|result| result.partial_cmp(&0.0))
+            .unwrap_or(Ordering::Equal))
+    })?;
+
+    Ok(new_array(position, items))
+}
+
+#[test]
+fn test_array_methods() {
+    use crate::test_utils::{check_with, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    let check_method = |code: &str| {
+        check_with(
+            "const values = [1, 2, 3];\nfunc double(value: number): number { return value * 2; }\nfunc describe(value: number): string { return value.toString(); }",
+            code,
+        )
+    };
+
+    assert!(
+        check_method("const doubles: number[] = values.map(double);").is_ok()
+    );
+    assert!(
+        check_method("const names: string[] = values.map(describe);").is_ok()
+    );
+    assert!(check_method("const last: number? = values.pop();").is_ok());
+    assert!(check_method("const size: number = values.push(4);").is_ok());
+    assert_error!(
+        check_method("const names: number[] = values.map(describe);"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_method("values.filter(double);"),
+        ErrorType::ExpectType(_, _)
+    );
+
+    // The items without an order need a comparator.
+    assert_error!(
+        check_method("const users = [{ name: 'Sflyn' }];\nusers.sort();"),
+        ErrorType::MissingArgument(_)
+    );
+
+    let mut environment = run_code(
+        "func add(total: number, value: number): number { return total + value; }\nfunc isEven(value: number): boolean { return value % 2 == 0; }\nfunc descending(a: number, b: number): number { return b - a; }\nfunc byLength(a: string, b: string): number { return a.length() - b.length(); }\nconst values = [3, 1, 4, 1, 5];\nconst total = values.reduce(add, 0);\nconst evens = values.filter(isEven);\nconst found = values.find(isEven);\nconst descended = values.sort(descending);\nconst ascended = values.sort();\nconst stable = ['bb', 'a', 'cc', 'd'].sort(byLength);\nconst flags = [true, false, true].sort();\nconst nested = [[2, 1], [1, 5], [1], [2]].sort();\nconst numbers = [0.5, -1, 10, -0.25].sort();",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("total"), Some(String::from("14")));
+    assert_eq!(get_value("evens"), Some(String::from("[4]")));
+    assert_eq!(get_value("found"), Some(String::from("4")));
+    assert_eq!(
+        get_value("descended"),
+        Some(String::from("[5, 4, 3, 1, 1]"))
+    );
+    assert_eq!(get_value("ascended"), Some(String::from("[1, 1, 3, 4, 5]")));
+    assert_eq!(
+        get_value("stable"),
+        Some(String::from("[\"a\", \"d\", \"bb\", \"cc\"]"))
+    );
+    assert_eq!(
+        get_value("flags"),
+        Some(String::from("[false, true, true]"))
+    );
+    assert_eq!(
+        get_value("nested"),
+        Some(String::from("[[1], [1, 5], [2], [2, 1]]"))
+    );
+    assert_eq!(
+        get_value("numbers"),
+        Some(String::from("[-1, -0.25, 0.5, 10]"))
+    );
+}
+
+#[test]
+fn test_sort_comparators() {
+    use crate::test_utils::{get_error_message, get_value, run_code};
+
+    // The comparators that are not a consistent order keep every item.
+    let mut environment = run_code(
+        "func shuffle(a: number, b: number): number { return Math.random() - 0.5; }\nfunc after(a: number, b: number): number { return 1; }\nfunc invalid(a: number, b: number): number { return 0 / 0; }\nMath.seed(7);\nconst values = [0, 37, 14, 51, 28, 5, 42, 19, 56, 33, 10, 47, 24, 1, 38, 15, 52, 29, 6, 43, 20, 57, 34, 11, 48, 25, 2, 39, 16, 53, 30, 7, 44, 21, 58, 35, 12, 49, 26, 3, 40, 17, 54, 31, 8, 45, 22, 59, 36, 13, 50, 27, 4, 41, 18, 55, 32, 9, 46, 23];\nconst shuffled = values.sort(shuffle).sort();\nconst ordered = [1, 2, 3].sort(after);\nconst unchanged = [3, 1, 2].sort(invalid);",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("shuffled"), Some(String::from("[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59]")));
+    assert_eq!(get_value("ordered"), Some(String::from("[1, 2, 3]")));
+    assert_eq!(get_value("unchanged"), Some(String::from("[3, 1, 2]")));
+
+    // The errors of the comparator stop the sort.
+    assert_eq!(
+        get_error_message(run_code(
+            "func broken(a: number, b: number): number { const value: number = JSON.parse('x'); return value; }\n[2, 1].sort(broken);"
+        )),
+        "Invalid JSON at line 1, column 1: unexpected `x`."
+    );
+}
@@ -1,51 +1,214 @@
-use crate::{Object, Objects};
-use sflynlang_parser::{Error, Position};
+use crate::{builtins::get_string_argument, Environment, Object, Objects};
+use sflynlang_parser::{Error, OrderedMap, Position};
+use std::io::{self, Write};
+
+/// Join the values using the `separator` named argument (a whitespace by
+/// default).
+fn join_values(
+    objects: Vec<Object>,
+    named_arguments: &OrderedMap<Object>,
+) -> Result<String, Error> {
+    let separator = match named_arguments.get("separator") {
+        Some(separator) => {
+            separator.get_node().get_string().ok_or_else(|| {
+                Error::new_expect_type(
+                    separator.get_position(),
+                    "string",
+                    &separator.to_data_type().to_string(),
+                )
+            })?
+        }
+        None => String::from(" "),
+    };
+
+    Ok(objects
+        .iter()
+        .map(|object| object.to_pretty_string())
+        .collect::<Vec<String>>()
+        .join(&separator))
+}
+
+/// Flush the standard output to show the values printed without a line break.
+fn flush_stdout(position: Position) -> Result<(), Error> {
+    io::stdout()
+        .flush()
+        .map_err(|error| Error::new_runtime(position, &error.to_string()))
+}
 
 pub fn print(
     objects: Vec<Object>,
+    named_arguments: OrderedMap<Object>,
     position: Position,
 ) -> Result<Object, Error> {
-    if objects.len() != 1 {
-        return Err(Error::new_expect_arguments(position, 1, objects.len()));
-    }
+    print!("{}", join_values(objects, &named_arguments)?);
 
-    let object = objects[0].clone();
+    flush_stdout(position.clone())?;
 
-    if object.get_node().get_string().is_none() {
-        return Err(Error::new_expect_type(
-            object.get_position(),
-            "String",
-            &object.to_data_type().to_string(),
-        ));
-    }
+    Ok(Object::new(position, Objects::Void))
+}
+
+pub fn println(
+    objects: Vec<Object>,
+    named_arguments: OrderedMap<Object>,
+    position: Position,
+) -> Result<Object, Error> {
+    println!("{}", join_values(objects, &named_arguments)?);
+
+    Ok(Object::new(position, Objects::Void))
+}
 
-    println!("{}", object.to_string());
+pub fn eprint(
+    objects: Vec<Object>,
+    named_arguments: OrderedMap<Object>,
+    position: Position,
+) -> Result<Object, Error> {
+    eprint!("{}", join_values(objects, &named_arguments)?);
 
-    Ok(Object::new(object.get_position(), Objects::Void))
+    Ok(Object::new(position, Objects::Void))
+}
+
+pub fn eprintln(
+    objects: Vec<Object>,
+    named_arguments: OrderedMap<Object>,
+    position: Position,
+) -> Result<Object, Error> {
+    eprintln!("{}", join_values(objects, &named_arguments)?);
+
+    Ok(Object::new(position, Objects::Void))
 }
 
 pub fn debug(
     objects: Vec<Object>,
+    named_arguments: OrderedMap<Object>,
     position: Position,
     debug_mode: bool,
 ) -> Result<Object, Error> {
-    if objects.len() != 1 {
-        return Err(Error::new_expect_arguments(position, 1, objects.len()));
+    let value = join_values(objects, &named_arguments)?;
+
+    if debug_mode {
+        println!("{}", value);
     }
 
-    let object = objects[0].clone();
+    Ok(Object::new(position, Objects::Void))
+}
 
-    if object.get_node().get_string().is_none() {
-        return Err(Error::new_expect_type(
-            object.get_position(),
-            "String",
-            &object.to_data_type().to_string(),
-        ));
-    }
+/// Print the prompt and read the next line of the input (an empty string at the
+/// end of the input).
+pub fn input(
+    objects: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let prompt = get_string_argument(&objects, 0, "prompt", position.clone())?;
 
-    if debug_mode {
-        println!("{}", object.to_string());
-    }
+    print!("{}", prompt);
+
+    flush_stdout(position.clone())?;
+
+    let line = environment.read_line().map_err(|error| {
+        Error::new_runtime(position.clone(), &error.to_string())
+    })?;
+
+    Ok(Object::new(
+        position,
+        Objects::String(line.unwrap_or_default()),
+    ))
+}
+
+/// Read the next line of the input or get `null` at the end of the input.
+pub fn read_line(
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let line = environment.read_line().map_err(|error| {
+        Error::new_runtime(position.clone(), &error.to_string())
+    })?;
+
+    Ok(match line {
+        Some(line) => Object::new(position, Objects::String(line)),
+        None => Object::new(
+            position.clone(),
+            Objects::Null(Box::new(Object::new(
+                position,
+                Objects::String(String::new()),
+            ))),
+        ),
+    })
+}
+
+/// Read the rest of the input.
+pub fn read_all(
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let content = environment.read_all().map_err(|error| {
+        Error::new_runtime(position.clone(), &error.to_string())
+    })?;
+
+    Ok(Object::new(position, Objects::String(content)))
+}
+
+#[test]
+fn test_console_input() {
+    use crate::test_utils::{get_value, run_code_in};
+    use std::io::Cursor;
+
+    let mut environment = Environment::new();
+
+    environment.set_reader(Box::new(Cursor::new("Sflyn\r\nfirst\nsecond\n")));
+
+    run_code_in(
+        "const name: string = input('Name: ');\nconst first: string? = readLine();\nconst rest: string = readAll();\nconst last: string? = readLine();",
+        &mut environment,
+    )
+    .unwrap();
+
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("name"), Some(String::from("Sflyn")));
+    assert_eq!(get_value("first"), Some(String::from("first")));
+    assert_eq!(get_value("rest"), Some(String::from("second\n")));
+    assert_eq!(get_value("last"), Some(String::from("null")));
+}
+
+#[test]
+fn test_print() {
+    use crate::test_utils::{check_code, run_code};
+    use sflynlang_parser::ErrorType;
+
+    assert!(check_code("print(10, true, [1, 2], { size: 10 });").is_ok());
+    assert!(check_code("println(...['a', 'b'], separator: ', ');").is_ok());
+    assert!(check_code("eprintln('Error:', 404);\neprint();").is_ok());
+    assert_error!(
+        check_code("println('a', 'b', separator: 1);"),
+        ErrorType::ExpectType(_, _)
+    );
+
+    let mut environment =
+        run_code("const values = ['a', 1.5, [true], { size: 10 }];").unwrap();
+    let values = environment
+        .get_store()
+        .get_object(&String::from("values"))
+        .and_then(|values| values.get_node().get_array())
+        .unwrap();
+    let mut named_arguments: OrderedMap<Object> = OrderedMap::new();
+
+    // The strings are not quoted and the hashmaps are indented.
+    assert_eq!(
+        join_values(values.clone(), &named_arguments).unwrap(),
+        "a 1.5 [true] {\n  \"size\": 10\n}"
+    );
+
+    named_arguments.insert(
+        String::from("separator"),
+        Object::new(
+            Position::new(0, 0, 1, 1),
+            Objects::String(String::from(", ")),
+        ),
+    );
 
-    Ok(Object::new(object.get_position(), Objects::Void))
+    assert_eq!(
+        join_values(values[..2].to_vec(), &named_arguments).unwrap(),
+        "a, 1.5"
+    );
 }
@@ -0,0 +1,386 @@
+use crate::{builtins::get_string_argument, Object, Objects};
+use sflynlang_parser::{Error, OrderedMap, Position};
+
+/// The value of a placeholder.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Argument {
+    /// A positional value (`{}` takes the next one and `{1}` the second one).
+    Index(usize),
+
+    /// A named argument value (`{name}`).
+    Name(String),
+}
+
+/// The alignment of a value in the width of its placeholder.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// Maximum width and precision of a placeholder.
+const MAX_COUNT: usize = 1024;
+
+/// A `{argument:[fill]align[0]width.precision}` placeholder of a template.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Placeholder {
+    pub argument: Argument,
+
+    /// Character used to fill the width (a whitespace by default).
+    pub fill: char,
+
+    /// Alignment of the value (the numbers are aligned to the right and the
+    /// other values to the left by default).
+    pub align: Option<Align>,
+
+    /// Whether the numbers are padded with zeros after their sign (`{:05}`),
+    /// ignoring the fill and the alignment.
+    pub zero: bool,
+
+    /// Minimum number of characters of the value.
+    pub width: Option<usize>,
+
+    /// Number of decimals of a number or maximum number of characters of the
+    /// other values.
+    pub precision: Option<usize>,
+}
+
+/// A part of a template.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Segment {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+/// Get the alignment of the character.
+fn get_align(character: char) -> Option<Align> {
+    match character {
+        '<' => Some(Align::Left),
+        '^' => Some(Align::Center),
+        '>' => Some(Align::Right),
+        _ => None,
+    }
+}
+
+/// Parse an unsigned number of the specification.
+fn parse_count(value: &str, name: &str) -> Result<Option<usize>, String> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    let count: usize = value.parse().map_err(|_| {
+        format!("Invalid {} `{}` in the template.", name, value)
+    })?;
+
+    if count > MAX_COUNT {
+        return Err(format!(
+            "The {} `{}` of the template is greater than {}.",
+            name, count, MAX_COUNT
+        ));
+    }
+
+    Ok(Some(count))
+}
+
+/// Parse the content between the braces of a placeholder.
+fn parse_placeholder(
+    content: &str,
+    next_index: &mut usize,
+) -> Result<Placeholder, String> {
+    let (argument, specification) = match content.find(':') {
+        Some(index) => (&content[..index], &content[index + 1..]),
+        None => (content, ""),
+    };
+
+    let argument = if argument.is_empty() {
+        *next_index += 1;
+        Argument::Index(*next_index - 1)
+    } else if argument.chars().all(|character| character.is_ascii_digit()) {
+        Argument::Index(argument.parse().map_err(|_| {
+            format!("Invalid index `{}` in the template.", argument)
+        })?)
+    } else if argument
+        .chars()
+        .all(|character| character.is_alphanumeric() || character == '_')
+        && !argument.starts_with(|character: char| character.is_ascii_digit())
+    {
+        Argument::Name(String::from(argument))
+    } else {
+        return Err(format!(
+            "Invalid argument `{}` in the template.",
+            argument
+        ));
+    };
+
+    let characters: Vec<char> = specification.chars().collect();
+    let (fill, align, rest) =
+        match (characters.first(), characters.get(1).cloned()) {
+            (Some(fill), Some(align)) if get_align(align).is_some() => {
+                (*fill, get_align(align), &characters[2..])
+            }
+            (Some(align), _) if get_align(*align).is_some() => {
+                (' ', get_align(*align), &characters[1..])
+            }
+            _ => (' ', None, &characters[..]),
+        };
+    let mut rest: String = rest.iter().collect();
+    let zero =
+        rest.starts_with('0') && rest.len() > 1 && !rest[1..].starts_with('.');
+
+    if zero {
+        rest.remove(0);
+    }
+
+    let (width, precision) = match rest.find('.') {
+        Some(index) => {
+            let precision = parse_count(&rest[index + 1..], "precision")?
+                .ok_or_else(|| {
+                    String::from(
+                        "Expect a precision after `.` in the template.",
+                    )
+                })?;
+
+            (parse_count(&rest[..index], "width")?, Some(precision))
+        }
+        None => (parse_count(&rest, "width")?, None),
+    };
+
+    Ok(Placeholder {
+        argument,
+        fill,
+        align,
+        zero,
+        width,
+        precision,
+    })
+}
+
+/// Parse the text and the placeholders of a template. The braces are escaped
+/// doubling them (`{{` and `}}`).
+pub fn parse_template(template: &str) -> Result<Vec<Segment>, String> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut text = String::new();
+    let mut next_index: usize = 0;
+    let mut characters = template.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '{' if characters.peek() == Some(&'{') => {
+                characters.next();
+                text.push('{');
+            }
+            '}' if characters.peek() == Some(&'}') => {
+                characters.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut content = String::new();
+
+                loop {
+                    match characters.next() {
+                        Some('}') => break,
+                        Some(character) => content.push(character),
+                        None => {
+                            return Err(String::from(
+                                "Expect `}` to close the placeholder.",
+                            ))
+                        }
+                    }
+                }
+
+                if !text.is_empty() {
+                    segments.push(Segment::Text(text.clone()));
+                    text.clear();
+                }
+
+                segments.push(Segment::Placeholder(parse_placeholder(
+                    &content,
+                    &mut next_index,
+                )?));
+            }
+            '}' => {
+                return Err(String::from(
+                    "Unexpected `}` in the template (use `}}` to escape it).",
+                ))
+            }
+            character => text.push(character),
+        }
+    }
+
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+
+    Ok(segments)
+}
+
+/// Get the number of positional values used by the placeholders.
+pub fn get_expected_values(segments: &[Segment]) -> usize {
+    segments
+        .iter()
+        .filter_map(|segment| match segment {
+            Segment::Placeholder(Placeholder {
+                argument: Argument::Index(index),
+                ..
+            }) => Some(index + 1),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Get the string of a value using the width, precision and alignment of the
+/// placeholder.
+fn format_value(value: &Object, placeholder: &Placeholder) -> String {
+    let number = value.get_node().get_number();
+    let content = match (number, placeholder.precision) {
+        (Some(number), Some(precision)) => format!("{:.*}", precision, number),
+        (None, Some(precision)) => {
+            value.to_string().chars().take(precision).collect()
+        }
+        (_, None) => value.to_string(),
+    };
+
+    let length = content.chars().count();
+    let padding = placeholder.width.unwrap_or(0).saturating_sub(length);
+
+    if padding == 0 {
+        return content;
+    }
+
+    if placeholder.zero && number.is_some() {
+        let digits = content.trim_start_matches('-');
+        let sign = &content[..content.len() - digits.len()];
+
+        return format!("{}{}{}", sign, "0".repeat(padding), digits);
+    }
+
+    let align = placeholder.align.unwrap_or(if number.is_some() {
+        Align::Right
+    } else {
+        Align::Left
+    });
+    let (before, after) = match align {
+        Align::Left => (0, padding),
+        Align::Center => (padding / 2, padding - padding / 2),
+        Align::Right => (padding, 0),
+    };
+    let fill = placeholder.fill.to_string();
+
+    format!("{}{}{}", fill.repeat(before), content, fill.repeat(after))
+}
+
+/// Replace the placeholders of the template (the first argument) with the
+/// positional and named values.
+pub fn format(
+    arguments: Vec<Object>,
+    named_arguments: OrderedMap<Object>,
+    position: Position,
+) -> Result<Object, Error> {
+    let template =
+        get_string_argument(&arguments, 0, "template", position.clone())?;
+    let segments = parse_template(&template)
+        .map_err(|message| Error::new_runtime(position.clone(), &message))?;
+    let expected_values = get_expected_values(&segments);
+
+    if arguments.len() - 1 < expected_values {
+        return Err(Error::new_expect_arguments(
+            position,
+            expected_values,
+            arguments.len() - 1,
+        ));
+    }
+
+    let mut value = String::new();
+
+    for segment in segments.iter() {
+        match segment {
+            Segment::Text(text) => value.push_str(text),
+            Segment::Placeholder(placeholder) => {
+                let argument = match &placeholder.argument {
+                    Argument::Index(index) => &arguments[index + 1],
+                    Argument::Name(name) => {
+                        named_arguments.get(name).ok_or_else(|| {
+                            Error::new_missing_argument(
+                                position.clone(),
+                                name.clone(),
+                            )
+                        })?
+                    }
+                };
+
+                value.push_str(&format_value(argument, placeholder));
+            }
+        }
+    }
+
+    Ok(Object::new(position, Objects::String(value)))
+}
+
+#[test]
+fn test_format() {
+    use crate::test_utils::{check_code, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    assert!(check_code(
+        "const text: string = format('{} has {:.2} items', 'Cart', 19.75);"
+    )
+    .is_ok());
+    assert!(
+        check_code("format('{name:>8} {0:*^5} {{}}', 1, name: 'Sflyn');")
+            .is_ok()
+    );
+    assert!(
+        check_code("const template = '{}';\nformat(template, 1, 2);").is_ok()
+    );
+
+    // The errors of the literal templates are reported at the template.
+    let check_template = |code: &str| {
+        let result = check_code(code);
+
+        if let Err(error) = &result {
+            assert_eq!(error.get_position().get_range().start, 7);
+        }
+
+        result
+    };
+
+    assert_error!(
+        check_template("format('{} {} {}', 1);"),
+        ErrorType::ExpectArguments(3, 1)
+    );
+    assert_error!(
+        check_template("format('{size}', name: 1);"),
+        ErrorType::MissingArgument(_)
+    );
+    assert_error!(check_template("format('{:.}', 1);"), ErrorType::Semantic(_));
+    assert_error!(
+        check_template("format('{:999999999999}', 1);"),
+        ErrorType::Semantic(_)
+    );
+
+    let mut environment = run_code(
+        "const padded = format('{:05}|{:05.1}|{:<05}|{:05}', 42, -3.14, 7, 'ab');\nconst filled = format('{:0>5}|{:0}', 42, 1);",
+    )
+    .unwrap();
+
+    assert_eq!(
+        get_value(&mut environment, "padded"),
+        Some(String::from("00042|-03.1|00007|ab   "))
+    );
+    assert_eq!(
+        get_value(&mut environment, "filled"),
+        Some(String::from("00042|1"))
+    );
+
+    // The templates that are not literals are validated when they are used.
+    assert_error!(
+        run_code("const template = '{} {} {}';\nformat(template, 1);"),
+        ErrorType::ExpectArguments(3, 1)
+    );
+    assert_error!(
+        run_code("const template = '{:999999999999}';\nformat(template, 1);"),
+        ErrorType::Runtime(_)
+    );
+}
@@ -0,0 +1,327 @@
+use crate::{
+    builtins::{
+        get_string_argument, new_argument, new_function_type, Function,
+        Namespace,
+    },
+    Environment, Object, Objects,
+};
+use sflynlang_parser::{
+    ast::{DataType, DataTypes},
+    Error, Position,
+};
+use std::{fs, io::Write, path::Path};
+
+pub const NAMESPACE: Namespace = Namespace {
+    name: "fs",
+    functions: &[
+        Function {
+            name: "appendFile",
+            get_type: write_type,
+            eval: append_file,
+        },
+        Function {
+            name: "exists",
+            get_type: exists_type,
+            eval: exists,
+        },
+        Function {
+            name: "listDir",
+            get_type: list_dir_type,
+            eval: list_dir,
+        },
+        Function {
+            name: "mkdir",
+            get_type: path_type,
+            eval: mkdir,
+        },
+        Function {
+            name: "readFile",
+            get_type: read_file_type,
+            eval: read_file,
+        },
+        Function {
+            name: "remove",
+            get_type: path_type,
+            eval: remove,
+        },
+        Function {
+            name: "writeFile",
+            get_type: write_type,
+            eval: write_file,
+        },
+    ],
+    constants: &[],
+};
+
+/// Get the path argument, rejecting the paths reserved by the environment.
+fn get_path(
+    arguments: &[Object],
+    position: Position,
+    environment: &Environment,
+) -> Result<String, Error> {
+    let path = get_string_argument(arguments, 0, "path", position.clone())?;
+
+    if environment.is_reserved_path(Path::new(&path)) {
+        return Err(Error::new_runtime(
+            position,
+            &format!("The `{}` path uses a reserved name.", path),
+        ));
+    }
+
+    Ok(path)
+}
+
+/// Get the error of a failed file system operation.
+fn new_fs_error(
+    position: Position,
+    action: &str,
+    path: &str,
+    error: std::io::Error,
+) -> Error {
+    Error::new_runtime(
+        position,
+        &format!("Cannot {} `{}`: {}.", action, path, error),
+    )
+}
+
+/// Get the function data type of the path and string arguments.
+fn new_path_type(
+    position: Position,
+    names: &[&str],
+    return_type: DataTypes,
+) -> DataType {
+    new_function_type(
+        position.clone(),
+        names
+            .iter()
+            .map(|name| {
+                new_argument(position.clone(), name, DataTypes::String, false)
+            })
+            .collect(),
+        return_type,
+    )
+}
+
+/// (path: string, content: string) => void
+fn write_type(position: Position) -> DataType {
+    new_path_type(position, &["path", "content"], DataTypes::Void)
+}
+
+/// Add the content at the end of the file, creating it when it is missing.
+fn append_file(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let path = get_path(&arguments, position.clone(), environment)?;
+    let content =
+        get_string_argument(&arguments, 1, "content", position.clone())?;
+
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|error| {
+            new_fs_error(position.clone(), "append to", &path, error)
+        })?;
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+/// (path: string) => boolean
+fn exists_type(position: Position) -> DataType {
+    new_path_type(position, &["path"], DataTypes::Boolean)
+}
+
+fn exists(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let path = get_path(&arguments, position.clone(), environment)?;
+
+    Ok(Object::new(
+        position,
+        Objects::Boolean(Path::new(&path).exists()),
+    ))
+}
+
+/// (path: string) => string[]
+fn list_dir_type(position: Position) -> DataType {
+    new_path_type(
+        position.clone(),
+        &["path"],
+        DataTypes::Array(Box::new(DataType::new(position, DataTypes::String))),
+    )
+}
+
+/// Get the sorted names of the directory entries.
+fn list_dir(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let path = get_path(&arguments, position.clone(), environment)?;
+    let mut names = fs::read_dir(&path)
+        .and_then(|entries| {
+            entries
+                .map(|entry| {
+                    entry.map(|entry| {
+                        entry.file_name().to_string_lossy().into_owned()
+                    })
+                })
+                .collect::<Result<Vec<String>, _>>()
+        })
+        .map_err(|error| {
+            new_fs_error(position.clone(), "list", &path, error)
+        })?;
+
+    names.sort();
+
+    Ok(Object::new(
+        position.clone(),
+        Objects::new_array(
+            names
+                .into_iter()
+                .map(|name| {
+                    Object::new(position.clone(), Objects::String(name))
+                })
+                .collect(),
+        ),
+    ))
+}
+
+/// (path: string) => void
+fn path_type(position: Position) -> DataType {
+    new_path_type(position, &["path"], DataTypes::Void)
+}
+
+/// Create the directory and its missing parents.
+fn mkdir(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let path = get_path(&arguments, position.clone(), environment)?;
+
+    fs::create_dir_all(&path).map_err(|error| {
+        new_fs_error(position.clone(), "create", &path, error)
+    })?;
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+/// (path: string) => string
+fn read_file_type(position: Position) -> DataType {
+    new_path_type(position, &["path"], DataTypes::String)
+}
+
+fn read_file(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let path = get_path(&arguments, position.clone(), environment)?;
+    let content = fs::read_to_string(&path).map_err(|error| {
+        new_fs_error(position.clone(), "read", &path, error)
+    })?;
+
+    Ok(Object::new(position, Objects::String(content)))
+}
+
+/// Remove the file or the empty directory.
+fn remove(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let path = get_path(&arguments, position.clone(), environment)?;
+    let result = if Path::new(&path).is_dir() {
+        fs::remove_dir(&path)
+    } else {
+        fs::remove_file(&path)
+    };
+
+    result.map_err(|error| {
+        new_fs_error(position.clone(), "remove", &path, error)
+    })?;
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+/// Write the content to the file, replacing it when it exists.
+fn write_file(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let path = get_path(&arguments, position.clone(), environment)?;
+    let content =
+        get_string_argument(&arguments, 1, "content", position.clone())?;
+
+    fs::write(&path, content).map_err(|error| {
+        new_fs_error(position.clone(), "write", &path, error)
+    })?;
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+#[test]
+fn test_fs() {
+    use crate::test_utils::{check_code, get_value, run_code_in};
+    use sflynlang_parser::ErrorType;
+
+    assert!(
+        check_code("const content: string = fs.readFile('notes.txt');").is_ok()
+    );
+    assert!(check_code(
+        "const names: string[] = fs.listDir('.');\nfs.writeFile('notes.txt', 'Sflyn');"
+    )
+    .is_ok());
+    assert_error!(
+        check_code(
+            "try { fs.mkdir('notes'); } catch (error) { const code: number = error; }"
+        ),
+        ErrorType::ExpectType(_, _)
+    );
+
+    let directory = std::env::temp_dir()
+        .join(format!("sflyn-test-fs-{}", std::process::id()));
+    let directory_path = directory.to_string_lossy().replace('\\', "/");
+    let mut environment = Environment::new();
+
+    environment.set_reserved_path(|path| path.ends_with("con.txt"));
+
+    let result = run_code_in(
+        &format!(
+            "const directory = '{}';\nfunc read(path: string): string {{\ntry {{\nreturn fs.readFile(path);\n}} catch (error) {{\nreturn error;\n}}\n}}\nfunc remove(path: string): string {{\ntry {{\nfs.remove(path);\nreturn 'removed';\n}} catch (error) {{\nreturn error;\n}}\n}}\nfs.mkdir(directory + '/notes/old');\nfs.writeFile(directory + '/notes/a.txt', 'Sflyn');\nfs.appendFile(directory + '/notes/a.txt', ' lang');\nfs.writeFile(directory + '/notes/b.txt', '');\nconst content = fs.readFile(directory + '/notes/a.txt');\nconst names = fs.listDir(directory + '/notes');\nconst removed = remove(directory + '/notes/b.txt');\nconst exists = fs.exists(directory + '/notes/b.txt');\nconst notEmpty = remove(directory + '/notes').startsWith('Cannot remove');\nconst missing = read(directory + '/missing.txt').startsWith('Cannot read');\nconst reserved = read('con.txt');",
+            directory_path
+        ),
+        &mut environment,
+    );
+    let missing = run_code_in(
+        "fs.readFile(directory + '/missing.txt');",
+        &mut environment,
+    );
+
+    std::fs::remove_dir_all(&directory).unwrap();
+    result.unwrap();
+
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("content"), Some(String::from("Sflyn lang")));
+    assert_eq!(
+        get_value("names"),
+        Some(String::from("[\"a.txt\", \"b.txt\", \"old\"]"))
+    );
+    assert_eq!(get_value("removed"), Some(String::from("removed")));
+    assert_eq!(get_value("exists"), Some(String::from("false")));
+    assert_eq!(get_value("notEmpty"), Some(String::from("true")));
+    assert_eq!(get_value("missing"), Some(String::from("true")));
+    assert_eq!(
+        get_value("reserved"),
+        Some(String::from("The `con.txt` path uses a reserved name."))
+    );
+    assert_error!(missing, ErrorType::Runtime(_));
+}
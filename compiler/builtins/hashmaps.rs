@@ -0,0 +1,396 @@
+use crate::{
+    builtins::{get_string_argument, new_argument, new_function_type, Method},
+    typechecker, Environment, Object, Objects,
+};
+use sflynlang_parser::{
+    ast::{DataType, DataTypes},
+    Error, OrderedMap, Position,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub const METHODS: &[Method] = &[
+    Method {
+        name: "entries",
+        get_type: entries_type,
+        eval: entries,
+    },
+    Method {
+        name: "get",
+        get_type,
+        eval: get,
+    },
+    Method {
+        name: "has",
+        get_type: has_type,
+        eval: has,
+    },
+    Method {
+        name: "keys",
+        get_type: keys_type,
+        eval: keys,
+    },
+    Method {
+        name: "remove",
+        get_type: remove_type,
+        eval: remove,
+    },
+    Method {
+        name: "set",
+        get_type: set_type,
+        eval: set,
+    },
+    Method {
+        name: "size",
+        get_type: size_type,
+        eval: size,
+    },
+    Method {
+        name: "values",
+        get_type: values_type,
+        eval: values,
+    },
+];
+
+/// Get the data type that accepts every value when the receiver is a hashmap
+/// without a property with the name of the method (the properties take
+/// precedence over the methods).
+fn get_values_type(
+    receiver: &DataType,
+    method_name: &str,
+) -> Option<DataTypes> {
+    let data = receiver.node.get_hashmap()?;
+
+    if data.contains_key(method_name) {
+        return None;
+    }
+
+    Some(
+        data.values()
+            .map(|value| {
+                DataType::new(value.get_position(), value.node.widen())
+            })
+            .fold(None, |values_type: Option<DataType>, value| {
+                Some(match values_type {
+                    Some(values_type) => {
+                        typechecker::unify_data_types(&values_type, &value)
+                    }
+                    None => value,
+                })
+            })
+            .map(|values_type| values_type.node)
+            .unwrap_or(DataTypes::Unknown),
+    )
+}
+
+/// Get the function data type of a hashmap method.
+fn new_method_type(
+    position: Position,
+    arguments: Vec<(&str, DataTypes)>,
+    return_type: DataTypes,
+) -> DataType {
+    new_function_type(
+        position.clone(),
+        arguments
+            .into_iter()
+            .map(|(name, data_type)| {
+                new_argument(position.clone(), name, data_type, false)
+            })
+            .collect(),
+        return_type,
+    )
+}
+
+/// Get the data of the receiver.
+fn get_receiver(receiver: &Object) -> Result<OrderedMap<Object>, Error> {
+    receiver.get_node().get_hashmap().ok_or_else(|| {
+        Error::new_expect_type(
+            receiver.get_position(),
+            "hashmap",
+            &receiver.to_data_type().to_string(),
+        )
+    })
+}
+
+/// Get the shared data of the receiver to mutate it.
+fn get_receiver_ref(
+    receiver: &Object,
+) -> Result<Rc<RefCell<OrderedMap<Object>>>, Error> {
+    receiver.get_node().get_hashmap_ref().ok_or_else(|| {
+        Error::new_expect_type(
+            receiver.get_position(),
+            "hashmap",
+            &receiver.to_data_type().to_string(),
+        )
+    })
+}
+
+/// Get the object of the value or `null` when it is missing.
+fn new_optional(position: Position, value: Option<Object>) -> Object {
+    value.unwrap_or_else(|| {
+        Object::new(
+            position.clone(),
+            Objects::Null(Box::new(Object::new(position, Objects::Unknown))),
+        )
+    })
+}
+
+/// () => (string, V)[]
+fn entries_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let values_type = get_values_type(receiver, "entries")?;
+
+    Some(new_method_type(
+        position.clone(),
+        Vec::new(),
+        DataTypes::Array(Box::new(DataType::new(
+            position.clone(),
+            DataTypes::Tuple(vec![
+                DataType::new(position.clone(), DataTypes::String),
+                DataType::new(position, values_type),
+            ]),
+        ))),
+    ))
+}
+
+/// Get the `(key, value)` tuples in the insertion order.
+fn entries(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let data = get_receiver(receiver)?;
+
+    Ok(Object::new(
+        position.clone(),
+        Objects::new_array(
+            data.into_iter()
+                .map(|(key, value)| {
+                    Object::new(
+                        position.clone(),
+                        Objects::Tuple(vec![
+                            Object::new(position.clone(), Objects::String(key)),
+                            value,
+                        ]),
+                    )
+                })
+                .collect(),
+        ),
+    ))
+}
+
+/// (key: string) => V?
+fn get_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let values_type = get_values_type(receiver, "get")?;
+
+    Some(new_method_type(
+        position.clone(),
+        vec![("key", DataTypes::String)],
+        DataTypes::Option(Box::new(DataType::new(position, values_type))),
+    ))
+}
+
+/// Get the value of the key or `null` when it is missing.
+fn get(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let data = get_receiver(receiver)?;
+    let key = get_string_argument(&arguments, 0, "key", position.clone())?;
+
+    Ok(new_optional(position, data.get(&key).cloned()))
+}
+
+/// (key: string) => boolean
+fn has_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    get_values_type(receiver, "has")?;
+
+    Some(new_method_type(
+        position,
+        vec![("key", DataTypes::String)],
+        DataTypes::Boolean,
+    ))
+}
+
+fn has(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let data = get_receiver(receiver)?;
+    let key = get_string_argument(&arguments, 0, "key", position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::Boolean(data.contains_key(&key)),
+    ))
+}
+
+/// () => string[]
+fn keys_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    get_values_type(receiver, "keys")?;
+
+    Some(new_method_type(
+        position.clone(),
+        Vec::new(),
+        DataTypes::Array(Box::new(DataType::new(position, DataTypes::String))),
+    ))
+}
+
+/// Get the keys in the insertion order.
+fn keys(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let data = get_receiver(receiver)?;
+
+    Ok(Object::new(
+        position.clone(),
+        Objects::new_array(
+            data.keys()
+                .map(|key| {
+                    Object::new(position.clone(), Objects::String(key.clone()))
+                })
+                .collect(),
+        ),
+    ))
+}
+
+/// (key: string) => V?
+fn remove_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let values_type = get_values_type(receiver, "remove")?;
+
+    Some(new_method_type(
+        position.clone(),
+        vec![("key", DataTypes::String)],
+        DataTypes::Option(Box::new(DataType::new(position, values_type))),
+    ))
+}
+
+/// Remove the key from the receiver and get its value or `null` when it is
+/// missing.
+fn remove(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let key = get_string_argument(&arguments, 0, "key", position.clone())?;
+    let value = get_receiver_ref(receiver)?.borrow_mut().remove(&key);
+
+    Ok(new_optional(position, value))
+}
+
+/// (key: string, value: V) => void
+fn set_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let values_type = get_values_type(receiver, "set")?;
+
+    Some(new_method_type(
+        position,
+        vec![("key", DataTypes::String), ("value", values_type)],
+        DataTypes::Void,
+    ))
+}
+
+/// Set the value of the key in the receiver, adding it at the end when it is
+/// missing.
+fn set(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let key = get_string_argument(&arguments, 0, "key", position.clone())?;
+    let value = arguments.get(1).cloned().ok_or_else(|| {
+        Error::new_missing_argument(position.clone(), String::from("value"))
+    })?;
+
+    get_receiver_ref(receiver)?.borrow_mut().insert(key, value);
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+/// () => number
+fn size_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    get_values_type(receiver, "size")?;
+
+    Some(new_method_type(position, Vec::new(), DataTypes::Number))
+}
+
+fn size(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let data = get_receiver(receiver)?;
+
+    Ok(Object::new(position, Objects::Number(data.len() as f64)))
+}
+
+/// () => V[]
+fn values_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    let values_type = get_values_type(receiver, "values")?;
+
+    Some(new_method_type(
+        position.clone(),
+        Vec::new(),
+        DataTypes::Array(Box::new(DataType::new(position, values_type))),
+    ))
+}
+
+/// Get the values in the insertion order.
+fn values(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let data = get_receiver(receiver)?;
+
+    Ok(Object::new(
+        position,
+        Objects::new_array(data.values().cloned().collect()),
+    ))
+}
+
+#[test]
+fn test_hashmap_methods() {
+    use crate::test_utils::{check_with, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    let check_method =
+        |code: &str| check_with("const ages = { zoe: 31, adam: 27 };", code);
+
+    assert!(check_method("const names: string[] = ages.keys();").is_ok());
+    assert!(check_method("const values: number[] = ages.values();").is_ok());
+    assert!(check_method("const age: number? = ages.get('zoe');").is_ok());
+    assert!(check_method("ages.set('mia', 45);").is_ok());
+    assert_error!(
+        check_method("const age: number = ages.get('zoe');"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_method("ages.set('mia', 'old');"),
+        ErrorType::ExpectType(_, _)
+    );
+
+    // The mutations are visible from every copy of the values.
+    let mut environment = run_code(
+        "const ages = { zoe: 31, adam: 27 };\nconst holder = { list: [1], inner: { ages: ages } };\nconst list = [1];\nfunc update() {\nlist.push(2);\nages.set('mia', 45);\n}\nupdate();\nholder.list.push(2);\nholder.inner.ages.remove('zoe');\nconst copy = list;\nconst last = copy.pop();\nconst names = ages.keys();\nconst size = holder.list.length();",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("list"), Some(String::from("[1]")));
+    assert_eq!(get_value("last"), Some(String::from("2")));
+    assert_eq!(
+        get_value("names"),
+        Some(String::from("[\"adam\", \"mia\"]"))
+    );
+    assert_eq!(get_value("size"), Some(String::from("2")));
+}
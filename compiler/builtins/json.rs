@@ -0,0 +1,932 @@
+use crate::{
+    builtins::{
+        get_number_argument, get_string_argument, new_argument,
+        new_function_type, Function, Namespace,
+    },
+    typechecker, Environment, Object, Objects, Store,
+};
+use sflynlang_parser::{
+    ast::{DataType, DataTypes, Expression},
+    Error, OrderedMap, Position,
+};
+use std::{iter::Peekable, str::Chars};
+
+pub const NAMESPACE: Namespace = Namespace {
+    name: "JSON",
+    functions: &[
+        Function {
+            name: "parse",
+            get_type: parse_type,
+            eval: parse,
+        },
+        Function {
+            name: "stringify",
+            get_type: stringify_type,
+            eval: stringify,
+        },
+    ],
+    constants: &[],
+};
+
+/// The maximum number of spaces of the `stringify` indentation.
+const MAX_INDENT: f64 = 10.0;
+
+/// The maximum number of nested arrays and objects of a JSON value.
+const MAX_DEPTH: usize = 512;
+
+/// Check if the name is defined by the program in the store or its outer
+/// stores (like an argument named `JSON`).
+fn is_defined(store: &Store, name: &String) -> bool {
+    if store.has_data_type(name) || store.has_object(name) {
+        return true;
+    }
+
+    match store.get_outer() {
+        Some(outer) => is_defined(&outer, name),
+        None => false,
+    }
+}
+
+/// Check if the expression calls the `JSON.parse` builtin function.
+pub fn is_parse_call(expression: &Expression, store: &Store) -> bool {
+    let (namespace, call) = match expression.node.get_method() {
+        Some(method) => method,
+        None => return false,
+    };
+
+    namespace.node.get_identifier().as_deref() == Some(NAMESPACE.name)
+        && matches!(
+            call.node.get_call(),
+            Some((function, _))
+                if function.node.get_identifier().as_deref() == Some("parse")
+        )
+        && !is_defined(store, &String::from(NAMESPACE.name))
+}
+
+/// Check if the values of the data type can be parsed from JSON.
+pub fn is_json_type(data_type: &DataTypes) -> bool {
+    match data_type {
+        DataTypes::Argument { data_type, .. } => is_json_type(&data_type.node),
+        DataTypes::Array(value_type) | DataTypes::Option(value_type) => {
+            is_json_type(&value_type.node)
+        }
+        DataTypes::Boolean
+        | DataTypes::Number
+        | DataTypes::NumberLiteral(_)
+        | DataTypes::String
+        | DataTypes::StringLiteral(_)
+        | DataTypes::Unknown => true,
+        DataTypes::HashMap(data) => {
+            data.iter().all(|(_, value)| is_json_type(&value.node))
+        }
+        DataTypes::Tuple(values) | DataTypes::Union(values) => {
+            values.iter().all(|value| is_json_type(&value.node))
+        }
+        _ => false,
+    }
+}
+
+/// Get the name of the JSON value of the object.
+fn get_json_name(object: &Object) -> &'static str {
+    match object.get_node() {
+        Objects::Array(_) | Objects::Tuple(_) => "array",
+        Objects::Boolean(_) => "boolean",
+        Objects::HashMap(_) => "object",
+        Objects::Null(_) => "null",
+        Objects::Number(_) => "number",
+        Objects::String(_) => "string",
+        _ => "unknown",
+    }
+}
+
+/// Convert a parsed value to the data type (like the arrays to tuples) or get
+/// the description of its first mismatch.
+fn convert_value(
+    object: Object,
+    data_type: &DataType,
+    path: &str,
+) -> Result<Object, String> {
+    let position = object.get_position();
+    let mismatch = || {
+        format!(
+            "expect {} at `{}`, found {}",
+            match data_type.node {
+                DataTypes::HashMap(_) => String::from("object"),
+                _ => format!("`{}`", data_type.to_string()),
+            },
+            path,
+            get_json_name(&object)
+        )
+    };
+
+    let is_valid = match &data_type.node {
+        DataTypes::Argument { data_type, .. } => {
+            return convert_value(object, data_type, path)
+        }
+        DataTypes::Array(value_type) => {
+            let values = object.get_node().get_array().ok_or_else(mismatch)?;
+            let mut items: Vec<Object> = Vec::new();
+
+            for (index, value) in values.into_iter().enumerate() {
+                items.push(convert_value(
+                    value,
+                    value_type,
+                    &format!("{}[{}]", path, index),
+                )?);
+            }
+
+            return Ok(Object::new(position, Objects::new_array(items)));
+        }
+        DataTypes::Boolean => object.get_node().get_boolean().is_some(),
+        DataTypes::HashMap(properties) => {
+            let data = object.get_node().get_hashmap().ok_or_else(mismatch)?;
+
+            if let Some(key) =
+                data.keys().find(|key| !properties.contains_key(key))
+            {
+                return Err(format!(
+                    "unexpected `{}` property at `{}`",
+                    key, path
+                ));
+            }
+
+            let mut values: OrderedMap<Object> = OrderedMap::new();
+
+            for (key, value_type) in properties.iter() {
+                let value = match data.get(key) {
+                    Some(value) => convert_value(
+                        value.clone(),
+                        value_type,
+                        &format!("{}.{}", path, key),
+                    )?,
+                    // The missing optional properties are null.
+                    None if value_type.node.get_option().is_some() => {
+                        Object::new(
+                            position.clone(),
+                            Objects::Null(Box::new(Object::new(
+                                position.clone(),
+                                Objects::Unknown,
+                            ))),
+                        )
+                    }
+                    None => {
+                        return Err(format!(
+                            "missing `{}` property at `{}`",
+                            key, path
+                        ))
+                    }
+                };
+
+                values.insert(key.clone(), value);
+            }
+
+            return Ok(Object::new(position, Objects::new_hashmap(values)));
+        }
+        DataTypes::Number => object.get_node().get_number().is_some(),
+        DataTypes::NumberLiteral(literal) => {
+            object.get_node().get_number() == Some(*literal)
+        }
+        DataTypes::Option(value_type) => {
+            if object.get_node().get_null().is_some() {
+                return Ok(object);
+            }
+
+            return convert_value(object, value_type, path);
+        }
+        DataTypes::String => object.get_node().get_string().is_some(),
+        DataTypes::StringLiteral(literal) => {
+            object.get_node().get_string().as_ref() == Some(literal)
+        }
+        DataTypes::Tuple(values_types) => {
+            let values = object.get_node().get_array().ok_or_else(mismatch)?;
+
+            if values.len() != values_types.len() {
+                return Err(mismatch());
+            }
+
+            let mut items: Vec<Object> = Vec::new();
+
+            for (index, (value, value_type)) in
+                values.into_iter().zip(values_types.iter()).enumerate()
+            {
+                items.push(convert_value(
+                    value,
+                    value_type,
+                    &format!("{}[{}]", path, index),
+                )?);
+            }
+
+            return Ok(Object::new(position, Objects::Tuple(items)));
+        }
+        DataTypes::Union(values_types) => {
+            return values_types
+                .iter()
+                .find_map(|value_type| {
+                    convert_value(object.clone(), value_type, path).ok()
+                })
+                .ok_or_else(mismatch);
+        }
+        DataTypes::Unknown => true,
+        _ => false,
+    };
+
+    if !is_valid {
+        return Err(mismatch());
+    }
+
+    Ok(object)
+}
+
+/// Convert a value of `JSON.parse` to the declared data type of its variable,
+/// validating its structure.
+pub fn convert(
+    object: Object,
+    data_type: &DataType,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let position = object.get_position();
+    let resolved_type = typechecker::resolve_data_type(data_type, environment)?;
+
+    convert_value(object, &resolved_type, "$").map_err(|message| {
+        Error::new_runtime(
+            position,
+            &format!(
+                "The JSON value does not match `{}`: {}.",
+                data_type.to_string(),
+                message
+            ),
+        )
+    })
+}
+
+/// A reader of the characters of a JSON text, tracking their line and
+/// column.
+struct Reader<'a> {
+    characters: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+    depth: usize,
+    position: Position,
+}
+
+impl<'a> Reader<'a> {
+    fn new(text: &'a str, position: Position) -> Self {
+        Self {
+            characters: text.chars().peekable(),
+            line: 1,
+            column: 1,
+            depth: 0,
+            position,
+        }
+    }
+
+    /// Create an error at the line and the column of the next character.
+    fn new_error(&self, message: &str) -> Error {
+        Error::new_runtime(
+            self.position.clone(),
+            &format!(
+                "Invalid JSON at line {}, column {}: {}.",
+                self.line, self.column, message
+            ),
+        )
+    }
+
+    /// Create an error for the next character.
+    fn new_unexpected_error(&mut self) -> Error {
+        match self.peek() {
+            Some(character) => {
+                self.new_error(&format!("unexpected `{}`", character))
+            }
+            None => self.new_error("unexpected end of the text"),
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.characters.peek().cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let character = self.characters.next()?;
+
+        if character == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(character)
+    }
+
+    /// Consume the next character when it is the expected one.
+    fn read_char(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.next();
+            return true;
+        }
+
+        false
+    }
+
+    fn expect_char(&mut self, expected: char) -> Result<(), Error> {
+        if self.read_char(expected) {
+            return Ok(());
+        }
+
+        Err(self.new_unexpected_error())
+    }
+
+    fn skip_whitespaces(&mut self) {
+        while let Some(' ') | Some('\t') | Some('\n') | Some('\r') = self.peek()
+        {
+            self.next();
+        }
+    }
+
+    /// Enter an array or an object, checking the nesting depth.
+    fn enter(&mut self) -> Result<(), Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.new_error(&format!(
+                "more than {} nested arrays and objects",
+                MAX_DEPTH
+            )));
+        }
+
+        self.depth += 1;
+
+        Ok(())
+    }
+
+    fn new_object(&self, node: Objects) -> Object {
+        Object::new(self.position.clone(), node)
+    }
+
+    fn read_value(&mut self) -> Result<Object, Error> {
+        self.skip_whitespaces();
+
+        match self.peek() {
+            Some('{') => self.read_object(),
+            Some('[') => self.read_array(),
+            Some('"') => {
+                let value = self.read_string()?;
+
+                Ok(self.new_object(Objects::String(value)))
+            }
+            Some('-') | Some('0'..='9') => self.read_number(),
+            Some('t') => self.read_keyword("true", Objects::Boolean(true)),
+            Some('f') => self.read_keyword("false", Objects::Boolean(false)),
+            Some('n') => self.read_keyword(
+                "null",
+                Objects::Null(Box::new(self.new_object(Objects::Unknown))),
+            ),
+            _ => Err(self.new_unexpected_error()),
+        }
+    }
+
+    fn read_keyword(
+        &mut self,
+        keyword: &str,
+        node: Objects,
+    ) -> Result<Object, Error> {
+        for character in keyword.chars() {
+            self.expect_char(character)?;
+        }
+
+        Ok(self.new_object(node))
+    }
+
+    /// Read the digits and get if there is at least one.
+    fn read_digits(&mut self, value: &mut String) -> bool {
+        let length = value.len();
+
+        while let Some(character) = self.peek() {
+            if !character.is_ascii_digit() {
+                break;
+            }
+
+            value.push(character);
+            self.next();
+        }
+
+        value.len() > length
+    }
+
+    fn read_number(&mut self) -> Result<Object, Error> {
+        let mut value = String::new();
+
+        if self.read_char('-') {
+            value.push('-');
+        }
+
+        // The integer part cannot have leading zeros.
+        if self.read_char('0') {
+            value.push('0');
+        } else if !self.read_digits(&mut value) {
+            return Err(self.new_unexpected_error());
+        }
+
+        if self.read_char('.') {
+            value.push('.');
+
+            if !self.read_digits(&mut value) {
+                return Err(self.new_unexpected_error());
+            }
+        }
+
+        if self.read_char('e') || self.read_char('E') {
+            value.push('e');
+
+            if self.read_char('-') {
+                value.push('-');
+            } else {
+                self.read_char('+');
+            }
+
+            if !self.read_digits(&mut value) {
+                return Err(self.new_unexpected_error());
+            }
+        }
+
+        match value.parse() {
+            Ok(number) => Ok(self.new_object(Objects::Number(number))),
+            Err(_) => {
+                Err(self.new_error(&format!("invalid number `{}`", value)))
+            }
+        }
+    }
+
+    /// Read the 4 hexadecimal digits of an unicode escape.
+    fn read_code_unit(&mut self) -> Result<u32, Error> {
+        let mut code_unit: u32 = 0;
+
+        for _ in 0..4 {
+            let digit = match self.peek().and_then(|digit| digit.to_digit(16)) {
+                Some(digit) => digit,
+                None => return Err(self.new_unexpected_error()),
+            };
+
+            self.next();
+            code_unit = code_unit * 16 + digit;
+        }
+
+        Ok(code_unit)
+    }
+
+    /// Read the character of an unicode escape, joining the surrogate pairs.
+    fn read_unicode_escape(&mut self) -> Result<char, Error> {
+        let mut code_point = self.read_code_unit()?;
+
+        if (0xD800..0xDC00).contains(&code_point) {
+            if !self.read_char('\\') || !self.read_char('u') {
+                return Err(self.new_error("expect a low surrogate escape"));
+            }
+
+            let low_surrogate = self.read_code_unit()?;
+
+            if !(0xDC00..0xE000).contains(&low_surrogate) {
+                return Err(self.new_error("invalid low surrogate escape"));
+            }
+
+            code_point = 0x10000
+                + ((code_point - 0xD800) << 10)
+                + (low_surrogate - 0xDC00);
+        }
+
+        std::char::from_u32(code_point)
+            .ok_or_else(|| self.new_error("invalid unicode escape"))
+    }
+
+    fn read_string(&mut self) -> Result<String, Error> {
+        self.expect_char('"')?;
+
+        let mut value = String::new();
+
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.next();
+                    return Ok(value);
+                }
+                Some('\\') => {
+                    self.next();
+
+                    let character = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            self.next();
+                            value.push(self.read_unicode_escape()?);
+                            continue;
+                        }
+                        _ => return Err(self.new_unexpected_error()),
+                    };
+
+                    self.next();
+                    value.push(character);
+                }
+                Some(character) if character < ' ' => {
+                    return Err(self.new_error("unescaped control character"))
+                }
+                Some(character) => {
+                    self.next();
+                    value.push(character);
+                }
+                None => return Err(self.new_error("unterminated string")),
+            }
+        }
+    }
+
+    fn read_array(&mut self) -> Result<Object, Error> {
+        self.enter()?;
+        self.expect_char('[')?;
+        self.skip_whitespaces();
+
+        let mut values: Vec<Object> = Vec::new();
+
+        if !self.read_char(']') {
+            loop {
+                values.push(self.read_value()?);
+                self.skip_whitespaces();
+
+                if self.read_char(']') {
+                    break;
+                }
+
+                self.expect_char(',')?;
+            }
+        }
+
+        self.depth -= 1;
+
+        Ok(self.new_object(Objects::new_array(values)))
+    }
+
+    fn read_object(&mut self) -> Result<Object, Error> {
+        self.enter()?;
+        self.expect_char('{')?;
+        self.skip_whitespaces();
+
+        let mut data: OrderedMap<Object> = OrderedMap::new();
+
+        if !self.read_char('}') {
+            loop {
+                self.skip_whitespaces();
+
+                let key = self.read_string()?;
+
+                self.skip_whitespaces();
+                self.expect_char(':')?;
+
+                // The last value of a repeated key replaces the previous ones.
+                data.insert(key, self.read_value()?);
+                self.skip_whitespaces();
+
+                if self.read_char('}') {
+                    break;
+                }
+
+                self.expect_char(',')?;
+            }
+        }
+
+        self.depth -= 1;
+
+        Ok(self.new_object(Objects::new_hashmap(data)))
+    }
+}
+
+/// (text: string) => unknown
+fn parse_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(position, "text", DataTypes::String, false)],
+        DataTypes::Unknown,
+    )
+}
+
+/// Get the hashmap, array, number, string, boolean or null value of the JSON
+/// text.
+fn parse(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let text = get_string_argument(&arguments, 0, "text", position.clone())?;
+    let mut reader = Reader::new(&text, position);
+    let value = reader.read_value()?;
+
+    reader.skip_whitespaces();
+
+    if reader.peek().is_some() {
+        return Err(reader.new_unexpected_error());
+    }
+
+    Ok(value)
+}
+
+/// (value: unknown, indent?: number) => string
+fn stringify_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![
+            new_argument(position.clone(), "value", DataTypes::Unknown, false),
+            new_argument(position, "indent", DataTypes::Number, true),
+        ],
+        DataTypes::String,
+    )
+}
+
+/// Get the JSON string of the text.
+fn quote(text: &str) -> String {
+    let mut value = String::from("\"");
+
+    for character in text.chars() {
+        match character {
+            '"' => value.push_str("\\\""),
+            '\\' => value.push_str("\\\\"),
+            '\n' => value.push_str("\\n"),
+            '\r' => value.push_str("\\r"),
+            '\t' => value.push_str("\\t"),
+            '\u{8}' => value.push_str("\\b"),
+            '\u{c}' => value.push_str("\\f"),
+            character if character < ' ' => {
+                value.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => value.push(character),
+        }
+    }
+
+    value.push('"');
+    value
+}
+
+/// Write the JSON of the items of an array (without keys) or an object.
+fn write_items(
+    object: &Object,
+    items: Vec<(Option<String>, Object)>,
+    brackets: (char, char),
+    indent: &str,
+    depth: usize,
+    output: &mut String,
+) -> Result<(), Error> {
+    // The limit also stops the values that contain themselves.
+    if depth == MAX_DEPTH {
+        return Err(Error::new_runtime(
+            object.get_position(),
+            &format!(
+                "The value has more than {} nested arrays and objects.",
+                MAX_DEPTH
+            ),
+        ));
+    }
+
+    output.push(brackets.0);
+
+    for (index, (key, value)) in items.iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+
+        if !indent.is_empty() {
+            output.push('\n');
+            output.push_str(&indent.repeat(depth + 1));
+        }
+
+        if let Some(key) = key {
+            output.push_str(&quote(key));
+            output.push(':');
+
+            if !indent.is_empty() {
+                output.push(' ');
+            }
+        }
+
+        write_value(value, indent, depth + 1, output)?;
+    }
+
+    if !indent.is_empty() && !items.is_empty() {
+        output.push('\n');
+        output.push_str(&indent.repeat(depth));
+    }
+
+    output.push(brackets.1);
+
+    Ok(())
+}
+
+/// Write the JSON of the value.
+fn write_value(
+    object: &Object,
+    indent: &str,
+    depth: usize,
+    output: &mut String,
+) -> Result<(), Error> {
+    let new_error = || {
+        Error::new_runtime(
+            object.get_position(),
+            &format!(
+                "The `{}` value cannot be converted to JSON.",
+                object.to_string()
+            ),
+        )
+    };
+
+    let node = object.get_node();
+
+    if let Some(values) = node.get_array().or_else(|| node.get_tuple()) {
+        return write_items(
+            object,
+            values.into_iter().map(|value| (None, value)).collect(),
+            ('[', ']'),
+            indent,
+            depth,
+            output,
+        );
+    }
+
+    match node {
+        Objects::Boolean(value) => {
+            output.push_str(&value.to_string());
+            Ok(())
+        }
+        Objects::HashMap(data) => write_items(
+            object,
+            data.borrow()
+                .iter()
+                .map(|(key, value)| (Some(key.clone()), value.clone()))
+                .collect(),
+            ('{', '}'),
+            indent,
+            depth,
+            output,
+        ),
+        Objects::Instance { .. } => write_items(
+            object,
+            object
+                .get_node()
+                .get_instance_fields()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, value)| (Some(key), value))
+                .collect(),
+            ('{', '}'),
+            indent,
+            depth,
+            output,
+        ),
+        Objects::Null(_) => {
+            output.push_str("null");
+            Ok(())
+        }
+        Objects::Number(value) if value.is_finite() => {
+            output.push_str(&value.to_string());
+            Ok(())
+        }
+        Objects::Return(value) => write_value(&value, indent, depth, output),
+        Objects::String(value) => {
+            output.push_str(&quote(&value));
+            Ok(())
+        }
+        _ => Err(new_error()),
+    }
+}
+
+/// Get the JSON text of the value, indenting the items of the arrays and the
+/// objects with the number of spaces (up to 10) when it is not zero.
+fn stringify(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = arguments.first().ok_or_else(|| {
+        Error::new_missing_argument(position.clone(), String::from("value"))
+    })?;
+    let indent = if arguments.len() > 1 {
+        get_number_argument(&arguments, 1, "indent", position.clone())?
+    } else {
+        0.0
+    };
+    let mut output = String::new();
+
+    // The negative indentations are converted to zero.
+    write_value(
+        value,
+        &" ".repeat(indent.min(MAX_INDENT) as usize),
+        0,
+        &mut output,
+    )?;
+
+    Ok(Object::new(position, Objects::String(output)))
+}
+
+#[test]
+fn test_json() {
+    use crate::test_utils::{get_value, run_code, run_code_in};
+    use sflynlang_parser::ErrorType;
+    use std::io::Cursor;
+
+    let run_load = |code: &str, input: &str| {
+        let mut environment = Environment::new();
+
+        environment.set_reader(Box::new(Cursor::new(String::from(input))));
+
+        run_code_in(
+            &format!(
+                "interface User {{ name: string; tags: string[]; email: string?; }}\nfunc load(text: string): string {{\ntry {{\nconst user: User = JSON.parse(text);\nreturn JSON.stringify(user);\n}} catch (error) {{\nreturn error;\n}}\n}}\n{}",
+                code
+            ),
+            &mut environment,
+        )
+        .map(|_| get_value(&mut environment, "value"))
+    };
+
+    assert_eq!(
+        run_load(
+            "const value = load('{ \"tags\": [\"a\"], \"name\": \"Sflyn\" }');",
+            ""
+        )
+        .unwrap(),
+        Some(String::from(
+            r#"{"name":"Sflyn","tags":["a"],"email":null}"#
+        )),
+    );
+    assert_eq!(
+        run_load(
+            "const value = load('{ \"name\": \"Sflyn\", \"tags\": [1] }');",
+            ""
+        )
+        .unwrap(),
+        Some(String::from(
+            "The JSON value does not match `User`: expect `string` at `$.tags[0]`, found number."
+        )),
+    );
+    assert_eq!(
+        run_load("const value = load(readAll());", "[1,\n  2 3]").unwrap(),
+        Some(String::from(
+            "Invalid JSON at line 2, column 5: unexpected `3`."
+        )),
+    );
+    assert_eq!(
+        run_load(
+            "const value = JSON.stringify(JSON.parse('{\"a\": [1.5, true, null]}'), 2);",
+            ""
+        )
+        .unwrap(),
+        Some(String::from(
+            "{\n  \"a\": [\n    1.5,\n    true,\n    null\n  ]\n}"
+        )),
+    );
+
+    assert_error!(
+        run_code("const value: () => void = JSON.parse('1');"),
+        ErrorType::Semantic(_)
+    );
+
+    // The values can have up to 512 nested arrays and objects (the debug
+    // builds need a bigger stack than the one of the test threads).
+    let nested =
+        |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+    std::thread::Builder::new()
+        .stack_size(8 * 1024 * 1024)
+        .spawn(move || {
+            let mut environment = run_code(&format!(
+                "const text = '{}';\nconst value = JSON.stringify(JSON.parse(text));",
+                nested(MAX_DEPTH)
+            ))
+            .unwrap();
+
+            assert_eq!(
+                get_value(&mut environment, "value"),
+                Some(nested(MAX_DEPTH))
+            );
+            assert_eq!(
+                run_load(
+                    &format!("const value = load('{}');", nested(MAX_DEPTH + 1)),
+                    ""
+                )
+                .unwrap(),
+                Some(format!(
+                    "Invalid JSON at line 1, column {}: more than {} nested arrays and objects.",
+                    MAX_DEPTH + 1,
+                    MAX_DEPTH
+                )),
+            );
+            assert_error!(
+                run_code(&format!(
+                    "JSON.stringify([JSON.parse('{}')]);",
+                    nested(MAX_DEPTH)
+                )),
+                ErrorType::Runtime(_)
+            );
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
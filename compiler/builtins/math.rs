@@ -0,0 +1,460 @@
+use crate::{
+    builtins::{
+        get_number_argument, new_argument, new_function_type, Function,
+        Namespace,
+    },
+    Environment, Object, Objects,
+};
+use sflynlang_parser::{
+    ast::{DataType, DataTypes},
+    Error, Position,
+};
+use std::f64::consts;
+
+pub const NAMESPACE: Namespace = Namespace {
+    name: "Math",
+    functions: &[
+        Function {
+            name: "abs",
+            get_type: unary_type,
+            eval: abs,
+        },
+        Function {
+            name: "acos",
+            get_type: unary_type,
+            eval: acos,
+        },
+        Function {
+            name: "asin",
+            get_type: unary_type,
+            eval: asin,
+        },
+        Function {
+            name: "atan",
+            get_type: unary_type,
+            eval: atan,
+        },
+        Function {
+            name: "atan2",
+            get_type: atan2_type,
+            eval: atan2,
+        },
+        Function {
+            name: "ceil",
+            get_type: unary_type,
+            eval: ceil,
+        },
+        Function {
+            name: "cos",
+            get_type: unary_type,
+            eval: cos,
+        },
+        Function {
+            name: "exp",
+            get_type: unary_type,
+            eval: exp,
+        },
+        Function {
+            name: "floor",
+            get_type: unary_type,
+            eval: floor,
+        },
+        Function {
+            name: "log",
+            get_type: unary_type,
+            eval: log,
+        },
+        Function {
+            name: "max",
+            get_type: values_type,
+            eval: max,
+        },
+        Function {
+            name: "min",
+            get_type: values_type,
+            eval: min,
+        },
+        Function {
+            name: "pow",
+            get_type: pow_type,
+            eval: pow,
+        },
+        Function {
+            name: "random",
+            get_type: random_type,
+            eval: random,
+        },
+        Function {
+            name: "round",
+            get_type: unary_type,
+            eval: round,
+        },
+        Function {
+            name: "seed",
+            get_type: seed_type,
+            eval: seed,
+        },
+        Function {
+            name: "sin",
+            get_type: unary_type,
+            eval: sin,
+        },
+        Function {
+            name: "sqrt",
+            get_type: unary_type,
+            eval: sqrt,
+        },
+        Function {
+            name: "tan",
+            get_type: unary_type,
+            eval: tan,
+        },
+    ],
+    constants: &[("E", consts::E), ("PI", consts::PI)],
+};
+
+/// Get the random generator state of a seed, which is never zero.
+pub fn get_seed_state(seed: u64) -> u64 {
+    // Mix the seed bits (SplitMix64) to avoid similar sequences for similar
+    // seeds.
+    let mut state = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    state ^= state >> 31;
+
+    if state == 0 {
+        1
+    } else {
+        state
+    }
+}
+
+/// Get the function data type of the number arguments.
+fn new_numbers_type(position: Position, names: &[&str]) -> DataType {
+    new_function_type(
+        position.clone(),
+        names
+            .iter()
+            .map(|name| {
+                new_argument(position.clone(), name, DataTypes::Number, false)
+            })
+            .collect(),
+        DataTypes::Number,
+    )
+}
+
+/// Apply the operation to the number argument.
+fn apply(
+    arguments: &[Object],
+    position: Position,
+    operation: fn(f64) -> f64,
+) -> Result<Object, Error> {
+    let value = get_number_argument(arguments, 0, "value", position.clone())?;
+
+    Ok(Object::new(position, Objects::Number(operation(value))))
+}
+
+/// Get the number values of the spread arguments.
+fn get_values(
+    arguments: &[Object],
+    position: Position,
+) -> Result<Vec<f64>, Error> {
+    (0..arguments.len())
+        .map(|index| {
+            get_number_argument(arguments, index, "values", position.clone())
+        })
+        .collect()
+}
+
+/// (value: number) => number
+fn unary_type(position: Position) -> DataType {
+    new_numbers_type(position, &["value"])
+}
+
+fn abs(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::abs)
+}
+
+fn acos(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::acos)
+}
+
+fn asin(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::asin)
+}
+
+fn atan(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::atan)
+}
+
+/// (y: number, x: number) => number
+fn atan2_type(position: Position) -> DataType {
+    new_numbers_type(position, &["y", "x"])
+}
+
+fn atan2(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let y = get_number_argument(&arguments, 0, "y", position.clone())?;
+    let x = get_number_argument(&arguments, 1, "x", position.clone())?;
+
+    Ok(Object::new(position, Objects::Number(y.atan2(x))))
+}
+
+fn ceil(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::ceil)
+}
+
+fn cos(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::cos)
+}
+
+fn exp(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::exp)
+}
+
+fn floor(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::floor)
+}
+
+/// Get the natural logarithm of the value.
+fn log(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::ln)
+}
+
+/// (...values: number[]) => number
+fn values_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![DataType::new(
+            position.clone(),
+            DataTypes::Spread(Box::new(new_argument(
+                position.clone(),
+                "values",
+                DataTypes::Array(Box::new(DataType::new(
+                    position,
+                    DataTypes::Number,
+                ))),
+                false,
+            ))),
+        )],
+        DataTypes::Number,
+    )
+}
+
+/// Get the greatest value (`-Infinity` without values).
+fn max(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let values = get_values(&arguments, position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::Number(values.into_iter().fold(f64::NEG_INFINITY, f64::max)),
+    ))
+}
+
+/// Get the smallest value (`Infinity` without values).
+fn min(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let values = get_values(&arguments, position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::Number(values.into_iter().fold(f64::INFINITY, f64::min)),
+    ))
+}
+
+/// (base: number, exponent: number) => number
+fn pow_type(position: Position) -> DataType {
+    new_numbers_type(position, &["base", "exponent"])
+}
+
+fn pow(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let base = get_number_argument(&arguments, 0, "base", position.clone())?;
+    let exponent =
+        get_number_argument(&arguments, 1, "exponent", position.clone())?;
+
+    Ok(Object::new(position, Objects::Number(base.powf(exponent))))
+}
+
+/// () => number
+fn random_type(position: Position) -> DataType {
+    new_numbers_type(position, &[])
+}
+
+/// Get a random number between zero (included) and one (excluded), from the
+/// xorshift64* generator of the environment.
+fn random(
+    _arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let mut state = environment.get_random_state();
+
+    state ^= state >> 12;
+    state ^= state << 25;
+    state ^= state >> 27;
+
+    environment.set_random_state(state);
+
+    // Use the 53 upper bits as the mantissa of the number.
+    let value = (state.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64
+        / (1u64 << 53) as f64;
+
+    Ok(Object::new(position, Objects::Number(value)))
+}
+
+fn round(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::round)
+}
+
+/// (value: number) => void
+fn seed_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(position, "value", DataTypes::Number, false)],
+        DataTypes::Void,
+    )
+}
+
+/// Restart the random numbers from the seed, to repeat the same sequence.
+fn seed(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_number_argument(&arguments, 0, "value", position.clone())?;
+
+    environment.set_random_state(get_seed_state(value.to_bits()));
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+fn sin(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::sin)
+}
+
+fn sqrt(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::sqrt)
+}
+
+fn tan(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    apply(&arguments, position, f64::tan)
+}
+
+#[test]
+fn test_math() {
+    use crate::test_utils::{check_code, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    assert!(check_code("const root: number = Math.sqrt(2.25);").is_ok());
+    assert!(check_code("const area: number = Math.PI * 1.5 ** 2;").is_ok());
+    assert!(check_code(
+        "const max = 'a';\nconst value: number = Math.max(1, 2, 3);"
+    )
+    .is_ok());
+    assert_error!(check_code("Math.sqrt('4');"), ErrorType::ExpectType(_, _));
+    assert_error!(check_code("Math.cbrt(8);"), ErrorType::UnknownIdentifier(_));
+
+    let mut environment = run_code(
+        "const root = Math.sqrt(2.25);\nconst rounded = Math.round(2.5);\nconst floor = Math.floor(-1.5);\nconst max = Math.max(1, 2.5);",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("root"), Some(String::from("1.5")));
+    assert_eq!(get_value("rounded"), Some(String::from("3")));
+    assert_eq!(get_value("floor"), Some(String::from("-2")));
+    assert_eq!(get_value("max"), Some(String::from("2.5")));
+}
+
+#[test]
+fn test_numbers() {
+    use crate::test_utils::{get_value, run_code};
+
+    // The numbers are floats and the indexes ignore their decimals.
+    let mut environment = run_code(
+        "const sum = 0.1 + 0.2;\nconst half = 7 / 2;\nconst rest = 7 % 2.5;\nconst power = 2 ** 0.5;\nconst infinite = 1 / 0;\nconst invalid = 0 / 0;\nconst text = 2.5.toString();\nconst items = [1, 2, 3].slice(1.7);\nconst last = 'Sflyn'.slice(-2.5);\nconst large = 1000000 * 1000000 * 1000000 * 1000000;",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("sum"), Some(String::from("0.30000000000000004")));
+    assert_eq!(get_value("half"), Some(String::from("3.5")));
+    assert_eq!(get_value("rest"), Some(String::from("2")));
+    assert_eq!(get_value("power"), Some(String::from("1.4142135623730951")));
+    assert_eq!(get_value("infinite"), Some(String::from("inf")));
+    assert_eq!(get_value("invalid"), Some(String::from("NaN")));
+    assert_eq!(get_value("text"), Some(String::from("2.5")));
+    assert_eq!(get_value("items"), Some(String::from("[2, 3]")));
+    assert_eq!(get_value("last"), Some(String::from("yn")));
+    assert_eq!(
+        get_value("large"),
+        Some(String::from("1000000000000000000000000"))
+    );
+}
@@ -1,59 +1,60 @@
-use crate::{Environment, Object, Objects};
+use crate::{builtins::Method, Environment, Object, Objects};
 use sflynlang_parser::{
     ast::{DataType, DataTypes},
     Error, Position,
 };
-use std::collections::HashMap;
 
-pub fn get_hashmap_typechecker() -> HashMap<String, DataType> {
-    let mut data: HashMap<String, DataType> = HashMap::new();
+pub const METHODS: &[Method] = &[Method {
+    name: "toString",
+    get_type: to_string_type,
+    eval: to_string,
+}];
 
-    data.insert(
-        String::from("toString"),
-        DataType::new(
-            Position::new(0, 0, 1, 1),
-            DataTypes::Function(
-                Vec::new(),
-                Box::new(DataType::new(
-                    Position::new(0, 0, 1, 1),
-                    DataTypes::String,
-                )),
-            ),
-        ),
-    );
+/// () => string
+fn to_string_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    if !receiver.node.is_number() {
+        return None;
+    }
 
-    data
+    Some(super::new_function_type(
+        position,
+        Vec::new(),
+        DataTypes::String,
+    ))
 }
 
-pub fn get_hashmap_compiler(
-    object: Object,
-    environment: Environment,
-) -> Result<HashMap<String, Object>, Error> {
-    if let Some(number) = object.get_node().get_number() {
-        let mut data: HashMap<String, Object> = HashMap::new();
+fn to_string(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    match receiver.get_node().get_number() {
+        Some(number) => {
+            Ok(Object::new(position, Objects::String(number.to_string())))
+        }
+        None => Err(Error::new_expect_type(
+            receiver.get_position(),
+            "number",
+            &receiver.to_data_type().to_string(),
+        )),
+    }
+}
 
-        data.insert(
-            String::from("toString"),
-            Object::new(
-                Position::new(0, 0, 1, 1),
-                Objects::Function {
-                    arguments: HashMap::new(),
-                    body: Vec::new(),
-                    return_obj: Box::new(Object::new(
-                        object.get_position(),
-                        Objects::String(number.to_string()),
-                    )),
-                    environment,
-                },
-            ),
-        );
+#[test]
+fn test_builtin_methods() {
+    use crate::test_utils::check_with;
+    use sflynlang_parser::ErrorType;
 
-        return Ok(data);
-    }
+    let check_method = |code: &str| check_with("const size = 10;", code);
 
-    Err(Error::new_expect_type(
-        object.get_position(),
-        "Number",
-        &object.to_string(),
-    ))
+    assert!(check_method("const text: string = size.toString();").is_ok());
+    assert_error!(
+        check_method("const text: number = size.toString();"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_method("size.toString(2);"),
+        ErrorType::ExpectArguments(_, _)
+    );
 }
@@ -0,0 +1,144 @@
+use crate::{
+    builtins::{
+        get_number_argument, get_string_argument, new_argument,
+        new_function_type, Function, Namespace,
+    },
+    Environment, Object, Objects,
+};
+use sflynlang_parser::{
+    ast::{DataType, DataTypes},
+    Error, Position,
+};
+use std::env;
+
+pub const NAMESPACE: Namespace = Namespace {
+    name: "process",
+    functions: &[
+        Function {
+            name: "args",
+            get_type: args_type,
+            eval: args,
+        },
+        Function {
+            name: "env",
+            get_type: env_type,
+            eval: get_env,
+        },
+        Function {
+            name: "exit",
+            get_type: exit_type,
+            eval: exit,
+        },
+    ],
+    constants: &[],
+};
+
+/// () => string[]
+fn args_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        Vec::new(),
+        DataTypes::Array(Box::new(DataType::new(position, DataTypes::String))),
+    )
+}
+
+/// Get the arguments of the program (the ones after the file in the CLI).
+fn args(
+    _arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    Ok(Object::new(
+        position.clone(),
+        Objects::new_array(
+            environment
+                .get_args()
+                .iter()
+                .map(|arg| {
+                    Object::new(position.clone(), Objects::String(arg.clone()))
+                })
+                .collect(),
+        ),
+    ))
+}
+
+/// (name: string) => string?
+fn env_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(
+            position.clone(),
+            "name",
+            DataTypes::String,
+            false,
+        )],
+        DataTypes::Option(Box::new(DataType::new(position, DataTypes::String))),
+    )
+}
+
+/// Get the value of the environment variable or `null` when it is missing.
+fn get_env(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let name = get_string_argument(&arguments, 0, "name", position.clone())?;
+
+    Ok(match env::var(name) {
+        Ok(value) => Object::new(position, Objects::String(value)),
+        Err(_) => Object::new(
+            position.clone(),
+            Objects::Null(Box::new(Object::new(
+                position,
+                Objects::String(String::new()),
+            ))),
+        ),
+    })
+}
+
+/// (code: number) => void
+fn exit_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(position, "code", DataTypes::Number, false)],
+        DataTypes::Void,
+    )
+}
+
+/// Stop the program with the exit code, ignoring its decimals.
+fn exit(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let code = get_number_argument(&arguments, 0, "code", position.clone())?;
+
+    Err(Error::new_exit(position, code as i32))
+}
+
+#[test]
+fn test_process() {
+    use crate::test_utils::{get_value, run_code_in};
+    use sflynlang_parser::ErrorType;
+
+    let mut environment = Environment::new();
+
+    environment.set_args(vec![String::from("--name"), String::from("Sflyn")]);
+
+    let result = run_code_in(
+        "const args: string[] = process.args();\nconst home: string? = process.env('SFLYN_MISSING_VARIABLE');\ntry {\nprocess.exit(3);\n} catch (error) {\nprintln(error);\n}",
+        &mut environment,
+    );
+
+    assert_eq!(
+        get_value(&mut environment, "args"),
+        Some(String::from("[\"--name\", \"Sflyn\"]"))
+    );
+    assert_eq!(
+        get_value(&mut environment, "home"),
+        Some(String::from("null"))
+    );
+
+    // The exit is not caught by the try.
+    assert_error!(result, ErrorType::Exit(3));
+}
@@ -0,0 +1,597 @@
+use crate::{
+    builtins::{
+        get_index, get_number_argument, get_string_argument, new_argument,
+        new_function_type, Method,
+    },
+    Environment, Object, Objects,
+};
+use sflynlang_parser::{
+    ast::{DataType, DataTypes},
+    Error, Position,
+};
+
+/// Maximum length in bytes of a repeated string.
+const MAX_LENGTH: usize = 1 << 24;
+
+pub const METHODS: &[Method] = &[
+    Method {
+        name: "chars",
+        get_type: chars_type,
+        eval: chars,
+    },
+    Method {
+        name: "contains",
+        get_type: contains_type,
+        eval: contains,
+    },
+    Method {
+        name: "endsWith",
+        get_type: ends_with_type,
+        eval: ends_with,
+    },
+    Method {
+        name: "indexOf",
+        get_type: index_of_type,
+        eval: index_of,
+    },
+    Method {
+        name: "length",
+        get_type: length_type,
+        eval: length,
+    },
+    Method {
+        name: "parseNumber",
+        get_type: parse_number_type,
+        eval: parse_number,
+    },
+    Method {
+        name: "repeat",
+        get_type: repeat_type,
+        eval: repeat,
+    },
+    Method {
+        name: "replace",
+        get_type: replace_type,
+        eval: replace,
+    },
+    Method {
+        name: "slice",
+        get_type: slice_type,
+        eval: slice,
+    },
+    Method {
+        name: "split",
+        get_type: split_type,
+        eval: split,
+    },
+    Method {
+        name: "startsWith",
+        get_type: starts_with_type,
+        eval: starts_with,
+    },
+    Method {
+        name: "toLowerCase",
+        get_type: to_lower_case_type,
+        eval: to_lower_case,
+    },
+    Method {
+        name: "toUpperCase",
+        get_type: to_upper_case_type,
+        eval: to_upper_case,
+    },
+    Method {
+        name: "trim",
+        get_type: trim_type,
+        eval: trim,
+    },
+];
+
+/// Get the function data type of a method when the receiver is a string.
+fn new_method_type(
+    receiver: &DataType,
+    position: Position,
+    arguments: Vec<(&str, DataTypes, bool)>,
+    return_type: DataTypes,
+) -> Option<DataType> {
+    if !receiver.node.is_string() {
+        return None;
+    }
+
+    Some(new_function_type(
+        position.clone(),
+        arguments
+            .into_iter()
+            .map(|(name, data_type, is_optional)| {
+                new_argument(position.clone(), name, data_type, is_optional)
+            })
+            .collect(),
+        return_type,
+    ))
+}
+
+/// Get the string value of the receiver.
+fn get_receiver(receiver: &Object) -> Result<String, Error> {
+    receiver.get_node().get_string().ok_or_else(|| {
+        Error::new_expect_type(
+            receiver.get_position(),
+            "string",
+            &receiver.to_data_type().to_string(),
+        )
+    })
+}
+
+/// Get the array object of the strings.
+fn new_strings_array(values: Vec<String>, position: Position) -> Object {
+    Object::new(
+        position.clone(),
+        Objects::new_array(
+            values
+                .into_iter()
+                .map(|value| {
+                    Object::new(position.clone(), Objects::String(value))
+                })
+                .collect(),
+        ),
+    )
+}
+
+/// () => string[]
+fn chars_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position.clone(),
+        Vec::new(),
+        DataTypes::Array(Box::new(DataType::new(position, DataTypes::String))),
+    )
+}
+
+fn chars(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+
+    Ok(new_strings_array(
+        value
+            .chars()
+            .map(|character| character.to_string())
+            .collect(),
+        position,
+    ))
+}
+
+/// (value: string) => boolean
+fn contains_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position,
+        vec![("value", DataTypes::String, false)],
+        DataTypes::Boolean,
+    )
+}
+
+fn contains(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+    let other = get_string_argument(&arguments, 0, "value", position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::Boolean(value.contains(other.as_str())),
+    ))
+}
+
+/// (value: string) => boolean
+fn ends_with_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position,
+        vec![("value", DataTypes::String, false)],
+        DataTypes::Boolean,
+    )
+}
+
+fn ends_with(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+    let other = get_string_argument(&arguments, 0, "value", position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::Boolean(value.ends_with(other.as_str())),
+    ))
+}
+
+/// (value: string) => number
+fn index_of_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position,
+        vec![("value", DataTypes::String, false)],
+        DataTypes::Number,
+    )
+}
+
+/// Get the characters index of the first occurrence or `-1`.
+fn index_of(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+    let other = get_string_argument(&arguments, 0, "value", position.clone())?;
+
+    let index = match value.find(other.as_str()) {
+        Some(byte_index) => value[..byte_index].chars().count() as f64,
+        None => -1.0,
+    };
+
+    Ok(Object::new(position, Objects::Number(index)))
+}
+
+/// () => number
+fn length_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(receiver, position, Vec::new(), DataTypes::Number)
+}
+
+/// Get the number of characters.
+fn length(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+
+    Ok(Object::new(
+        position,
+        Objects::Number(value.chars().count() as f64),
+    ))
+}
+
+/// () => number?
+fn parse_number_type(
+    receiver: &DataType,
+    position: Position,
+) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position.clone(),
+        Vec::new(),
+        DataTypes::Option(Box::new(DataType::new(position, DataTypes::Number))),
+    )
+}
+
+/// Get the number of the string or `null` when it is not a number.
+fn parse_number(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+
+    Ok(
+        match value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|number| number.is_finite())
+        {
+            Some(number) => Object::new(position, Objects::Number(number)),
+            None => Object::new(
+                position.clone(),
+                Objects::Null(Box::new(Object::new(
+                    position,
+                    Objects::Number(0.0),
+                ))),
+            ),
+        },
+    )
+}
+
+/// (count: number) => string
+fn repeat_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position,
+        vec![("count", DataTypes::Number, false)],
+        DataTypes::String,
+    )
+}
+
+fn repeat(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+    let count = get_number_argument(&arguments, 0, "count", position.clone())?;
+
+    if count < 0.0 {
+        return Err(Error::new_runtime(
+            arguments[0].get_position(),
+            "The repeat count cannot be negative.",
+        ));
+    }
+
+    let count = count as usize;
+
+    match value.len().checked_mul(count) {
+        Some(length) if length <= MAX_LENGTH => {
+            Ok(Object::new(position, Objects::String(value.repeat(count))))
+        }
+        _ => Err(Error::new_runtime(
+            arguments[0].get_position(),
+            &format!(
+                "The repeated string is longer than {} bytes.",
+                MAX_LENGTH
+            ),
+        )),
+    }
+}
+
+/// (pattern: string, replacement: string) => string
+fn replace_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position,
+        vec![
+            ("pattern", DataTypes::String, false),
+            ("replacement", DataTypes::String, false),
+        ],
+        DataTypes::String,
+    )
+}
+
+/// Replace every occurrence of the pattern.
+fn replace(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+    let pattern =
+        get_string_argument(&arguments, 0, "pattern", position.clone())?;
+    let replacement =
+        get_string_argument(&arguments, 1, "replacement", position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::String(value.replace(pattern.as_str(), replacement.as_str())),
+    ))
+}
+
+/// (start: number, end?: number) => string
+fn slice_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position,
+        vec![
+            ("start", DataTypes::Number, false),
+            ("end", DataTypes::Number, true),
+        ],
+        DataTypes::String,
+    )
+}
+
+/// Get the characters between the start and the end indexes, counting the
+/// negative indexes from the end.
+fn slice(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+    let length = value.chars().count();
+
+    let start = get_index(
+        get_number_argument(&arguments, 0, "start", position.clone())?,
+        length,
+    );
+
+    let end = if arguments.len() > 1 {
+        get_index(
+            get_number_argument(&arguments, 1, "end", position.clone())?,
+            length,
+        )
+    } else {
+        length
+    };
+
+    Ok(Object::new(
+        position,
+        Objects::String(
+            value
+                .chars()
+                .skip(start)
+                .take(end.saturating_sub(start))
+                .collect(),
+        ),
+    ))
+}
+
+/// (separator: string) => string[]
+fn split_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position.clone(),
+        vec![("separator", DataTypes::String, false)],
+        DataTypes::Array(Box::new(DataType::new(position, DataTypes::String))),
+    )
+}
+
+/// Split the string by the separator, or by characters when the separator
+/// is empty.
+fn split(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+    let separator =
+        get_string_argument(&arguments, 0, "separator", position.clone())?;
+
+    if separator.is_empty() {
+        return chars(receiver, arguments, position, environment);
+    }
+
+    Ok(new_strings_array(
+        value
+            .split(separator.as_str())
+            .map(|value| value.to_string())
+            .collect(),
+        position,
+    ))
+}
+
+/// (value: string) => boolean
+fn starts_with_type(
+    receiver: &DataType,
+    position: Position,
+) -> Option<DataType> {
+    new_method_type(
+        receiver,
+        position,
+        vec![("value", DataTypes::String, false)],
+        DataTypes::Boolean,
+    )
+}
+
+fn starts_with(
+    receiver: &Object,
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+    let other = get_string_argument(&arguments, 0, "value", position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::Boolean(value.starts_with(other.as_str())),
+    ))
+}
+
+/// () => string
+fn to_lower_case_type(
+    receiver: &DataType,
+    position: Position,
+) -> Option<DataType> {
+    new_method_type(receiver, position, Vec::new(), DataTypes::String)
+}
+
+fn to_lower_case(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+
+    Ok(Object::new(position, Objects::String(value.to_lowercase())))
+}
+
+/// () => string
+fn to_upper_case_type(
+    receiver: &DataType,
+    position: Position,
+) -> Option<DataType> {
+    new_method_type(receiver, position, Vec::new(), DataTypes::String)
+}
+
+fn to_upper_case(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+
+    Ok(Object::new(position, Objects::String(value.to_uppercase())))
+}
+
+/// () => string
+fn trim_type(receiver: &DataType, position: Position) -> Option<DataType> {
+    new_method_type(receiver, position, Vec::new(), DataTypes::String)
+}
+
+fn trim(
+    receiver: &Object,
+    _arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let value = get_receiver(receiver)?;
+
+    Ok(Object::new(
+        position,
+        Objects::String(value.trim().to_string()),
+    ))
+}
+
+#[test]
+fn test_string_methods() {
+    use crate::test_utils::{check_with, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    let check_method = |code: &str| check_with("const text = 'Sflyn';", code);
+
+    assert!(check_method("const words: string[] = text.split(' ');").is_ok());
+    assert!(check_method("const part: string = text.slice(1);").is_ok());
+    assert!(check_method("const size: number? = text.parseNumber();").is_ok());
+    assert_error!(
+        check_method("const size: number = text.parseNumber();"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_method("text.repeat('3');"),
+        ErrorType::ExpectType(_, _)
+    );
+
+    // The strings are indexed by their characters.
+    let mut environment = run_code(
+        "const text = 'héllo wörld 日本';\nconst size = text.length();\nconst chars = 'ñ日'.chars();\nconst part = text.slice(1, 4);\nconst last = text.slice(-2);\nconst index = text.indexOf('wö');\nconst upper = text.toUpperCase();\nconst words = text.split(' ');\nconst trimmed = '  ñ  '.trim();\nconst sharp = 'ß'.toUpperCase();",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("size"), Some(String::from("14")));
+    assert_eq!(get_value("chars"), Some(String::from("[\"ñ\", \"日\"]")));
+    assert_eq!(get_value("part"), Some(String::from("éll")));
+    assert_eq!(get_value("last"), Some(String::from("日本")));
+    assert_eq!(get_value("index"), Some(String::from("6")));
+    assert_eq!(get_value("upper"), Some(String::from("HÉLLO WÖRLD 日本")));
+    assert_eq!(
+        get_value("words"),
+        Some(String::from("[\"héllo\", \"wörld\", \"日本\"]"))
+    );
+    assert_eq!(get_value("trimmed"), Some(String::from("ñ")));
+    assert_eq!(get_value("sharp"), Some(String::from("SS")));
+
+    // The repeated strings are limited in length.
+    assert!(run_code("const line = '-'.repeat(80);").is_ok());
+    assert_error!(
+        run_code("const line = 'ab'.repeat(10000000000);"),
+        ErrorType::Runtime(_)
+    );
+    assert_error!(
+        run_code("const line = 'ab'.repeat(100000000000000000000);"),
+        ErrorType::Runtime(_)
+    );
+}
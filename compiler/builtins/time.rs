@@ -0,0 +1,704 @@
+use crate::{
+    builtins::{
+        get_number_argument, get_string_argument, new_argument,
+        new_function_type, Function, Namespace,
+    },
+    Environment, Object, Objects,
+};
+use sflynlang_parser::{
+    ast::{DataType, DataTypes},
+    Error, Position,
+};
+use std::{
+    fmt, thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// The clock of the `time` functions, which can be replaced in the environment
+/// (like a fixed clock to test the scripts).
+pub trait Clock: fmt::Debug {
+    /// Get the milliseconds since the Unix epoch.
+    fn now(&self) -> f64;
+
+    /// Get the milliseconds since a fixed instant, which never decrease.
+    fn monotonic(&self) -> f64;
+
+    /// Wait the milliseconds.
+    fn sleep(&self, milliseconds: f64);
+}
+
+/// The clock of the operating system.
+#[derive(Debug)]
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> f64 {
+        match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs_f64() * 1000.0,
+            Err(error) => -error.duration().as_secs_f64() * 1000.0,
+        }
+    }
+
+    fn monotonic(&self) -> f64 {
+        self.start.elapsed().as_secs_f64() * 1000.0
+    }
+
+    fn sleep(&self, milliseconds: f64) {
+        // The cast saturates the values out of range (and gets zero for NaN).
+        let microseconds = (milliseconds * 1000.0) as u64;
+
+        if microseconds > 0 {
+            thread::sleep(Duration::from_micros(microseconds));
+        }
+    }
+}
+
+pub const NAMESPACE: Namespace = Namespace {
+    name: "time",
+    functions: &[
+        Function {
+            name: "date",
+            get_type: date_type,
+            eval: date,
+        },
+        Function {
+            name: "format",
+            get_type: format_type,
+            eval: format,
+        },
+        Function {
+            name: "monotonic",
+            get_type: clock_type,
+            eval: monotonic,
+        },
+        Function {
+            name: "now",
+            get_type: clock_type,
+            eval: now,
+        },
+        Function {
+            name: "parse",
+            get_type: parse_type,
+            eval: parse,
+        },
+        Function {
+            name: "sleep",
+            get_type: sleep_type,
+            eval: sleep,
+        },
+        Function {
+            name: "timestamp",
+            get_type: timestamp_type,
+            eval: timestamp,
+        },
+    ],
+    constants: &[],
+};
+
+/// The fields of a `Date` value (in UTC).
+const DATE_FIELDS: &[&str] = &[
+    "year",
+    "month",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "millisecond",
+];
+
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+
+/// Maximum number of milliseconds of a timestamp (100 000 000 days from the
+/// Unix epoch, like the ECMAScript dates) or a sleep.
+const MAX_MILLISECONDS: f64 = 8_640_000_000_000_000.0;
+
+/// Check that the milliseconds are a finite number in the range of the dates.
+fn check_milliseconds(
+    milliseconds: f64,
+    name: &str,
+    position: Position,
+) -> Result<f64, Error> {
+    if !milliseconds.is_finite() || milliseconds.abs() > MAX_MILLISECONDS {
+        return Err(Error::new_runtime(
+            position,
+            &format!(
+                "The `{}` must be a number between -{} and {}.",
+                name, MAX_MILLISECONDS, MAX_MILLISECONDS
+            ),
+        ));
+    }
+
+    Ok(milliseconds)
+}
+
+/// Get the hashmap data type of the `Date` values.
+pub fn get_date_type(position: Position) -> DataType {
+    DataType::new(
+        position.clone(),
+        DataTypes::HashMap(
+            DATE_FIELDS
+                .iter()
+                .map(|field| {
+                    (
+                        String::from(*field),
+                        Box::new(DataType::new(
+                            position.clone(),
+                            DataTypes::Number,
+                        )),
+                    )
+                })
+                .collect(),
+        ),
+    )
+}
+
+/// Get the days since the Unix epoch of a civil date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year =
+        (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era =
+        year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Get the civil date (year, month, day) of the days since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+
+    (
+        year_of_era + era * 400 + if month <= 2 { 1 } else { 0 },
+        month,
+        day,
+    )
+}
+
+/// Get the number of days of the month.
+fn get_days_in_month(year: i64, month: i64) -> i64 {
+    days_from_civil(year + month / 12, month % 12 + 1, 1)
+        - days_from_civil(year, month, 1)
+}
+
+/// Get the `Date` value of the milliseconds since the Unix epoch.
+fn new_date(position: Position, timestamp: f64) -> Result<Object, Error> {
+    let timestamp =
+        check_milliseconds(timestamp, "timestamp", position.clone())?.floor()
+            as i64;
+    let (year, month, day) =
+        civil_from_days(timestamp.div_euclid(MILLISECONDS_PER_DAY));
+    let time = timestamp.rem_euclid(MILLISECONDS_PER_DAY);
+    let values = [
+        year,
+        month,
+        day,
+        time / 3_600_000,
+        time / 60_000 % 60,
+        time / 1000 % 60,
+        time % 1000,
+    ];
+
+    Ok(Object::new(
+        position.clone(),
+        Objects::new_hashmap(
+            DATE_FIELDS
+                .iter()
+                .zip(values.iter())
+                .map(|(field, value)| {
+                    (
+                        String::from(*field),
+                        Object::new(
+                            position.clone(),
+                            Objects::Number(*value as f64),
+                        ),
+                    )
+                })
+                .collect(),
+        ),
+    ))
+}
+
+/// Get the milliseconds since the Unix epoch of a `Date` argument.
+fn get_date_argument(
+    arguments: &[Object],
+    position: Position,
+) -> Result<f64, Error> {
+    let argument = arguments.first().ok_or_else(|| {
+        Error::new_missing_argument(position.clone(), String::from("date"))
+    })?;
+    let data = argument.get_node().get_hashmap().unwrap_or_default();
+    let mut values: Vec<f64> = Vec::new();
+
+    for field in DATE_FIELDS.iter() {
+        match data
+            .get(field)
+            .and_then(|value| value.get_node().get_number())
+        {
+            Some(value) => values.push(check_milliseconds(
+                value,
+                &format!("date.{}", field),
+                position.clone(),
+            )?),
+            None => {
+                return Err(Error::new_expect_type(
+                    argument.get_position(),
+                    &get_date_type(position).node.to_string(),
+                    &argument.to_data_type().to_string(),
+                ))
+            }
+        }
+    }
+
+    let days = days_from_civil(values[0] as i64, values[1] as i64, 1)
+        + values[2] as i64
+        - 1;
+
+    check_milliseconds(
+        days as f64 * MILLISECONDS_PER_DAY as f64
+            + values[3] * 3_600_000.0
+            + values[4] * 60_000.0
+            + values[5] * 1000.0
+            + values[6],
+        "date",
+        position,
+    )
+}
+
+/// Get the ISO 8601 string (`YYYY-MM-DDTHH:MM:SS.sssZ`) of the milliseconds
+/// since the Unix epoch.
+fn format_timestamp(timestamp: f64) -> String {
+    let timestamp = timestamp.floor() as i64;
+    let (year, month, day) =
+        civil_from_days(timestamp.div_euclid(MILLISECONDS_PER_DAY));
+    let time = timestamp.rem_euclid(MILLISECONDS_PER_DAY);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        time / 3_600_000,
+        time / 60_000 % 60,
+        time / 1000 % 60,
+        time % 1000
+    )
+}
+
+/// Read a number of the digits count at the index of the text.
+fn read_digits(text: &[u8], index: &mut usize, count: usize) -> Option<i64> {
+    let digits = text.get(*index..*index + count)?;
+
+    if !digits.iter().all(|digit| digit.is_ascii_digit()) {
+        return None;
+    }
+
+    *index += count;
+
+    Some(
+        digits
+            .iter()
+            .fold(0, |value, digit| value * 10 + i64::from(digit - b'0')),
+    )
+}
+
+/// Check if the character at the index of the text is the expected one and
+/// skip it.
+fn read_char(text: &[u8], index: &mut usize, expected: u8) -> bool {
+    if text.get(*index) == Some(&expected) {
+        *index += 1;
+        return true;
+    }
+
+    false
+}
+
+/// Parse an ISO 8601 date (`YYYY-MM-DD`) or date and time
+/// (`YYYY-MM-DDTHH:MM[:SS[.sss]][Z|±HH:MM]`, in UTC without a time zone) and
+/// get its milliseconds since the Unix epoch.
+fn parse_timestamp(text: &str) -> Option<f64> {
+    let text = text.as_bytes();
+    let mut index: usize = 0;
+
+    let year = read_digits(text, &mut index, 4)?;
+    let month = if read_char(text, &mut index, b'-') {
+        read_digits(text, &mut index, 2)?
+    } else {
+        return None;
+    };
+    let day = if read_char(text, &mut index, b'-') {
+        read_digits(text, &mut index, 2)?
+    } else {
+        return None;
+    };
+
+    if !(1..=12).contains(&month)
+        || !(1..=get_days_in_month(year, month)).contains(&day)
+    {
+        return None;
+    }
+
+    let mut time: i64 = 0;
+    let mut offset: i64 = 0;
+
+    if read_char(text, &mut index, b'T') || read_char(text, &mut index, b' ') {
+        let hour = read_digits(text, &mut index, 2)?;
+
+        if !read_char(text, &mut index, b':') {
+            return None;
+        }
+
+        let minute = read_digits(text, &mut index, 2)?;
+        let mut second: i64 = 0;
+        let mut millisecond: i64 = 0;
+
+        if read_char(text, &mut index, b':') {
+            second = read_digits(text, &mut index, 2)?;
+
+            if read_char(text, &mut index, b'.') {
+                let start = index;
+
+                while matches!(text.get(index), Some(digit) if digit.is_ascii_digit())
+                {
+                    index += 1;
+                }
+
+                // Keep the milliseconds of the fraction.
+                let fraction = &text[start..index];
+
+                if fraction.is_empty() {
+                    return None;
+                }
+
+                millisecond = (0..3).fold(0, |value, position| {
+                    value * 10
+                        + fraction
+                            .get(position)
+                            .map_or(0, |digit| i64::from(digit - b'0'))
+                });
+            }
+        }
+
+        if hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+
+        time = ((hour * 60 + minute) * 60 + second) * 1000 + millisecond;
+
+        // Read the time zone.
+        if !read_char(text, &mut index, b'Z') {
+            let sign = match text.get(index) {
+                Some(b'+') => 1,
+                Some(b'-') => -1,
+                _ => 0,
+            };
+
+            if sign != 0 {
+                index += 1;
+
+                let hours = read_digits(text, &mut index, 2)?;
+
+                read_char(text, &mut index, b':');
+
+                let minutes = read_digits(text, &mut index, 2)?;
+
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+
+                offset = sign * (hours * 60 + minutes) * 60_000;
+            }
+        }
+    }
+
+    if index != text.len() {
+        return None;
+    }
+
+    Some(
+        (days_from_civil(year, month, day) * MILLISECONDS_PER_DAY + time
+            - offset) as f64,
+    )
+}
+
+/// (timestamp?: number) => Date
+fn date_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(
+            position.clone(),
+            "timestamp",
+            DataTypes::Number,
+            true,
+        )],
+        get_date_type(position).node,
+    )
+}
+
+/// Get the date of the milliseconds since the Unix epoch (now by default).
+fn date(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let timestamp = if arguments.is_empty() {
+        environment.get_clock().now()
+    } else {
+        get_number_argument(&arguments, 0, "timestamp", position.clone())?
+    };
+
+    new_date(position, timestamp)
+}
+
+/// (date: Date) => string
+fn format_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(
+            position.clone(),
+            "date",
+            get_date_type(position).node,
+            false,
+        )],
+        DataTypes::String,
+    )
+}
+
+/// Get the ISO 8601 string of the date.
+fn format(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let timestamp = get_date_argument(&arguments, position.clone())?;
+
+    Ok(Object::new(
+        position,
+        Objects::String(format_timestamp(timestamp)),
+    ))
+}
+
+/// () => number
+fn clock_type(position: Position) -> DataType {
+    new_function_type(position, Vec::new(), DataTypes::Number)
+}
+
+/// Get the milliseconds since an arbitrary instant to measure durations.
+fn monotonic(
+    _arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    Ok(Object::new(
+        position,
+        Objects::Number(environment.get_clock().monotonic()),
+    ))
+}
+
+/// Get the milliseconds since the Unix epoch.
+fn now(
+    _arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    Ok(Object::new(
+        position,
+        Objects::Number(environment.get_clock().now()),
+    ))
+}
+
+/// (text: string) => Date
+fn parse_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(
+            position.clone(),
+            "text",
+            DataTypes::String,
+            false,
+        )],
+        get_date_type(position).node,
+    )
+}
+
+/// Get the date of an ISO 8601 string.
+fn parse(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let text = get_string_argument(&arguments, 0, "text", position.clone())?;
+    let timestamp = parse_timestamp(&text).ok_or_else(|| {
+        Error::new_runtime(
+            position.clone(),
+            &format!("`{}` is not an ISO 8601 date.", text),
+        )
+    })?;
+
+    new_date(position, timestamp)
+}
+
+/// (milliseconds: number) => void
+fn sleep_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(
+            position,
+            "milliseconds",
+            DataTypes::Number,
+            false,
+        )],
+        DataTypes::Void,
+    )
+}
+
+/// Wait the milliseconds (the negative ones do not wait).
+fn sleep(
+    arguments: Vec<Object>,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let milliseconds = check_milliseconds(
+        get_number_argument(&arguments, 0, "milliseconds", position.clone())?,
+        "milliseconds",
+        position.clone(),
+    )?;
+
+    environment.get_clock().sleep(milliseconds);
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+/// (date: Date) => number
+fn timestamp_type(position: Position) -> DataType {
+    new_function_type(
+        position.clone(),
+        vec![new_argument(
+            position.clone(),
+            "date",
+            get_date_type(position).node,
+            false,
+        )],
+        DataTypes::Number,
+    )
+}
+
+/// Get the milliseconds since the Unix epoch of the date.
+fn timestamp(
+    arguments: Vec<Object>,
+    position: Position,
+    _environment: &mut Environment,
+) -> Result<Object, Error> {
+    let timestamp = get_date_argument(&arguments, position.clone())?;
+
+    Ok(Object::new(position, Objects::Number(timestamp)))
+}
+
+#[test]
+fn test_time() {
+    use crate::test_utils::{check_code, get_value, run_code, run_code_in};
+    use sflynlang_parser::ErrorType;
+    use std::{cell::Cell, rc::Rc};
+
+    /// A clock that only moves when the program sleeps.
+    #[derive(Debug)]
+    struct FixedClock {
+        now: Cell<f64>,
+    }
+
+    impl Clock for FixedClock {
+        fn now(&self) -> f64 {
+            self.now.get()
+        }
+
+        fn monotonic(&self) -> f64 {
+            self.now.get()
+        }
+
+        fn sleep(&self, milliseconds: f64) {
+            self.now.set(self.now.get() + milliseconds);
+        }
+    }
+
+    let mut environment = Environment::new();
+
+    // 2026-10-19T00:00:00.000Z
+    environment.set_clock(Rc::new(FixedClock {
+        now: Cell::new(1_792_368_000_000.0),
+    }));
+
+    run_code_in(
+        "const start: number = time.monotonic();\ntime.sleep(1500);\nconst now: string = time.format(time.date());\nconst elapsed = time.monotonic() - start;\nconst leap: Date = time.parse('2024-02-29T23:59:59.5-01:00');\nconst next = time.format(leap);\nconst last = time.format(time.date(8640000000000000));",
+        &mut environment,
+    )
+    .unwrap();
+
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(
+        get_value("now"),
+        Some(String::from("2026-10-19T00:00:01.500Z"))
+    );
+    assert_eq!(get_value("elapsed"), Some(String::from("1500")));
+    assert_eq!(
+        get_value("next"),
+        Some(String::from("2024-03-01T00:59:59.500Z"))
+    );
+    assert_eq!(
+        get_value("last"),
+        Some(String::from("275760-09-13T00:00:00.000Z"))
+    );
+
+    assert_error!(
+        check_code("time.format('2026-10-19');"),
+        ErrorType::ExpectType(_, _)
+    );
+
+    // The values out of the range of the dates are runtime errors.
+    for code in [
+        "time.sleep(0 / 0);",
+        "time.sleep(1 / 0);",
+        "time.sleep(10000000000000000000000);",
+        "time.date(0 / 0);",
+        "time.date(-1 / 0);",
+        "time.date(8640000000000001);",
+        "time.timestamp({ year: 1 / 0, month: 1, day: 1, hour: 0, minute: 0, second: 0, millisecond: 0 });",
+        "time.parse('2026-10-19T00:00+24:00');",
+        "time.parse('2026-10-19T00:00-01:60');",
+    ]
+    .iter()
+    {
+        assert_error!(run_code(code), ErrorType::Runtime(_));
+    }
+}
@@ -1,22 +1,66 @@
 mod store;
 
-pub use store::Store;
+pub use store::{Member, Store};
 
+use crate::builtins::{
+    self,
+    time::{Clock, SystemClock},
+};
 use sflynlang_parser::{Error, File};
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+    rc::Rc,
+    time::SystemTime,
+};
+
+/// The input reader of the scripts (the standard input by default), shared by
+/// the environment copies.
+#[derive(Clone)]
+pub struct Reader(Rc<RefCell<Box<dyn BufRead>>>);
+
+impl fmt::Debug for Reader {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("Reader")
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct Environment {
     debug_mode: bool,
+    args: Rc<Vec<String>>,
+    reserved_path: fn(&Path) -> bool,
     store: Store,
     errors: Vec<Error>,
+
+    // Shared by the environment copies (like the function environments).
+    random_state: Rc<Cell<u64>>,
+    reader: Reader,
+    clock: Rc<dyn Clock>,
 }
 
 impl Environment {
     pub fn new() -> Self {
+        // Seed the random numbers with the current time.
+        let seed = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+
         Self {
             debug_mode: false,
+            args: Rc::new(Vec::new()),
+            reserved_path: |_| false,
             store: Store::new(),
             errors: Vec::new(),
+            random_state: Rc::new(Cell::new(builtins::math::get_seed_state(
+                seed,
+            ))),
+            reader: Reader(Rc::new(RefCell::new(Box::new(BufReader::new(
+                io::stdin(),
+            ))))),
+            clock: Rc::new(SystemClock::new()),
         }
     }
 
@@ -28,6 +72,78 @@ impl Environment {
         self.debug_mode = debug_mode;
     }
 
+    /// Get the arguments of the program (the ones after the file in the CLI).
+    pub fn get_args(&self) -> &[String] {
+        &self.args
+    }
+
+    pub fn set_args(&mut self, args: Vec<String>) {
+        self.args = Rc::new(args);
+    }
+
+    /// Check if the `fs` functions cannot use the path (every path can be used
+    /// by default).
+    pub fn is_reserved_path(&self, path: &Path) -> bool {
+        (self.reserved_path)(path)
+    }
+
+    pub fn set_reserved_path(&mut self, reserved_path: fn(&Path) -> bool) {
+        self.reserved_path = reserved_path;
+    }
+
+    pub fn get_random_state(&self) -> u64 {
+        self.random_state.get()
+    }
+
+    pub fn set_random_state(&mut self, random_state: u64) {
+        self.random_state.set(random_state);
+    }
+
+    /// Replace the input reader of the environment and its copies (like a
+    /// `Cursor` to test the scripts).
+    pub fn set_reader(&mut self, reader: Box<dyn BufRead>) {
+        *self.reader.0.borrow_mut() = reader;
+    }
+
+    /// Read the next line of the input without its line break or get `None`
+    /// at the end of the input.
+    pub fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+
+        if self.reader.0.borrow_mut().read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        if line.ends_with('\n') {
+            line.pop();
+
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+
+        Ok(Some(line))
+    }
+
+    /// Read the rest of the input.
+    pub fn read_all(&mut self) -> io::Result<String> {
+        let mut content = String::new();
+
+        self.reader.0.borrow_mut().read_to_string(&mut content)?;
+
+        Ok(content)
+    }
+
+    pub fn get_clock(&self) -> Rc<dyn Clock> {
+        Rc::clone(&self.clock)
+    }
+
+    /// Replace the clock of the `time` functions (like a fixed clock to test
+    /// the scripts).
+    pub fn set_clock(&mut self, clock: Rc<dyn Clock>) {
+        self.clock = clock;
+    }
+
     pub fn get_store(&mut self) -> &mut Store {
         &mut self.store
     }
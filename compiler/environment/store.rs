@@ -1,11 +1,31 @@
-use crate::Object;
-use sflynlang_parser::ast::DataType;
+use crate::{builtins, Object};
+use sflynlang_parser::ast::{DataType, Expression};
 use std::collections::HashMap;
 
+/// A method implemented (or declared by an interface) for the data types
+/// named as the first value (like `Label` for `impl Label`) or, when they do
+/// not have a name, the data types that can be assigned to the second value.
+type Method<T> = (Option<String>, DataType, String, T);
+
+/// A field or a method of a class.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub is_method: bool,
+    pub is_public: bool,
+    pub data_type: DataType,
+}
+
 #[derive(Clone, Debug)]
 pub struct Store {
     data_types: HashMap<String, DataType>,
     objects: HashMap<String, Object>,
+    type_definitions: HashMap<String, DataType>,
+    type_names: HashMap<String, String>,
+
+    methods: Vec<Method<DataType>>,
+    method_objects: Vec<Method<Object>>,
+    signatures: Vec<Method<DataType>>,
+    members: Vec<(String, String, Member)>,
 
     outer: Option<Box<Store>>,
 }
@@ -15,6 +35,13 @@ impl Store {
         Self {
             data_types: HashMap::new(),
             objects: HashMap::new(),
+            type_definitions: HashMap::new(),
+            type_names: HashMap::new(),
+
+            methods: Vec::new(),
+            method_objects: Vec::new(),
+            signatures: Vec::new(),
+            members: Vec::new(),
 
             outer: None,
         }
@@ -36,6 +63,10 @@ impl Store {
         self.objects.clone()
     }
 
+    pub fn get_type_definitions(&self) -> HashMap<String, DataType> {
+        self.type_definitions.clone()
+    }
+
     pub fn get_outer(&self) -> Option<Box<Store>> {
         self.outer.clone()
     }
@@ -63,7 +94,7 @@ impl Store {
             Some(data_type) => Some(data_type),
             None => match self.get_outer() {
                 Some(outer) => outer.get_data_type_with_outer(key),
-                None => None,
+                None => builtins::get_namespace_type(key),
             },
         }
     }
@@ -91,20 +122,249 @@ impl Store {
             Some(object) => Some(object),
             None => match self.get_outer() {
                 Some(outer) => outer.get_object_with_outer(key),
+                None => builtins::get_namespace_object(key),
+            },
+        }
+    }
+
+    pub fn has_type_definition(&self, key: &String) -> bool {
+        self.type_definitions.contains_key(key)
+    }
+
+    pub fn has_type_definition_with_outer(&self, key: &String) -> bool {
+        self.has_type_definition(key)
+            || (self.get_outer().is_some()
+                && self
+                    .get_outer()
+                    .unwrap()
+                    .has_type_definition_with_outer(key))
+            || builtins::get_type_definition(key).is_some()
+    }
+
+    pub fn get_type_definition(&self, key: &String) -> Option<DataType> {
+        if self.has_type_definition(key) {
+            Some(self.get_type_definitions().get(key).unwrap().clone())
+        } else {
+            None
+        }
+    }
+
+    pub fn get_type_definition_with_outer(
+        &self,
+        key: &String,
+    ) -> Option<DataType> {
+        match self.get_type_definition(key) {
+            Some(data_type) => Some(data_type),
+            None => match self.get_outer() {
+                Some(outer) => outer.get_type_definition_with_outer(key),
+                None => builtins::get_type_definition(key),
+            },
+        }
+    }
+
+    /// Get the name of the declared data type of a variable (or of the
+    /// return data type of a function).
+    pub fn get_type_name_with_outer(&self, key: &String) -> Option<String> {
+        match self.type_names.get(key) {
+            Some(type_name) => Some(type_name.clone()),
+            None => match self.get_outer() {
+                Some(outer) => outer.get_type_name_with_outer(key),
+                None => None,
+            },
+        }
+    }
+
+    /// Get the name of a declared data type when it is a named data type
+    /// (using the implemented data type for `Self`).
+    pub fn get_data_type_name(&self, data_type: &DataType) -> Option<String> {
+        let type_name = data_type.node.get_identifier()?;
+
+        Some(
+            self.get_type_name_with_outer(&type_name)
+                .unwrap_or(type_name),
+        )
+    }
+
+    /// Get the name of the declared data type of the value of the expression
+    /// (like a variable or a function call).
+    pub fn get_expression_type_name(
+        &self,
+        expression: &Expression,
+    ) -> Option<String> {
+        if let Some(name) = expression.node.get_identifier() {
+            return self.get_type_name_with_outer(&name);
+        } else if let Some((function, _)) = expression.node.get_call() {
+            return function
+                .node
+                .get_identifier()
+                .and_then(|name| self.get_type_name_with_outer(&name));
+        } else if let Some(value) = expression.node.get_group() {
+            return self.get_expression_type_name(&value);
+        }
+
+        None
+    }
+
+    /// Find the last method of the list that matches in the store or its
+    /// outer stores.
+    fn find_method_with_outer<T: Clone>(
+        &self,
+        get_methods: fn(&Store) -> &Vec<Method<T>>,
+        is_match: &dyn Fn(&Method<T>) -> bool,
+    ) -> Option<T> {
+        match get_methods(self)
+            .iter()
+            .rev()
+            .find(|method| is_match(method))
+        {
+            Some((_, _, _, value)) => Some(value.clone()),
+            None => match &self.outer {
+                Some(outer) => {
+                    outer.find_method_with_outer(get_methods, is_match)
+                }
+                None => None,
+            },
+        }
+    }
+
+    /// Find a method for the name of the receiver data type or, when there
+    /// is not one, for the receiver data type.
+    fn find_method<T: Clone>(
+        &self,
+        get_methods: fn(&Store) -> &Vec<Method<T>>,
+        receiver_name: Option<&String>,
+        receiver: &DataType,
+        key: &String,
+    ) -> Option<T> {
+        receiver_name
+            .and_then(|receiver_name| {
+                self.find_method_with_outer(get_methods, &|(
+                    method_receiver_name,
+                    _,
+                    name,
+                    _,
+                )| {
+                    name == key
+                        && method_receiver_name.as_ref() == Some(receiver_name)
+                })
+            })
+            .or_else(|| {
+                self.find_method_with_outer(get_methods, &|(
+                    _,
+                    method_receiver,
+                    name,
+                    _,
+                )| {
+                    name == key
+                        && method_receiver.node.is_assignable(&receiver.node)
+                })
+            })
+    }
+
+    /// Get the data type of a method implemented for the receiver data type.
+    pub fn get_method_with_outer(
+        &self,
+        receiver_name: Option<&String>,
+        receiver: &DataType,
+        key: &String,
+    ) -> Option<DataType> {
+        self.find_method(|store| &store.methods, receiver_name, receiver, key)
+    }
+
+    /// Get the function object of a method implemented for the receiver
+    /// data type.
+    pub fn get_method_object_with_outer(
+        &self,
+        receiver_name: Option<&String>,
+        receiver: &DataType,
+        key: &String,
+    ) -> Option<Object> {
+        self.find_method(
+            |store| &store.method_objects,
+            receiver_name,
+            receiver,
+            key,
+        )
+    }
+
+    /// Get the data type of a method declared by an interface for the
+    /// receiver data type.
+    pub fn get_signature_with_outer(
+        &self,
+        receiver_name: Option<&String>,
+        receiver: &DataType,
+        key: &String,
+    ) -> Option<DataType> {
+        self.find_method(
+            |store| &store.signatures,
+            receiver_name,
+            receiver,
+            key,
+        )
+    }
+
+    /// Get the names and the data types of the methods declared by the
+    /// interface.
+    pub fn get_signatures_with_outer(
+        &self,
+        interface_name: &String,
+    ) -> Vec<(String, DataType)> {
+        let mut signatures = match self.get_outer() {
+            Some(outer) => outer.get_signatures_with_outer(interface_name),
+            None => Vec::new(),
+        };
+
+        for (receiver_name, _, name, data_type) in self.signatures.iter() {
+            if receiver_name.as_ref() == Some(interface_name) {
+                signatures.push((name.clone(), data_type.clone()));
+            }
+        }
+
+        signatures
+    }
+
+    /// Get a field or a method of the class.
+    pub fn get_member_with_outer(
+        &self,
+        class_name: &String,
+        key: &String,
+    ) -> Option<Member> {
+        match self.members.iter().find(|(member_class, name, _)| {
+            member_class == class_name && name == key
+        }) {
+            Some((_, _, member)) => Some(member.clone()),
+            None => match self.get_outer() {
+                Some(outer) => outer.get_member_with_outer(class_name, key),
                 None => None,
             },
         }
     }
 
     pub fn is_builtin(&self, key: &String) -> bool {
-        key == "print" || key == "debug"
+        [
+            "print", "println", "eprint", "eprintln", "debug", "format",
+            "input", "readLine", "readAll",
+        ]
+        .iter()
+        .any(|name| key == name)
     }
 
     pub fn has_key_type(&self, key: &String) -> bool {
-        self.is_builtin(key) || self.has_data_type_with_outer(key)
+        self.is_type_in_use(key) || builtins::is_namespace(key)
     }
 
     pub fn has_key_object(&self, key: &String) -> bool {
+        self.is_object_in_use(key) || builtins::is_namespace(key)
+    }
+
+    /// Check if the key cannot be declared again in the typechecker (the
+    /// builtin namespaces can be shadowed by the program).
+    pub fn is_type_in_use(&self, key: &String) -> bool {
+        self.is_builtin(key) || self.has_data_type_with_outer(key)
+    }
+
+    /// Check if the key cannot be declared again in the evaluator.
+    pub fn is_object_in_use(&self, key: &String) -> bool {
         self.is_builtin(key) || self.has_object_with_outer(key)
     }
 
@@ -115,4 +375,70 @@ impl Store {
     pub fn add_object(&mut self, key: &String, value: &Object) {
         self.objects.insert(key.clone(), value.clone());
     }
+
+    pub fn add_type_definition(&mut self, key: &str, value: &DataType) {
+        self.type_definitions
+            .insert(String::from(key), value.clone());
+    }
+
+    /// Keep the name of the declared data type of a variable (or of the
+    /// return data type of a function).
+    pub fn add_type_name(&mut self, key: &str, type_name: Option<String>) {
+        if let Some(type_name) = type_name {
+            self.type_names.insert(String::from(key), type_name);
+        }
+    }
+
+    pub fn add_method(
+        &mut self,
+        receiver_name: Option<String>,
+        receiver: &DataType,
+        key: &str,
+        value: &DataType,
+    ) {
+        self.methods.push((
+            receiver_name,
+            receiver.clone(),
+            String::from(key),
+            value.clone(),
+        ));
+    }
+
+    pub fn add_method_object(
+        &mut self,
+        receiver_name: Option<String>,
+        receiver: &DataType,
+        key: &str,
+        value: &Object,
+    ) {
+        self.method_objects.push((
+            receiver_name,
+            receiver.clone(),
+            String::from(key),
+            value.clone(),
+        ));
+    }
+
+    pub fn add_signature(
+        &mut self,
+        interface_name: &str,
+        receiver: &DataType,
+        key: &str,
+        value: &DataType,
+    ) {
+        self.signatures.push((
+            Some(String::from(interface_name)),
+            receiver.clone(),
+            String::from(key),
+            value.clone(),
+        ));
+    }
+
+    pub fn add_member(&mut self, class_name: &str, key: &str, value: &Member) {
+        self.members.push((
+            String::from(class_name),
+            String::from(key),
+            value.clone(),
+        ));
+    }
 }
@@ -1,49 +1,499 @@
-use crate::{builtins, Environment, Object, Objects, Store};
+use crate::{builtins, typechecker, Environment, Object, Objects, Store};
 use sflynlang_parser::{
-    ast::{Expression, Statement},
-    Error, Position, Token,
+    ast::{DataType, DataTypes, Expression, Pattern, Statement},
+    Error, ErrorType, OrderedMap, Position, Token,
 };
-use std::collections::HashMap;
-
+use std::{cell::RefCell, rc::Rc};
 pub fn evaluate_body(body: Vec<Statement>, environment: &mut Environment) -> Result<Object, Error> {
     let object = Object::new(Position::new(0, 0, 1, 1), Objects::Unknown);
 
     for statement in body.iter() {
         let stmt_object = evaluate_statement(statement, environment)?;
 
-        if let Some(return_obj) = stmt_object.get_node().get_return() {
-            return Ok(*return_obj);
+        if stmt_object.get_node().get_return().is_some() {
+            return Ok(stmt_object);
         }
     }
 
     Ok(object)
 }
 
+/// Add the pattern names to the store with the values destructured from the
+/// object.
+fn evaluate_pattern(
+    pattern: &Pattern,
+    object: &Object,
+    environment: &mut Environment,
+) -> Result<(), Error> {
+    // Array:
+    if let Some(values) = pattern.node.get_array() {
+        let items = object.get_node().get_array().ok_or_else(|| {
+            Error::new_unknown_token(pattern.get_position())
+        })?;
+
+        for (index, value) in values.iter().enumerate() {
+            if let Some(rest_name) = value.node.get_rest() {
+                let rest_items = items.iter().skip(index).cloned().collect();
+
+                environment.get_store().add_object(
+                    &rest_name,
+                    &Object::new(
+                        value.get_position(),
+                        Objects::new_array(rest_items),
+                    ),
+                );
+
+                continue;
+            }
+
+            match items.get(index) {
+                Some(item) => evaluate_pattern(value, item, environment)?,
+                None => {
+                    return Err(Error::new_runtime(
+                        value.get_position(),
+                        "The array does not have enough values.",
+                    ))
+                }
+            }
+        }
+
+        return Ok(());
+    }
+    // Enum variant:
+    else if let Some((_, values)) = pattern.node.get_enum_variant() {
+        let (_, _, items) =
+            object.get_node().get_enum_variant().ok_or_else(|| {
+                Error::new_unknown_token(pattern.get_position())
+            })?;
+
+        for (value, item) in values.iter().zip(items.iter()) {
+            evaluate_pattern(value, item, environment)?;
+        }
+
+        return Ok(());
+    }
+    // HashMap:
+    else if let Some(data) = pattern.node.get_hashmap() {
+        let properties = object.get_node().get_hashmap().ok_or_else(|| {
+            Error::new_unknown_token(pattern.get_position())
+        })?;
+
+        for (key, value) in data.iter() {
+            match properties.get(key) {
+                Some(property) => {
+                    evaluate_pattern(value, property, environment)?
+                }
+                None => {
+                    return Err(Error::new_unknown_property(
+                        value.get_position(),
+                        key.clone(),
+                    ))
+                }
+            }
+        }
+
+        return Ok(());
+    }
+    // Identifier:
+    else if let Some(name) = pattern.node.get_identifier() {
+        // The name of a variant does not add a value.
+        if is_variant_name(&name, object, environment) {
+            return Ok(());
+        }
+
+        environment.get_store().add_object(&name, object);
+
+        return Ok(());
+    }
+    // Tuple:
+    else if let Some(values) = pattern.node.get_tuple() {
+        let items = object.get_node().get_tuple().ok_or_else(|| {
+            Error::new_unknown_token(pattern.get_position())
+        })?;
+
+        for (value, item) in values.iter().zip(items.iter()) {
+            evaluate_pattern(value, item, environment)?;
+        }
+
+        return Ok(());
+    }
+
+    Err(Error::new_unknown_token(pattern.get_position()))
+}
+
+/// Check if the name is a variant of the enum of the object.
+fn is_variant_name(
+    name: &str,
+    object: &Object,
+    environment: &mut Environment,
+) -> bool {
+    let enum_name = match object.get_node().get_enum_variant() {
+        Some((enum_name, _, _)) => enum_name,
+        None => return false,
+    };
+
+    match environment
+        .get_store()
+        .get_object_with_outer(&enum_name)
+        .and_then(|enum_object| enum_object.get_node().get_hashmap())
+    {
+        Some(constructors) => constructors.contains_key(name),
+        None => false,
+    }
+}
+
+/// Check if the object has the variants required by the pattern.
+fn is_pattern_match(
+    pattern: &Pattern,
+    object: &Object,
+    environment: &mut Environment,
+) -> bool {
+    if let Some((variant_name, values)) = pattern.node.get_enum_variant() {
+        return match object.get_node().get_enum_variant() {
+            Some((_, variant, items)) => {
+                variant == variant_name
+                    && values.len() == items.len()
+                    && values.iter().zip(items.iter()).all(|(value, item)| {
+                        is_pattern_match(value, item, environment)
+                    })
+            }
+            None => false,
+        };
+    } else if let Some(name) = pattern.node.get_identifier() {
+        if is_variant_name(&name, object, environment) {
+            return match object.get_node().get_enum_variant() {
+                Some((_, variant, _)) => variant == name,
+                None => false,
+            };
+        }
+    } else if let Some(values) = pattern.node.get_tuple() {
+        return match object.get_node().get_tuple() {
+            Some(items) => {
+                values.iter().zip(items.iter()).all(|(value, item)| {
+                    is_pattern_match(value, item, environment)
+                })
+            }
+            None => false,
+        };
+    }
+
+    true
+}
+
+/// Evaluate the call arguments, expanding the spread arguments.
+fn evaluate_spread_arguments(
+    arguments: &[Expression],
+    environment: &mut Environment,
+) -> Result<Vec<Object>, Error> {
+    let mut objects: Vec<Object> = Vec::new();
+
+    for argument in arguments.iter() {
+        if let Some(spread_value) = argument.node.get_spread() {
+            let spread_object =
+                evaluate_expression(&spread_value, environment)?;
+
+            match spread_object.get_node().get_array() {
+                Some(values) => objects.extend(values),
+                None => {
+                    return Err(Error::new_expect_type(
+                        spread_value.get_position(),
+                        "array",
+                        &spread_object.to_data_type().to_string(),
+                    ));
+                }
+            }
+
+            continue;
+        }
+
+        objects.push(evaluate_expression(argument, environment)?);
+    }
+
+    Ok(objects)
+}
+
+/// Get the argument expression of a function argument (removing the rest sign).
+fn get_function_argument(function_argument: &Expression) -> Expression {
+    match function_argument.node.get_spread() {
+        Some(rest_argument) => *rest_argument,
+        None => function_argument.clone(),
+    }
+}
+
+/// Bind the call arguments to the function arguments, evaluating the default
+/// values of the missing ones on each call.
+fn evaluate_arguments(
+    position: Position,
+    function_arguments: &[Expression],
+    arguments: &[Expression],
+    environment: &mut Environment,
+    function_environment: &mut Environment,
+) -> Result<(), Error> {
+    let rest_index = function_arguments.iter().position(|function_argument| {
+        function_argument.node.get_spread().is_some()
+    });
+    let mut values: Vec<Option<Object>> = vec![None; function_arguments.len()];
+    let mut rest_values: Vec<Object> = Vec::new();
+    let mut index: usize = 0;
+
+    for argument in arguments.iter() {
+        if let Some((argument_name, argument_value)) =
+            argument.node.get_named_argument()
+        {
+            let argument_index = function_arguments
+                .iter()
+                .position(|function_argument| {
+                    get_function_argument(function_argument)
+                        .node
+                        .get_argument()
+                        .unwrap()
+                        .0
+                        == argument_name
+                })
+                .ok_or_else(|| {
+                    Error::new_unknown_argument(
+                        argument.get_position(),
+                        argument_name.clone(),
+                    )
+                })?;
+
+            values[argument_index] =
+                Some(evaluate_expression(&argument_value, environment)?);
+
+            continue;
+        }
+
+        for object in evaluate_spread_arguments(
+            std::slice::from_ref(argument),
+            environment,
+        )? {
+            // Check if the object is part of the rest argument.
+            if let Some(rest_index) = rest_index {
+                if index >= rest_index {
+                    rest_values.push(object);
+                    continue;
+                }
+            }
+
+            if index >= function_arguments.len() {
+                return Err(Error::new_expect_arguments(
+                    position,
+                    function_arguments.len(),
+                    arguments.len(),
+                ));
+            }
+
+            values[index] = Some(object);
+            index += 1;
+        }
+    }
+
+    for (argument_index, function_argument) in
+        function_arguments.iter().enumerate()
+    {
+        let function_argument = get_function_argument(function_argument);
+        let (argument_name, argument_type, argument_value) =
+            function_argument.node.get_argument().unwrap();
+
+        if let Some(value) = values[argument_index].clone() {
+            let type_name = function_environment
+                .get_store()
+                .get_data_type_name(&argument_type);
+
+            function_environment
+                .get_store()
+                .add_object(&argument_name, &value);
+            function_environment
+                .get_store()
+                .add_type_name(&argument_name, type_name);
+        } else if rest_index == Some(argument_index) {
+            function_environment.get_store().add_object(
+                &argument_name,
+                &Object::new(
+                    position.clone(),
+                    Objects::new_array(rest_values.clone()),
+                ),
+            );
+        } else if argument_value.is_some() {
+            evaluate_expression(&function_argument, function_environment)?;
+        } else {
+            return Err(Error::new_missing_argument(position, argument_name));
+        }
+    }
+
+    Ok(())
+}
+
+/// Call a method with the object as its `self` argument.
+fn call_method(
+    position: Position,
+    method_object: &Object,
+    self_object: &Object,
+    arguments: &[Expression],
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    let (
+        function_arguments,
+        function_body,
+        _function_return_obj,
+        function_environment,
+    ) = method_object.get_node().get_function().unwrap();
+
+    let mut function_environment: Environment = function_environment.clone();
+    let self_type_name = function_arguments
+        .first()
+        .and_then(|argument| argument.node.get_argument())
+        .and_then(|(_, self_type, _)| {
+            function_environment
+                .get_store()
+                .get_data_type_name(&self_type)
+        });
+
+    function_environment
+        .get_store()
+        .add_object(&String::from("self"), self_object);
+    function_environment
+        .get_store()
+        .add_type_name("self", self_type_name);
+
+    // Skip the `self` argument.
+    evaluate_arguments(
+        position.clone(),
+        &function_arguments[1..],
+        arguments,
+        environment,
+        &mut function_environment,
+    )?;
+
+    let body_object = evaluate_body(function_body, &mut function_environment)?;
+
+    if let Some(return_obj) = body_object.get_node().get_return() {
+        return Ok(*return_obj);
+    }
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+/// Call a function object (like a callback of a builtin method) with the
+/// argument objects.
+pub(crate) fn call_function(
+    position: Position,
+    function_object: &Object,
+    arguments: Vec<Object>,
+    environment: &mut Environment,
+) -> Result<Object, Error> {
+    if let Some(function) = function_object.get_node().get_builtin() {
+        return (function.eval)(arguments, position, environment);
+    }
+
+    let (
+        function_arguments,
+        function_body,
+        _function_return_obj,
+        function_environment,
+    ) = function_object.get_node().get_function().ok_or_else(|| {
+        Error::new_expect_type(
+            function_object.get_position(),
+            "function",
+            &function_object.to_data_type().to_string(),
+        )
+    })?;
+
+    let mut function_environment: Environment = function_environment.clone();
+    let mut arguments = arguments.into_iter();
+
+    for function_argument in function_arguments.iter() {
+        let function_argument = get_function_argument(function_argument);
+        let (argument_name, argument_type, argument_value) =
+            function_argument.node.get_argument().unwrap();
+
+        if let Some(value) = arguments.next() {
+            let type_name = function_environment
+                .get_store()
+                .get_data_type_name(&argument_type);
+
+            function_environment
+                .get_store()
+                .add_object(&argument_name, &value);
+            function_environment
+                .get_store()
+                .add_type_name(&argument_name, type_name);
+        } else if argument_value.is_some() {
+            evaluate_expression(&function_argument, &mut function_environment)?;
+        } else {
+            return Err(Error::new_missing_argument(position, argument_name));
+        }
+    }
+
+    let body_object = evaluate_body(function_body, &mut function_environment)?;
+
+    if let Some(return_obj) = body_object.get_node().get_return() {
+        return Ok(*return_obj);
+    }
+
+    Ok(Object::new(position, Objects::Void))
+}
+
+/// Create the function object of a method.
+fn evaluate_method(
+    method: &Statement,
+    environment: &mut Environment,
+) -> Result<(String, Object), Error> {
+    let (method_name, _, method_arguments, _, method_body) =
+        method.node.get_function().unwrap();
+
+    let identifier_name = method_name
+        .node
+        .get_identifier()
+        .ok_or_else(|| Error::new_unknown_token(method_name.get_position()))?;
+
+    let mut method_environment: Environment = environment.clone();
+
+    method_environment.set_store(&Store::from_outer(environment.get_store()));
+
+    Ok((
+        identifier_name,
+        Object::new(
+            method_name.get_position(),
+            Objects::Function {
+                arguments: method_arguments,
+                body: method_body,
+                return_obj: Box::new(Object::new(
+                    method.get_position(),
+                    Objects::Unknown,
+                )),
+                environment: Box::new(method_environment),
+            },
+        ),
+    ))
+}
+
 pub fn evaluate_expression(
     expression: &Expression,
     environment: &mut Environment,
 ) -> Result<Object, Error> {
     // Argument:
-    if let Some((argument_name, _argument_type, argument_value)) =
+    if let Some((argument_name, argument_type, argument_value)) =
         expression.node.get_argument()
     {
         // Check if the name is already in use.
-        if environment.get_store().has_key_object(&argument_name) {
+        if environment.get_store().is_object_in_use(&argument_name) {
             return Err(Error::new_name_in_use(
                 expression.get_position(),
                 argument_name.clone(),
                 environment
                     .get_store()
                     .get_object_with_outer(&argument_name)
-                    .unwrap()
-                    .get_position(),
+                    .map(|last| last.get_position()),
             ));
         }
 
         if let Some(argument_value) = argument_value {
             let value_object = evaluate_expression(&argument_value, environment)?;
+            let type_name = environment.get_store().get_data_type_name(&argument_type);
 
             environment.get_store().add_object(&argument_name, &value_object);
+            environment.get_store().add_type_name(&argument_name, type_name);
 
             return Ok(value_object);
         }
@@ -52,6 +502,54 @@ pub fn evaluate_expression(
 
         return Ok(argument_object);
     }
+    // Array:
+    else if let Some(values) = expression.node.get_array() {
+        let mut values_objects: Vec<Object> = Vec::new();
+
+        for value in values.iter() {
+            values_objects.push(evaluate_expression(value, environment)?);
+        }
+
+        return Ok(Object::new(
+            expression.get_position(),
+            Objects::new_array(values_objects),
+        ));
+    }
+    // Assignment:
+    else if let Some((target, sign, value)) = expression.node.get_assignment() {
+        if let Some((instance_exp, field_exp)) = target.node.get_method() {
+            let instance_obj = evaluate_expression(&instance_exp, environment)?;
+
+            if let (Some((_, fields)), Some(field_name)) = (
+                instance_obj.get_node().get_instance(),
+                field_exp.node.get_identifier(),
+            ) {
+                let value_obj = evaluate_expression(
+                    &typechecker::get_assignment_value(&target, sign, &value),
+                    environment,
+                )?;
+
+                fields.borrow_mut().add_object(&field_name, &value_obj);
+
+                return Ok(Object::new(
+                    expression.get_position(),
+                    Objects::Void,
+                ));
+            }
+        }
+
+        return Err(Error::new_runtime(
+            target.get_position(),
+            "Only the class fields can be assigned.",
+        ));
+    }
+    // Boolean:
+    else if let Some(value) = expression.node.get_boolean() {
+        return Ok(Object::new(
+            expression.get_position(),
+            Objects::Boolean(value),
+        ));
+    }
     // Call:
     else if let Some((identifier, arguments)) = expression.node.get_call() {
         if let Some(identifier_name) = identifier.node.get_identifier() {
@@ -62,18 +560,30 @@ pub fn evaluate_expression(
                 ));
             }
 
-            let mut arguments_objects: Vec<Object> = Vec::new();
+            if environment.get_store().is_builtin(&identifier_name) {
+                let (named_arguments, arguments): (Vec<_>, Vec<_>) =
+                    arguments.into_iter().partition(|argument| {
+                        argument.node.get_named_argument().is_some()
+                    });
+                let arguments_objects =
+                    evaluate_spread_arguments(&arguments, environment)?;
+                let mut named_objects: OrderedMap<Object> = OrderedMap::new();
 
-            for argument in arguments.iter() {
-                arguments_objects
-                    .push(evaluate_expression(argument, environment)?);
-            }
+                for argument in named_arguments.iter() {
+                    if let Some((name, value)) =
+                        argument.node.get_named_argument()
+                    {
+                        let value = evaluate_expression(&value, environment)?;
+
+                        named_objects.insert(name, value);
+                    }
+                }
 
-            if environment.get_store().is_builtin(&identifier_name) {
                 return builtins::eval_builtin(
                     identifier_name,
                     identifier.get_position(),
                     arguments_objects,
+                    named_objects,
                     environment,
                 );
             } else if let Some(env_object) = environment
@@ -87,34 +597,143 @@ pub fn evaluate_expression(
                     function_environment,
                 )) = env_object.get_node().get_function()
                 {
-                    let min_arguments: usize = function_arguments.iter().map(|(_key, value)| value.get_node().is_unknown()).len();
-                    let max_arguments: usize = function_arguments.len();
+                    let mut function_environment: Environment =
+                        function_environment.clone();
 
-                    if arguments_objects.len() < min_arguments {
-                        return Err(Error::new_expect_arguments(expression.get_position(), min_arguments, arguments_objects.len()));
-                    }
+                    // Add the function to its scope to allow recursive calls.
+                    function_environment
+                        .get_store()
+                        .add_object(&identifier_name, &env_object);
+
+                    evaluate_arguments(
+                        expression.get_position(),
+                        &function_arguments,
+                        &arguments,
+                        environment,
+                        &mut function_environment,
+                    )?;
+
+                    let body_object = evaluate_body(
+                        function_body,
+                        &mut function_environment,
+                    )?;
 
-                    if arguments_objects.len() > max_arguments {
-                        return Err(Error::new_expect_arguments(expression.get_position(), max_arguments, arguments_objects.len()));
+                    if let Some(return_obj) =
+                        body_object.get_node().get_return()
+                    {
+                        return Ok(*return_obj);
                     }
 
-                    let keys: Vec<String> = function_arguments.keys().map(|arg| arg.to_string()).collect();
-                    let mut index: usize = 0;
+                    return Ok(Object::new(
+                        expression.get_position(),
+                        Objects::Void,
+                    ));
+                } else if let Some((enum_name, variant, variant_arguments)) =
+                    env_object.get_node().get_enum_constructor()
+                {
+                    let mut variant_environment: Environment =
+                        environment.clone();
+
+                    variant_environment
+                        .set_store(&Store::from_outer(environment.get_store()));
 
-                    let mut function_environment: Environment = function_environment.clone();
+                    evaluate_arguments(
+                        expression.get_position(),
+                        &variant_arguments,
+                        &arguments,
+                        environment,
+                        &mut variant_environment,
+                    )?;
 
-                    for argument in arguments_objects.iter() {
-                        function_environment.get_store().add_object(&keys[index], argument);
-                        index += 1;
+                    // Get the values in the order of the variant arguments.
+                    let values = variant_arguments
+                        .iter()
+                        .filter_map(|argument| argument.node.get_argument())
+                        .filter_map(|(name, _, _)| {
+                            variant_environment.get_store().get_object(&name)
+                        })
+                        .collect();
+
+                    return Ok(Object::new(
+                        expression.get_position(),
+                        Objects::EnumVariant {
+                            enum_name,
+                            variant,
+                            values,
+                        },
+                    ));
+                } else if let Some((class_name, fields, constructor)) =
+                    env_object.get_node().get_class()
+                {
+                    let mut instance_store = Store::new();
+                    let mut field_names: Vec<String> = Vec::new();
+
+                    // Initialize the fields with a value.
+                    for field in fields.iter() {
+                        let (field_name, _, field_value) =
+                            field.node.get_argument().unwrap();
+
+                        field_names.push(field_name.clone());
+
+                        if let Some(field_value) = field_value {
+                            let field_obj =
+                                evaluate_expression(&field_value, environment)?;
+
+                            instance_store.add_object(&field_name, &field_obj);
+                        }
                     }
 
-                    return evaluate_body(function_body, &mut function_environment);
+                    let instance_obj = Object::new(
+                        expression.get_position(),
+                        Objects::Instance {
+                            class_name,
+                            field_names,
+                            fields: Rc::new(RefCell::new(instance_store)),
+                        },
+                    );
+
+                    if let Some(constructor) = constructor {
+                        call_method(
+                            expression.get_position(),
+                            &constructor,
+                            &instance_obj,
+                            &arguments,
+                            environment,
+                        )?;
+                    }
+
+                    return Ok(instance_obj);
+                } else if let Some(function) =
+                    env_object.get_node().get_builtin()
+                {
+                    let arguments_objects =
+                        evaluate_spread_arguments(&arguments, environment)?;
+
+                    return (function.eval)(
+                        arguments_objects,
+                        expression.get_position(),
+                        environment,
+                    );
                 }
             }
         } else {
             return Err(Error::new_unknown_token(identifier.get_position()));
         }
     }
+    // HashMap:
+    else if let Some(data) = expression.node.get_hashmap() {
+        let mut data_objects: OrderedMap<Object> = OrderedMap::new();
+
+        for (key, value) in data.iter() {
+            data_objects
+                .insert(key.clone(), evaluate_expression(value, environment)?);
+        }
+
+        return Ok(Object::new(
+            expression.get_position(),
+            Objects::new_hashmap(data_objects),
+        ));
+    }
     // Identifier:
     else if let Some(identifier_name) = expression.node.get_identifier() {
         if let Some(env_obj) = environment.get_store().get_object_with_outer(&identifier_name) {
@@ -133,8 +752,49 @@ pub fn evaluate_expression(
             return evaluate_body(alternative, environment);
         }
     }
+    // Try:
+    else if let Some((body, error_name, handler)) = expression.node.get_try() {
+        // The body has its own scope like the handler.
+        let mut body_environment = environment.clone();
+
+        body_environment.set_store(&Store::from_outer(environment.get_store()));
+
+        return match evaluate_body(body, &mut body_environment) {
+            Ok(body_object) => Ok(body_object),
+            // The program exits even inside a try.
+            Err(error)
+                if matches!(error.get_error_type(), ErrorType::Exit(_)) =>
+            {
+                Err(error)
+            }
+            Err(error) => {
+                // The handler receives the message of the error.
+                let mut handler_environment = environment.clone();
+
+                handler_environment
+                    .set_store(&Store::from_outer(environment.get_store()));
+                handler_environment.get_store().add_object(
+                    &error_name,
+                    &Object::new(
+                        error.get_position(),
+                        Objects::String(error.get_message()),
+                    ),
+                );
+
+                evaluate_body(handler, &mut handler_environment)
+            }
+        };
+    }
     // Infix:
     else if let Some((left, operator, right)) = expression.node.get_infix() {
+        // Check if the infix is a pipeline.
+        if operator == Token::VBarGreater {
+            return match expression.get_pipeline_call() {
+                Some(call_exp) => evaluate_expression(&call_exp, environment),
+                None => Err(Error::new_unknown_token(right.get_position())),
+            };
+        }
+
         let left_obj = evaluate_expression(&left, environment)?;
         let right_obj = evaluate_expression(&right, environment)?;
 
@@ -171,8 +831,8 @@ pub fn evaluate_expression(
                                 else if operator == Token::Star { left_value * right_value }
                                 else if operator == Token::Slash { left_value / right_value }
                                 else if operator == Token::Percent { left_value % right_value }
-                                else if operator == Token::DoubleStar { left_value.pow(right_value.to_string().parse().unwrap()) }
-                                else { 0 }
+                                else if operator == Token::DoubleStar { left_value.powf(right_value) }
+                                else { 0.0 }
                             ),
                         ));
                     }
@@ -198,23 +858,213 @@ pub fn evaluate_expression(
                 }
             }
 
+            Token::DoubleEqual | Token::NotEqual => {
+                let is_equal = left_obj.get_node() == right_obj.get_node();
+
+                return Ok(Object::new(
+                    expression.get_position(),
+                    Objects::Boolean(if operator == Token::DoubleEqual {
+                        is_equal
+                    } else {
+                        !is_equal
+                    }),
+                ));
+            }
+
             _ => {},
         }
 
         return Err(Error::new_unknown_token(expression.get_position()));
     }
+    // Match:
+    else if let Some((value, arms)) = expression.node.get_match() {
+        let value_obj = evaluate_expression(&value, environment)?;
+
+        for (arm_pattern, arm_value) in arms.iter() {
+            if !is_pattern_match(arm_pattern, &value_obj, environment) {
+                continue;
+            }
+
+            let mut arm_environment = environment.clone();
+
+            arm_environment
+                .set_store(&Store::from_outer(environment.get_store()));
+
+            evaluate_pattern(arm_pattern, &value_obj, &mut arm_environment)?;
+
+            return evaluate_expression(arm_value, &mut arm_environment);
+        }
+
+        return Err(Error::new_runtime(
+            expression.get_position(),
+            "The value does not match any pattern.",
+        ));
+    }
     // Method:
     else if let Some((left_exp, right_exp)) = expression.node.get_method() {
-        let _left_obj = evaluate_expression(&left_exp, environment)?;
+        let left_obj = evaluate_expression(&left_exp, environment)?;
+
+        // Call a method implemented for the left object.
+        if let Some((method_name, arguments)) = right_exp.node.get_call() {
+            let receiver_name =
+                environment.get_store().get_expression_type_name(&left_exp);
+
+            if let Some(method_object) =
+                method_name.node.get_identifier().and_then(|method_name| {
+                    environment.get_store().get_method_object_with_outer(
+                        receiver_name.as_ref(),
+                        &left_obj.to_data_type(),
+                        &method_name,
+                    )
+                })
+            {
+                return call_method(
+                    expression.get_position(),
+                    &method_object,
+                    &left_obj,
+                    &arguments,
+                    environment,
+                );
+            }
+        }
+
+        // Call a builtin method of the left object.
+        if let Some((method_name, arguments)) = right_exp.node.get_call() {
+            if let Some((method, _)) =
+                method_name.node.get_identifier().and_then(|method_name| {
+                    builtins::get_method(
+                        &left_obj.to_data_type(),
+                        &method_name,
+                        right_exp.get_position(),
+                    )
+                })
+            {
+                let arguments_objects =
+                    evaluate_spread_arguments(&arguments, environment)?;
+
+                return (method.eval)(
+                    &left_obj,
+                    arguments_objects,
+                    expression.get_position(),
+                    environment,
+                );
+            }
+        }
+
+        if let Some((_, fields)) = left_obj.get_node().get_instance() {
+            let mut method_environment = environment.clone();
+
+            method_environment
+                .set_store(&Store::from_outer(environment.get_store()));
+
+            for (key, value) in fields.borrow().get_objects().iter() {
+                method_environment.get_store().add_object(key, value);
+            }
+
+            return evaluate_expression(&right_exp, &mut method_environment);
+        }
+
+        if let Some(items) = left_obj.get_node().get_tuple() {
+            if let Some(index) = right_exp.node.get_number() {
+                return items.get(index as usize).cloned().ok_or_else(|| {
+                    Error::new_unknown_property(
+                        right_exp.get_position(),
+                        index.to_string(),
+                    )
+                });
+            }
+        }
+
+        if let Some(properties) = left_obj.get_node().get_hashmap() {
+            // Call a builtin function of a namespace (like `Math.sqrt`) with
+            // the arguments of the current scope.
+            if let Some((function_name, arguments)) = right_exp.node.get_call()
+            {
+                if let Some(function) = function_name
+                    .node
+                    .get_identifier()
+                    .and_then(|function_name| {
+                        properties.get(&function_name).cloned()
+                    })
+                    .and_then(|property| property.get_node().get_builtin())
+                {
+                    let arguments_objects =
+                        evaluate_spread_arguments(&arguments, environment)?;
+
+                    return (function.eval)(
+                        arguments_objects,
+                        expression.get_position(),
+                        environment,
+                    );
+                }
+            }
+
+            let mut method_environment = environment.clone();
+
+            method_environment
+                .set_store(&Store::from_outer(environment.get_store()));
+
+            for (key, value) in properties.iter() {
+                method_environment.get_store().add_object(key, value);
+            }
+
+            return evaluate_expression(&right_exp, &mut method_environment);
+        }
+
         let _right_obj = evaluate_expression(&right_exp, environment)?;
     }
+    // Null:
+    else if expression.node.is_null() {
+        return Ok(Object::new(
+            expression.get_position(),
+            Objects::Null(Box::new(Object::new(
+                expression.get_position(),
+                Objects::Unknown,
+            ))),
+        ));
+    }
     // Number:
     else if let Some(value) = expression.node.get_number() {
         return Ok(Object::new(
             expression.get_position(),
-            Objects::Number(value.to_string().parse().unwrap()),
+            Objects::Number(value),
         ));
     }
+    // Prefix:
+    else if let Some((operator, value)) = expression.node.get_prefix() {
+        let value_obj = evaluate_expression(&value, environment)?;
+
+        match operator {
+            Token::Typeof => {
+                return Ok(Object::new(
+                    expression.get_position(),
+                    Objects::String(value_obj.to_data_type().node.get_typeof()),
+                ));
+            }
+
+            Token::Not => {
+                if let Some(value) = value_obj.get_node().get_boolean() {
+                    return Ok(Object::new(
+                        expression.get_position(),
+                        Objects::Boolean(!value),
+                    ));
+                }
+            }
+
+            Token::Minus => {
+                if let Some(value) = value_obj.get_node().get_number() {
+                    return Ok(Object::new(
+                        expression.get_position(),
+                        Objects::Number(-value),
+                    ));
+                }
+            }
+
+            _ => {}
+        }
+
+        return Err(Error::new_unknown_token(expression.get_position()));
+    }
     // String:
     else if let Some(string_value) = expression.node.get_string() {
         return Ok(Object::new(
@@ -223,6 +1073,32 @@ pub fn evaluate_expression(
         ));
     }
 
+    // Ternary:
+    else if let Some((condition, consequence, alternative)) =
+        expression.node.get_ternary()
+    {
+        let condition_obj = evaluate_expression(&condition, environment)?;
+
+        if condition_obj.get_node().is_trusthy() {
+            return evaluate_expression(&consequence, environment);
+        }
+
+        return evaluate_expression(&alternative, environment);
+    }
+    // Tuple:
+    else if let Some(values) = expression.node.get_tuple() {
+        let mut values_objects: Vec<Object> = Vec::new();
+
+        for value in values.iter() {
+            values_objects.push(evaluate_expression(value, environment)?);
+        }
+
+        return Ok(Object::new(
+            expression.get_position(),
+            Objects::Tuple(values_objects),
+        ));
+    }
+
     Err(Error::new_unknown_token(expression.get_position()))
 }
 
@@ -230,70 +1106,181 @@ pub fn evaluate_statement(
     statement: &Statement,
     environment: &mut Environment,
 ) -> Result<Object, Error> {
+    // Class:
+    if let Some((class_name, fields, constructor, methods)) =
+        statement.node.get_class()
+    {
+        let class_type = DataType::new(
+            statement.get_position(),
+            DataTypes::Instance(class_name.clone()),
+        );
+
+        for (_, method) in methods.iter() {
+            let (method_name, method_object) =
+                evaluate_method(method, environment)?;
+
+            environment.get_store().add_method_object(
+                None,
+                &class_type,
+                &method_name,
+                &method_object,
+            );
+        }
+
+        let constructor = match constructor {
+            Some(constructor) => {
+                Some(Box::new(evaluate_method(&constructor, environment)?.1))
+            }
+            None => None,
+        };
+
+        let class_object = Object::new(
+            statement.get_position(),
+            Objects::Class {
+                name: class_name.clone(),
+                fields: fields.into_iter().map(|(_, field)| field).collect(),
+                constructor,
+            },
+        );
+
+        environment.get_store().add_object(&class_name, &class_object);
+
+        return Ok(Object::new(statement.get_position(), Objects::Void));
+    }
+    // Enum:
+    else if let Some((enum_name, variants)) = statement.node.get_enum() {
+        let mut constructors: OrderedMap<Object> = OrderedMap::new();
+
+        for (variant, arguments) in variants.iter() {
+            constructors.insert(
+                variant.clone(),
+                Object::new(
+                    statement.get_position(),
+                    if arguments.is_empty() {
+                        Objects::EnumVariant {
+                            enum_name: enum_name.clone(),
+                            variant: variant.clone(),
+                            values: Vec::new(),
+                        }
+                    } else {
+                        Objects::EnumConstructor {
+                            enum_name: enum_name.clone(),
+                            variant: variant.clone(),
+                            arguments: arguments.clone(),
+                        }
+                    },
+                ),
+            );
+        }
+
+        environment.get_store().add_object(
+            &enum_name,
+            &Object::new(
+                statement.get_position(),
+                Objects::new_hashmap(constructors),
+            ),
+        );
+
+        return Ok(Object::new(statement.get_position(), Objects::Void));
+    }
     // Expression:
-    if let Some(expression) = statement.node.get_expression() {
+    else if let Some(expression) = statement.node.get_expression() {
         return evaluate_expression(&expression, environment);
     }
     // Function:
     else if let Some((
         function_name,
-        function_arguments,
         _,
+        function_arguments,
+        function_return_type,
         function_body,
     )) = statement.node.get_function()
     {
         // Get the function name string.
         if let Some(identifier_name) = function_name.node.get_identifier() {
             // Check if the name is already in use.
-            if environment.get_store().has_key_object(&identifier_name) {
+            if environment.get_store().is_object_in_use(&identifier_name) {
                 return Err(Error::new_name_in_use(
                     function_name.get_position(),
                     identifier_name.clone(),
                     environment
                         .get_store()
                         .get_object_with_outer(&identifier_name)
-                        .unwrap()
-                        .get_position(),
+                        .map(|last| last.get_position()),
                 ));
             }
 
             // Create a new internal storage.
-            let store = Store::from_outer(&environment.get_store());
+            let store = Store::from_outer(environment.get_store());
 
             // Create a new internal environment.
             let mut function_environment: Environment = environment.clone();
 
             function_environment.set_store(&store);
 
-            let mut arguments_objects: HashMap<String, Object> = HashMap::new();
-
-            for arg in function_arguments.iter() {
-                // Evaluate expression:
-                // Get the expression from the argument.
-                arguments_objects
-                    .insert(
-                        arg.node.get_argument().unwrap().0,
-                        evaluate_expression(arg, &mut function_environment)?
-                    );
-            }
-
             let function_object = Object::new(
                 function_name.get_position(),
                 Objects::Function {
-                    arguments: arguments_objects.clone(),
+                    arguments: function_arguments.clone(),
                     body: function_body.clone(),
                     return_obj: Box::new(Object::new(statement.get_position(), Objects::Unknown)),
-                    environment: function_environment.clone(),
+                    environment: Box::new(function_environment.clone()),
                 }
             );
 
             environment.get_store().add_object(&identifier_name, &function_object);
 
+            let type_name = environment.get_store().get_data_type_name(&function_return_type);
+
+            environment.get_store().add_type_name(&identifier_name, type_name);
+
             return Ok(function_object);
         } else {
             return Err(Error::new_unknown_token(function_name.get_position()));
         }
     }
+    // Impl:
+    else if let Some((impl_type, impl_methods)) = statement.node.get_impl() {
+        // The methods are kept for the name of the data type (like `Label`).
+        let impl_name = impl_type.node.get_identifier();
+        let impl_type =
+            typechecker::resolve_data_type(&impl_type, environment)?;
+
+        // Create an environment where `self` has the implemented data type.
+        let mut impl_environment = environment.clone();
+
+        impl_environment.set_store(&Store::from_outer(environment.get_store()));
+
+        impl_environment
+            .get_store()
+            .add_type_name("Self", impl_name.clone());
+
+        for impl_method in impl_methods.iter() {
+            let (method_name, method_object) =
+                evaluate_method(impl_method, &mut impl_environment)?;
+
+            environment.get_store().add_method_object(
+                impl_name.clone(),
+                &impl_type,
+                &method_name,
+                &method_object,
+            );
+
+            // Allow the next methods to call this method.
+            impl_environment.get_store().add_method_object(
+                impl_name.clone(),
+                &impl_type,
+                &method_name,
+                &method_object,
+            );
+        }
+
+        return Ok(Object::new(statement.get_position(), Objects::Void));
+    }
+    // Interface:
+    else if statement.node.get_interface().is_some() {
+        return Ok(Object::new(statement.get_position(), Objects::Void));
+    }
     // Return
     else if let Some(value) = statement.node.get_return() {
         let mut object: Object = Object::new(statement.get_position(), Objects::Void);
@@ -304,6 +1291,60 @@ pub fn evaluate_statement(
 
         return Ok(Object::new(statement.get_position(), Objects::Return(Box::new(object))));
     }
+    // Type alias:
+    else if statement.node.get_type_alias().is_some() {
+        return Ok(Object::new(statement.get_position(), Objects::Void));
+    }
+    // Variable:
+    else if let Some((_, pattern, data_type, value)) =
+        statement.node.get_variable()
+    {
+        let type_name = match (&data_type, &value) {
+            (Some(data_type), _) => {
+                environment.get_store().get_data_type_name(data_type)
+            }
+            (None, Some(value)) => {
+                environment.get_store().get_expression_type_name(value)
+            }
+            (None, None) => None,
+        };
+        let object = match value {
+            // Convert the `JSON.parse` values to the declared data type.
+            Some(value)
+                if data_type.is_some()
+                    && builtins::json::is_parse_call(
+                        &value,
+                        environment.get_store(),
+                    ) =>
+            {
+                let object = evaluate_expression(&value, environment)?;
+
+                builtins::json::convert(
+                    object,
+                    &data_type.unwrap(),
+                    environment,
+                )?
+            }
+            Some(value) => evaluate_expression(&value, environment)?,
+            None => Object::new(
+                statement.get_position(),
+                Objects::Null(Box::new(Object::new(
+                    statement.get_position(),
+                    Objects::Unknown,
+                ))),
+            ),
+        };
+
+        evaluate_pattern(&pattern, &object, environment)?;
+
+        if let Some(variable_name) = pattern.node.get_identifier() {
+            environment
+                .get_store()
+                .add_type_name(&variable_name, type_name);
+        }
+
+        return Ok(object);
+    }
 
     Err(Error::new_unknown_token(statement.get_position()))
 }
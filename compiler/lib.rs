@@ -1,19 +1,25 @@
+#[cfg(test)]
+#[macro_use]
+mod test_utils;
+
 pub mod builtins;
 mod environment;
 mod evaluator;
 mod objects;
 mod typechecker;
 
-pub use environment::{Environment, Store};
+pub use environment::{Environment, Member, Store};
 pub use objects::{Object, Objects};
 
-use sflynlang_parser::{ast::Statement, File};
-
-pub fn run(statements: Vec<Statement>, debug_mode: bool, file: &File) -> i32 {
-    let mut environment = Environment::new();
-
-    environment.set_debug_mode(debug_mode);
+use sflynlang_parser::{ast::Statement, ErrorType, File};
 
+/// Check and evaluate the statements in the environment (configured by the
+/// caller, like the debug mode) and get the exit code.
+pub fn run(
+    statements: Vec<Statement>,
+    mut environment: Environment,
+    file: &File,
+) -> i32 {
     for statement in statements.iter() {
         if let Err(error) =
             typechecker::check_statement(statement, &mut environment)
@@ -27,6 +33,12 @@ pub fn run(statements: Vec<Statement>, debug_mode: bool, file: &File) -> i32 {
             if let Err(error) =
                 evaluator::evaluate_statement(statement, &mut environment)
             {
+                // Stop the program without errors when it exits.
+                if let ErrorType::Exit(code) = error.get_error_type() {
+                    environment.show_errors(file);
+                    return code;
+                }
+
                 environment.add_error(error);
             }
         }
@@ -1,9 +1,16 @@
-use crate::Environment;
+use crate::{builtins::Function, Environment, Store};
 use sflynlang_parser::{
-    ast::{DataType, DataTypes, Statement},
-    Position,
+    ast::{DataType, DataTypes, Expression, Statement},
+    OrderedMap, Position,
 };
-use std::collections::HashMap;
+use std::{cell::RefCell, rc::Rc};
+
+/// The arguments, body, return object and environment of a function object.
+pub type FunctionParts =
+    (Vec<Expression>, Vec<Statement>, Box<Object>, Environment);
+
+/// The fields of the instances that contain the value being displayed.
+type Parents = [*const RefCell<Store>];
 
 #[derive(Clone, Debug)]
 pub struct Object {
@@ -26,9 +33,76 @@ impl Object {
 
     pub fn to_data_type(&self) -> DataType {
         match self.get_node() {
+            Objects::Array(values) => DataType::new(
+                self.get_position(),
+                DataTypes::Array(Box::new(match values.borrow().first() {
+                    Some(value) => value.to_data_type(),
+                    None => {
+                        DataType::new(self.get_position(), DataTypes::Unknown)
+                    }
+                })),
+            ),
             Objects::Boolean(_) => {
                 DataType::new(self.get_position(), DataTypes::Boolean)
             }
+            Objects::Builtin(function) => {
+                (function.get_type)(self.get_position())
+            }
+            Objects::Class {
+                name,
+                fields: _,
+                constructor,
+            } => DataType::new(
+                self.get_position(),
+                DataTypes::Function(
+                    // Skip the `self` argument of the constructor.
+                    constructor
+                        .and_then(|constructor| {
+                            constructor.to_data_type().node.get_function()
+                        })
+                        .map(|(arguments, _)| arguments[1..].to_vec())
+                        .unwrap_or_default(),
+                    Box::new(DataType::new(
+                        self.get_position(),
+                        DataTypes::Instance(name),
+                    )),
+                ),
+            ),
+            Objects::EnumConstructor {
+                enum_name,
+                variant: _,
+                arguments,
+            } => DataType::new(
+                self.get_position(),
+                DataTypes::Function(
+                    arguments
+                        .iter()
+                        .filter_map(|argument| argument.node.get_argument())
+                        .map(|(name, data_type, _)| {
+                            DataType::new(
+                                data_type.get_position(),
+                                DataTypes::Argument {
+                                    name,
+                                    data_type,
+                                    is_optional: false,
+                                },
+                            )
+                        })
+                        .collect(),
+                    Box::new(DataType::new(
+                        self.get_position(),
+                        DataTypes::Enum(enum_name, Vec::new()),
+                    )),
+                ),
+            ),
+            Objects::EnumVariant {
+                enum_name,
+                variant: _,
+                values: _,
+            } => DataType::new(
+                self.get_position(),
+                DataTypes::Enum(enum_name, Vec::new()),
+            ),
             Objects::Function {
                 arguments,
                 body: _,
@@ -37,14 +111,28 @@ impl Object {
             } => DataType::new(
                 self.get_position(),
                 DataTypes::Function(
-                    arguments.iter().map(|(_key, value)| value.to_data_type()).collect(),
+                    arguments
+                        .iter()
+                        .filter_map(|argument| argument.node.get_argument())
+                        .map(|(name, data_type, value)| {
+                            DataType::new(
+                                data_type.get_position(),
+                                DataTypes::Argument {
+                                    name,
+                                    data_type,
+                                    is_optional: value.is_some(),
+                                },
+                            )
+                        })
+                        .collect(),
                     Box::new(return_obj.to_data_type()),
                 ),
             ),
             Objects::HashMap(data) => {
-                let mut data_data: HashMap<String, Box<DataType>> = HashMap::new();
+                let mut data_data: OrderedMap<Box<DataType>> =
+                    OrderedMap::new();
 
-                for (key, value) in data.iter() {
+                for (key, value) in data.borrow().iter() {
                     data_data.insert(key.clone(), Box::new(value.to_data_type()));
                 }
 
@@ -53,6 +141,10 @@ impl Object {
                     DataTypes::HashMap(data_data)
                 )
             },
+            Objects::Instance { class_name, .. } => DataType::new(
+                self.get_position(),
+                DataTypes::Instance(class_name),
+            ),
             Objects::Null(value) => DataType::new(self.get_position(), DataTypes::Option(Box::new(value.to_data_type()))),
             Objects::Number(_) => {
                 DataType::new(self.get_position(), DataTypes::Number)
@@ -61,6 +153,12 @@ impl Object {
             Objects::String(_) => {
                 DataType::new(self.get_position(), DataTypes::String)
             }
+            Objects::Tuple(values) => DataType::new(
+                self.get_position(),
+                DataTypes::Tuple(
+                    values.iter().map(|value| value.to_data_type()).collect(),
+                ),
+            ),
             Objects::Unknown => DataType::new(self.get_position(), DataTypes::Unknown),
             Objects::Void => DataType::new(self.get_position(), DataTypes::Void),
         }
@@ -69,27 +167,81 @@ impl Object {
     pub fn to_string(&self) -> String {
         self.get_node().to_string()
     }
+
+    pub fn to_pretty_string(&self) -> String {
+        self.get_node().to_pretty_string()
+    }
 }
 
 #[derive(Clone, Debug)]
 pub enum Objects {
+    /// The arrays are shared by their copies, so the methods that mutate them
+    /// (like `push`) update every variable that has them.
+    Array(Rc<RefCell<Vec<Object>>>),
     Boolean(bool),
+    Builtin(&'static Function),
+    Class {
+        name: String,
+        fields: Vec<Expression>,
+        constructor: Option<Box<Object>>,
+    },
+    EnumConstructor {
+        enum_name: String,
+        variant: String,
+        arguments: Vec<Expression>,
+    },
+    EnumVariant {
+        enum_name: String,
+        variant: String,
+        values: Vec<Object>,
+    },
     Function {
-        arguments: HashMap<String, Object>,
+        arguments: Vec<Expression>,
         body: Vec<Statement>,
         return_obj: Box<Object>,
-        environment: Environment,
+        environment: Box<Environment>,
+    },
+    /// The hashmaps are shared by their copies like the arrays.
+    HashMap(Rc<RefCell<OrderedMap<Object>>>),
+    Instance {
+        class_name: String,
+        /// The names of the fields in their declaration order.
+        field_names: Vec<String>,
+        fields: Rc<RefCell<Store>>,
     },
-    HashMap(HashMap<String, Object>),
     Null(Box<Object>),
-    Number(i64),
+    Number(f64),
     Return(Box<Object>),
     String(String),
+    Tuple(Vec<Object>),
     Unknown,
     Void,
 }
 
 impl Objects {
+    pub fn new_array(values: Vec<Object>) -> Self {
+        Self::Array(Rc::new(RefCell::new(values)))
+    }
+
+    pub fn new_hashmap(data: OrderedMap<Object>) -> Self {
+        Self::HashMap(Rc::new(RefCell::new(data)))
+    }
+
+    pub fn get_array(&self) -> Option<Vec<Object>> {
+        match self {
+            Self::Array(values) => Some(values.borrow().clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the shared values of an array to mutate them.
+    pub fn get_array_ref(&self) -> Option<Rc<RefCell<Vec<Object>>>> {
+        match self {
+            Self::Array(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+
     pub fn get_boolean(&self) -> Option<bool> {
         match self {
             Self::Boolean(value) => Some(value.clone()),
@@ -97,9 +249,51 @@ impl Objects {
         }
     }
 
-    pub fn get_function(
+    pub fn get_builtin(&self) -> Option<&'static Function> {
+        match self {
+            Self::Builtin(function) => Some(function),
+            _ => None,
+        }
+    }
+
+    pub fn get_class(
         &self,
-    ) -> Option<(HashMap<String, Object>, Vec<Statement>, Box<Object>, Environment)> {
+    ) -> Option<(String, Vec<Expression>, Option<Box<Object>>)> {
+        match self {
+            Self::Class {
+                name,
+                fields,
+                constructor,
+            } => Some((name.clone(), fields.clone(), constructor.clone())),
+            _ => None,
+        }
+    }
+
+    pub fn get_enum_constructor(
+        &self,
+    ) -> Option<(String, String, Vec<Expression>)> {
+        match self {
+            Self::EnumConstructor {
+                enum_name,
+                variant,
+                arguments,
+            } => Some((enum_name.clone(), variant.clone(), arguments.clone())),
+            _ => None,
+        }
+    }
+
+    pub fn get_enum_variant(&self) -> Option<(String, String, Vec<Object>)> {
+        match self {
+            Self::EnumVariant {
+                enum_name,
+                variant,
+                values,
+            } => Some((enum_name.clone(), variant.clone(), values.clone())),
+            _ => None,
+        }
+    }
+
+    pub fn get_function(&self) -> Option<FunctionParts> {
         match self {
             Self::Function {
                 arguments,
@@ -110,19 +304,61 @@ impl Objects {
                 arguments.clone(),
                 body.clone(),
                 return_obj.clone(),
-                environment.clone(),
+                *environment.clone(),
             )),
             _ => None,
         }
     }
 
-    pub fn get_hashmap(&self) -> Option<HashMap<String, Object>> {
+    pub fn get_hashmap(&self) -> Option<OrderedMap<Object>> {
+        match self {
+            Self::HashMap(data) => Some(data.borrow().clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the shared data of a hashmap to mutate it.
+    pub fn get_hashmap_ref(&self) -> Option<Rc<RefCell<OrderedMap<Object>>>> {
         match self {
             Self::HashMap(data) => Some(data.clone()),
             _ => None,
         }
     }
 
+    pub fn get_instance(&self) -> Option<(String, Rc<RefCell<Store>>)> {
+        match self {
+            Self::Instance {
+                class_name, fields, ..
+            } => Some((class_name.clone(), fields.clone())),
+            _ => None,
+        }
+    }
+
+    /// Get the fields of an instance in their declaration order.
+    pub fn get_instance_fields(&self) -> Option<OrderedMap<Object>> {
+        match self {
+            Self::Instance {
+                field_names,
+                fields,
+                ..
+            } => {
+                let fields = fields.borrow();
+
+                Some(
+                    field_names
+                        .iter()
+                        .filter_map(|name| {
+                            fields
+                                .get_object(name)
+                                .map(|value| (name.clone(), value))
+                        })
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_null(&self) -> Option<Box<Object>> {
         match self {
             Self::Null(value) => Some(value.clone()),
@@ -130,9 +366,9 @@ impl Objects {
         }
     }
 
-    pub fn get_number(&self) -> Option<i64> {
+    pub fn get_number(&self) -> Option<f64> {
         match self {
-            Self::Number(value) => Some(value.clone()),
+            Self::Number(value) => Some(*value),
             _ => None,
         }
     }
@@ -151,18 +387,19 @@ impl Objects {
         }
     }
 
-    pub fn is_unknown(&self) -> bool {
+    pub fn get_tuple(&self) -> Option<Vec<Object>> {
         match self {
-            Self::Unknown => true,
-            _ => false,
+            Self::Tuple(values) => Some(values.clone()),
+            _ => None,
         }
     }
 
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown)
+    }
+
     pub fn is_void(&self) -> bool {
-        match self {
-            Self::Void => true,
-            _ => false,
-        }
+        matches!(self, Self::Void)
     }
 
     pub fn is_trusthy(&self) -> bool {
@@ -173,8 +410,58 @@ impl Objects {
     }
 
     pub fn to_string(&self) -> String {
+        self.to_string_within(&[])
+    }
+
+    /// Get the string of a value inside the fields of the given instances,
+    /// replacing the instances that contain themselves with `Name { ... }`.
+    fn to_string_within(&self, parents: &Parents) -> String {
+        let to_nested_string =
+            |value: &Object| value.get_node().to_nested_string_within(parents);
+
         match self {
+            Self::Array(values) => format!(
+                "[{}]",
+                values
+                    .borrow()
+                    .iter()
+                    .map(to_nested_string)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
             Self::Boolean(value) => value.to_string(),
+            Self::Builtin(function) => {
+                (function.get_type)(Position::new(0, 0, 1, 1)).node.to_string()
+            }
+            Self::Class {
+                name,
+                fields: _,
+                constructor: _,
+            } => format!("class {}", name),
+            Self::EnumConstructor {
+                enum_name,
+                variant,
+                arguments: _,
+            } => format!("{}.{}", enum_name, variant),
+            Self::EnumVariant {
+                enum_name: _,
+                variant,
+                values,
+            } => {
+                if values.is_empty() {
+                    return variant.clone();
+                }
+
+                format!(
+                    "{}({})",
+                    variant,
+                    values
+                        .iter()
+                        .map(to_nested_string)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
             Self::Function {
                 arguments,
                 body: _,
@@ -182,19 +469,233 @@ impl Objects {
                 environment: _,
             } => format!(
                 "({}) => {}",
-                arguments.iter().map(|(_, value)| value.to_string()).collect::<Vec<String>>().join(", "),
+                arguments
+                    .iter()
+                    .map(|argument| argument.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
                 return_obj.to_string()
             ),
-            Self::HashMap(data) => format!(
-                "{{\n{}\n}}",
-                data.iter().map(|(key, value)| format!("{}: {}", key, value.to_string())).collect::<Vec<String>>().join(", ")
-            ),
+            Self::HashMap(data) => {
+                data.borrow().to_inline_string(to_nested_string)
+            }
+            Self::Instance {
+                class_name, fields, ..
+            } => {
+                let pointer = Rc::as_ptr(fields);
+
+                if parents.contains(&pointer) {
+                    return format!("{} {{ ... }}", class_name);
+                }
+
+                let parents = [parents, &[pointer]].concat();
+
+                format!(
+                    "{} {}",
+                    class_name,
+                    self.get_instance_fields()
+                        .unwrap_or_default()
+                        .to_inline_string(|value| {
+                            value.get_node().to_nested_string_within(&parents)
+                        })
+                )
+            }
             Self::Null(_) => String::from("null"),
             Self::Number(value) => value.to_string(),
-            Self::Return(value) => value.to_string(),
+            Self::Return(value) => value.get_node().to_string_within(parents),
             Self::String(value) => value.clone(),
+            Self::Tuple(values) => format!(
+                "({})",
+                values
+                    .iter()
+                    .map(to_nested_string)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
             Self::Unknown => String::from("Unknown"),
             Self::Void => String::from("void"),
         }
     }
+
+    /// Get the string of a value to print it, with the hashmaps and the
+    /// instances indented on several lines.
+    pub fn to_pretty_string(&self) -> String {
+        self.to_pretty_string_within(&[])
+    }
+
+    fn to_pretty_string_within(&self, parents: &Parents) -> String {
+        let to_pretty_nested_string =
+            |value: &Object, parents: &Parents| match value.get_node() {
+                Self::String(value) => format!("{:?}", value),
+                node => node.to_pretty_string_within(parents),
+            };
+
+        match self {
+            Self::HashMap(data) => data.borrow().to_pretty_string(|value| {
+                to_pretty_nested_string(value, parents)
+            }),
+            Self::Instance {
+                class_name, fields, ..
+            } => {
+                let pointer = Rc::as_ptr(fields);
+
+                if parents.contains(&pointer) {
+                    return format!("{} {{ ... }}", class_name);
+                }
+
+                let parents = [parents, &[pointer]].concat();
+
+                format!(
+                    "{} {}",
+                    class_name,
+                    self.get_instance_fields()
+                        .unwrap_or_default()
+                        .to_pretty_string(|value| {
+                            to_pretty_nested_string(value, &parents)
+                        })
+                )
+            }
+            _ => self.to_string_within(parents),
+        }
+    }
+
+    /// Get the string of a value inside another value (like an array item),
+    /// quoting the strings.
+    pub fn to_nested_string(&self) -> String {
+        self.to_nested_string_within(&[])
+    }
+
+    fn to_nested_string_within(&self, parents: &Parents) -> String {
+        match self {
+            Self::String(value) => format!("{:?}", value),
+            _ => self.to_string_within(parents),
+        }
+    }
+}
+
+impl PartialEq for Objects {
+    fn eq(&self, other: &Self) -> bool {
+        if let Some(self_values) = self.get_array() {
+            if let Some(other_values) = other.get_array() {
+                return self_values.len() == other_values.len()
+                    && self_values.iter().zip(other_values.iter()).all(
+                        |(self_value, other_value)| {
+                            self_value.get_node() == other_value.get_node()
+                        },
+                    );
+            }
+        } else if let Some(self_value) = self.get_boolean() {
+            if let Some(other_value) = other.get_boolean() {
+                return self_value == other_value;
+            }
+        } else if let Some(self_function) = self.get_builtin() {
+            if let Some(other_function) = other.get_builtin() {
+                return std::ptr::eq(self_function, other_function);
+            }
+        } else if let Some((self_enum, self_variant, self_values)) =
+            self.get_enum_variant()
+        {
+            if let Some((other_enum, other_variant, other_values)) =
+                other.get_enum_variant()
+            {
+                return self_enum == other_enum
+                    && self_variant == other_variant
+                    && self_values.len() == other_values.len()
+                    && self_values.iter().zip(other_values.iter()).all(
+                        |(self_value, other_value)| {
+                            self_value.get_node() == other_value.get_node()
+                        },
+                    );
+            }
+        } else if let Some(self_data) = self.get_hashmap() {
+            if let Some(other_data) = other.get_hashmap() {
+                return self_data.len() == other_data.len()
+                    && self_data.iter().all(|(key, value)| {
+                        match other_data.get(key) {
+                            Some(other_value) => {
+                                value.get_node() == other_value.get_node()
+                            }
+                            None => false,
+                        }
+                    });
+            }
+        } else if let Some((_, self_fields)) = self.get_instance() {
+            // The instances are equal when they share the same fields.
+            if let Some((_, other_fields)) = other.get_instance() {
+                return Rc::ptr_eq(&self_fields, &other_fields);
+            }
+        } else if let Some(self_value) = self.get_number() {
+            if let Some(other_value) = other.get_number() {
+                return self_value == other_value;
+            }
+        } else if let Some(self_value) = self.get_string() {
+            if let Some(other_value) = other.get_string() {
+                return self_value == other_value;
+            }
+        } else if let Some(self_values) = self.get_tuple() {
+            if let Some(other_values) = other.get_tuple() {
+                return self_values.len() == other_values.len()
+                    && self_values.iter().zip(other_values.iter()).all(
+                        |(self_value, other_value)| {
+                            self_value.get_node() == other_value.get_node()
+                        },
+                    );
+            }
+        }
+
+        (self.get_null().is_some() && other.get_null().is_some())
+            || (self.is_void() && other.is_void())
+    }
+}
+
+#[test]
+fn test_display() {
+    use crate::test_utils::run_code;
+
+    let mut environment = run_code(
+        "class Point {\n  public y: number;\n  public x: number;\n  constructor(self, x: number, y: number) {\n    self.x = x;\n    self.y = y;\n  }\n}\nconst point = Point(1, 2);\nconst user = { name: 'Sflyn', tags: ['a'], meta: { point: point } };\nconst empty = {};",
+    )
+    .unwrap();
+    let mut get_object = |name: &str| {
+        environment
+            .get_store()
+            .get_object(&String::from(name))
+            .unwrap()
+    };
+
+    // The instances and the hashmaps share the format and the fields keep
+    // their declaration and insertion order.
+    assert_eq!(
+        get_object("point").to_string(),
+        "Point { \"y\": 2, \"x\": 1 }"
+    );
+    assert_eq!(
+        get_object("user").to_string(),
+        "{ \"name\": \"Sflyn\", \"tags\": [\"a\"], \"meta\": { \"point\": Point { \"y\": 2, \"x\": 1 } } }"
+    );
+    assert_eq!(get_object("empty").to_string(), "{}");
+    assert_eq!(
+        get_object("user").to_pretty_string(),
+        "{\n  \"name\": \"Sflyn\",\n  \"tags\": [\"a\"],\n  \"meta\": {\n    \"point\": Point {\n      \"y\": 2,\n      \"x\": 1\n    }\n  }\n}"
+    );
+    // The instances that contain themselves are not expanded again.
+    let mut environment = run_code(
+        "class Node {\n  public value: number;\n  public next: Node? = null;\n  constructor(self, value: number) {\n    self.value = value;\n  }\n  public func link(self, next: Node) {\n    self.next = next;\n  }\n}\nconst first = Node(1);\nconst second = Node(2);\nfirst.link(second);\nsecond.link(first);\nconst text = format('{}', [first, first]);",
+    )
+    .unwrap();
+    let mut get_object = |name: &str| {
+        environment
+            .get_store()
+            .get_object(&String::from(name))
+            .unwrap()
+    };
+
+    assert_eq!(
+        get_object("text").to_string(),
+        "[Node { \"value\": 1, \"next\": Node { \"value\": 2, \"next\": Node { ... } } }, Node { \"value\": 1, \"next\": Node { \"value\": 2, \"next\": Node { ... } } }]"
+    );
+    assert_eq!(
+        get_object("second").to_pretty_string(),
+        "Node {\n  \"value\": 2,\n  \"next\": Node {\n    \"value\": 1,\n    \"next\": Node { ... }\n  }\n}"
+    );
 }
@@ -0,0 +1,102 @@
+use crate::{evaluator, typechecker, Environment};
+use sflynlang_parser::{ast::Statement, Error, ErrorType, Lexer, Parser};
+
+/// Check that the result has an error matching the pattern.
+macro_rules! assert_error {
+    ($result: expr, $expected: pat) => {
+        match crate::test_utils::get_error_type($result) {
+            $expected => {}
+            error_type => panic!("Unexpected error type: {:?}", error_type),
+        }
+    };
+}
+
+/// Get the statements of the code.
+pub fn parse_code(code: &str) -> Vec<Statement> {
+    let mut lexer = Lexer::new(String::from(code));
+    let mut parser = Parser::new(lexer.run().unwrap());
+
+    parser.run().unwrap()
+}
+
+/// Check the statements of the code in a new environment, stopping at the
+/// first error.
+pub fn check_code(code: &str) -> Result<(), Error> {
+    let mut environment = Environment::new();
+
+    for statement in parse_code(code).iter() {
+        typechecker::check_statement(statement, &mut environment)?;
+    }
+
+    Ok(())
+}
+
+/// Check the code after the prelude (like the declarations shared by the cases
+/// of a test), stopping at the first error.
+pub fn check_with(prelude: &str, code: &str) -> Result<(), Error> {
+    check_code(&format!("{}\n{}", prelude, code))
+}
+
+/// Check every statement of the code in a new environment and get the errors.
+pub fn check_errors(code: &str) -> Vec<Error> {
+    let mut environment = Environment::new();
+    let mut errors = Vec::new();
+
+    for statement in parse_code(code).iter() {
+        if let Err(error) =
+            typechecker::check_statement(statement, &mut environment)
+        {
+            errors.push(error);
+        }
+    }
+
+    errors
+}
+
+/// Check and evaluate each statement of the code in the environment (like an
+/// environment with a test reader), stopping at the first error.
+pub fn run_code_in(
+    code: &str,
+    environment: &mut Environment,
+) -> Result<(), Error> {
+    for statement in parse_code(code).iter() {
+        typechecker::check_statement(statement, environment)?;
+        evaluator::evaluate_statement(statement, environment)?;
+    }
+
+    Ok(())
+}
+
+/// Check and evaluate the code in a new environment.
+pub fn run_code(code: &str) -> Result<Environment, Error> {
+    let mut environment = Environment::new();
+
+    run_code_in(code, &mut environment)?;
+
+    Ok(environment)
+}
+
+/// Get the string of the value of a variable.
+pub fn get_value(environment: &mut Environment, name: &str) -> Option<String> {
+    environment
+        .get_store()
+        .get_object(&String::from(name))
+        .map(|object| object.to_string())
+}
+
+/// Get the error type of the result, failing when it does not have an error.
+pub fn get_error_type<T>(result: Result<T, Error>) -> ErrorType {
+    match result {
+        Err(error) => error.get_error_type(),
+        Ok(_) => panic!("Expect an error."),
+    }
+}
+
+/// Get the message of the error of the result, failing when it does not have
+/// an error.
+pub fn get_error_message<T>(result: Result<T, Error>) -> String {
+    match result {
+        Err(error) => error.get_message(),
+        Ok(_) => panic!("Expect an error."),
+    }
+}
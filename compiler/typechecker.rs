@@ -1,16 +1,18 @@
-use crate::{builtins, Environment, Store};
+use crate::{builtins, Environment, Member, Store};
 use sflynlang_parser::{
-    ast::{DataType, DataTypes, Expression, Statement},
-    Error, Position, Token,
+    ast::{
+        DataType, DataTypes, Expression, Expressions, Pattern, Patterns,
+        Statement, VariantType,
+    },
+    Error, OrderedMap, Position, Token,
 };
+use std::collections::HashMap;
 
 fn check_body(
     body: Vec<Statement>,
     environment: &mut Environment,
 ) -> Result<DataType, Error> {
-    let mut data_type: DataType =
-        DataType::new(Position::new(0, 0, 1, 1), DataTypes::Unknown);
-    let mut is_first_stmt = true;
+    let mut data_type: Option<DataType> = None;
     let mut has_main_return = false;
 
     for statement in body.iter() {
@@ -23,202 +25,1716 @@ fn check_body(
 
         let stmt_type = check_statement(statement, environment)?;
 
+        // Narrow the data type of the next statements when an if without
+        // alternative returns from a `typeof` condition.
+        if let Some((condition, consequence, alternative)) = statement
+            .node
+            .get_expression()
+            .and_then(|expression| expression.node.get_if())
+        {
+            if alternative.is_empty()
+                && consequence
+                    .last()
+                    .and_then(|last| last.node.get_return())
+                    .is_some()
+            {
+                if let Some((name, _, data_type)) =
+                    get_narrowed_types(&condition, environment)
+                {
+                    environment.get_store().add_data_type(&name, &data_type);
+                }
+            }
+        }
+
         if statement.node.get_return().is_some() {
             has_main_return = true;
         }
-
-        if is_first_stmt {
-            is_first_stmt = false;
-            data_type = stmt_type;
+        // Only the returns (including the ones inside an if or a try
+        // expression) define the data type of the body.
+        else if !is_block_statement(statement) || stmt_type.node.is_void() {
             continue;
         }
 
-        if data_type.node != stmt_type.node {
-            return Err(Error::new_expect_type(
-                statement.get_position(),
-                &data_type.node.to_string(),
-                &stmt_type.node.to_string(),
-            ));
+        data_type = Some(match data_type {
+            Some(data_type) => unify_data_types(&data_type, &stmt_type),
+            None => stmt_type,
+        });
+    }
+
+    Ok(data_type.unwrap_or_else(|| {
+        DataType::new(Position::new(0, 0, 1, 1), DataTypes::Void)
+    }))
+}
+
+/// Get the data type that accepts the values of both data types, creating an
+/// union when they are different.
+pub(crate) fn unify_data_types(
+    data_type: &DataType,
+    other_type: &DataType,
+) -> DataType {
+    // A null value makes the other data type optional.
+    if is_null_type(data_type) && other_type.node.get_option().is_none() {
+        return DataType::new(
+            data_type.get_position(),
+            DataTypes::Option(Box::new(other_type.clone())),
+        );
+    } else if is_null_type(other_type) && data_type.node.get_option().is_none()
+    {
+        return DataType::new(
+            data_type.get_position(),
+            DataTypes::Option(Box::new(data_type.clone())),
+        );
+    }
+
+    if data_type.node.is_assignable(&other_type.node) {
+        return data_type.clone();
+    } else if other_type.node.is_assignable(&data_type.node) {
+        return DataType::new(
+            data_type.get_position(),
+            other_type.node.clone(),
+        );
+    }
+
+    let mut union_types: Vec<DataType> = data_type
+        .node
+        .get_union()
+        .unwrap_or_else(|| vec![data_type.clone()]);
+
+    for other_member in other_type
+        .node
+        .get_union()
+        .unwrap_or_else(|| vec![other_type.clone()])
+    {
+        if !union_types
+            .iter()
+            .any(|member| member.node.is_assignable(&other_member.node))
+        {
+            union_types.push(other_member);
         }
     }
 
-    Ok(data_type)
+    DataType::new(data_type.get_position(), DataTypes::Union(union_types))
 }
 
-pub fn check_expression(
-    expression: &Expression,
+/// Check if the data type is the data type of a `null` value.
+fn is_null_type(data_type: &DataType) -> bool {
+    match data_type.node.get_option() {
+        Some(value_type) => value_type.node.is_unknown(),
+        None => false,
+    }
+}
+
+/// Get the identifier and its narrowed data types (for the consequence and
+/// the alternative) from a condition like `typeof value == "string"`.
+fn get_narrowed_types(
+    condition: &Expression,
     environment: &mut Environment,
-) -> Result<DataType, Error> {
-    // Argument
-    if let Some((argument_name, argument_type, argument_value)) =
-        expression.node.get_argument()
-    {
-        // Check if the name is already in use.
-        if environment.get_store().has_key_type(&argument_name) {
-            return Err(Error::new_name_in_use(
-                expression.get_position(),
-                argument_name.clone(),
-                environment
-                    .get_store()
-                    .get_data_type_with_outer(&argument_name)
-                    .unwrap()
-                    .get_position(),
-            ));
+) -> Option<(String, DataType, DataType)> {
+    let (left_exp, operator, right_exp) = condition.node.get_infix()?;
+
+    if operator != Token::DoubleEqual && operator != Token::NotEqual {
+        return None;
+    }
+
+    let (prefix, value) = left_exp.node.get_prefix()?;
+
+    if prefix != Token::Typeof {
+        return None;
+    }
+
+    let name = value.node.get_identifier()?;
+    let type_name = right_exp.node.get_string()?;
+    let members = environment
+        .get_store()
+        .get_data_type_with_outer(&name)?
+        .node
+        .get_union()?;
+
+    let (matched, others): (Vec<DataType>, Vec<DataType>) = members
+        .into_iter()
+        .partition(|member| member.node.get_typeof() == type_name);
+
+    if matched.is_empty() || others.is_empty() {
+        return None;
+    }
+
+    let to_data_type = |mut members: Vec<DataType>| {
+        if members.len() == 1 {
+            members.remove(0)
+        } else {
+            DataType::new(members[0].get_position(), DataTypes::Union(members))
         }
+    };
 
-        if let Some(argument_value) = argument_value {
-            let value_type = check_expression(&argument_value, environment)?;
+    if operator == Token::DoubleEqual {
+        Some((name, to_data_type(matched), to_data_type(others)))
+    } else {
+        Some((name, to_data_type(others), to_data_type(matched)))
+    }
+}
 
-            if argument_type.node != value_type.node {
-                return Err(Error::new_expect_type(
-                    argument_value.get_position(),
-                    &argument_type.node.to_string(),
-                    &value_type.node.to_string(),
-                ));
-            }
+/// Create an inner environment where the identifier has the narrowed data
+/// type.
+fn get_narrowed_environment(
+    name: &String,
+    data_type: &DataType,
+    environment: &mut Environment,
+) -> Environment {
+    let mut narrowed_environment = environment.clone();
 
-            let data_type = DataType::new(
-                argument_type.get_position(),
-                DataTypes::Option(Box::new(value_type)),
-            );
+    narrowed_environment.set_store(&Store::from_outer(environment.get_store()));
+    narrowed_environment
+        .get_store()
+        .add_data_type(name, data_type);
 
-            environment
-                .get_store()
-                .add_data_type(&argument_name, &data_type);
+    narrowed_environment
+}
 
-            return Ok(data_type);
+fn is_block_statement(statement: &Statement) -> bool {
+    match statement.node.get_expression() {
+        Some(expression) => {
+            expression.node.get_if().is_some()
+                || expression.node.get_try().is_some()
         }
+        None => false,
+    }
+}
 
-        environment
-            .get_store()
-            .add_data_type(&argument_name, &*argument_type);
+/// Get the data type of a function argument without its name and rest sign.
+fn get_argument_type(function_argument: &DataType) -> DataType {
+    let mut data_type = function_argument.clone();
 
-        return Ok(*argument_type);
+    if let Some(rest_type) = data_type.node.get_spread() {
+        data_type = *rest_type;
     }
-    // Boolean
-    else if let Some(_) = expression.node.get_boolean() {
-        return Ok(DataType::new(
-            expression.get_position(),
-            DataTypes::Boolean,
-        ));
+
+    if let Some((_, argument_type, _)) = data_type.node.get_argument() {
+        data_type = *argument_type;
     }
-    // Call
-    else if let Some((identifier, arguments)) = expression.node.get_call() {
-        if let Some(identifier_name) = identifier.node.get_identifier() {
-            if !environment.get_store().has_key_type(&identifier_name) {
-                return Err(Error::new_unknown_identifier(
-                    identifier.get_position(),
-                    identifier_name,
-                ));
+
+    data_type
+}
+
+/// Get the name of a function argument if it has one.
+fn get_argument_name(function_argument: &DataType) -> Option<String> {
+    match function_argument.node.get_spread() {
+        Some(rest_type) => get_argument_name(&rest_type),
+        None => function_argument
+            .node
+            .get_argument()
+            .map(|(name, _, _)| name),
+    }
+}
+
+/// Get the name and the variants of an enum data type from its definition,
+/// because the variants of a recursive enum are unknown while it is resolved.
+fn get_enum_variants(
+    data_type: &DataType,
+    environment: &mut Environment,
+) -> Option<(String, Vec<VariantType>)> {
+    let (enum_name, variants) = data_type.node.get_enum()?;
+
+    match environment
+        .get_store()
+        .get_type_definition_with_outer(&enum_name)
+        .and_then(|definition| definition.node.get_enum())
+    {
+        Some(definition) => Some(definition),
+        None => Some((enum_name, variants)),
+    }
+}
+
+/// Check if the pattern matches every value of the data type.
+fn is_irrefutable_pattern(
+    pattern: &Pattern,
+    data_type: &DataType,
+    environment: &mut Environment,
+) -> bool {
+    if let Some(name) = pattern.node.get_identifier() {
+        // The name of a variant only matches that variant.
+        return match get_enum_variants(data_type, environment) {
+            Some((_, variants)) => {
+                !variants.iter().any(|(variant, _)| variant == &name)
             }
+            None => true,
+        };
+    } else if let Some(values) = pattern.node.get_tuple() {
+        return match data_type.node.get_tuple() {
+            Some(values_types) => values.iter().zip(values_types.iter()).all(
+                |(value, value_type)| {
+                    is_irrefutable_pattern(value, value_type, environment)
+                },
+            ),
+            None => false,
+        };
+    }
 
-            let mut arguments_types: Vec<DataType> = Vec::new();
+    false
+}
 
-            for argument in arguments.iter() {
-                arguments_types.push(check_expression(argument, environment)?);
+/// Get the rows of patterns that match the variant, replacing their first
+/// pattern with the patterns of the variant values.
+fn specialize_rows(
+    rows: &[Vec<Pattern>],
+    variant_name: &String,
+    values_count: usize,
+    data_type: &DataType,
+    environment: &mut Environment,
+) -> Vec<Vec<Pattern>> {
+    let mut specialized_rows: Vec<Vec<Pattern>> = Vec::new();
+
+    for row in rows.iter() {
+        let first_pattern = &row[0];
+
+        let mut specialized_row: Vec<Pattern> = if let Some((name, values)) =
+            first_pattern.node.get_enum_variant()
+        {
+            if &name != variant_name {
+                continue;
             }
 
-            if environment.get_store().is_builtin(&identifier_name) {
-                return builtins::check_builtin(
-                    identifier_name,
-                    expression.get_position(),
-                    arguments_types,
+            values
+        } else if is_irrefutable_pattern(first_pattern, data_type, environment)
+        {
+            vec![
+                Pattern::new(
+                    first_pattern.get_position(),
+                    Patterns::Identifier(String::from("_")),
                 );
-            } else if let Some(env_type) = environment
-                .get_store()
-                .get_data_type_with_outer(&identifier_name)
-            {
-                if let Some((_, return_type)) = env_type.node.get_function() {
-                    return Ok(*return_type);
-                }
+                values_count
+            ]
+        } else if first_pattern.node.get_identifier().as_ref()
+            == Some(variant_name)
+        {
+            Vec::new()
+        } else {
+            continue;
+        };
+
+        specialized_row.extend(row.iter().skip(1).cloned());
+        specialized_rows.push(specialized_row);
+    }
+
+    specialized_rows
+}
+
+/// Check if the rows of patterns match every value of the data types.
+fn is_exhaustive(
+    rows: &[Vec<Pattern>],
+    data_types: &[DataType],
+    environment: &mut Environment,
+) -> bool {
+    let first_type = match data_types.first() {
+        Some(first_type) => first_type,
+        None => return !rows.is_empty(),
+    };
+
+    // Check every variant only when a pattern requires a variant, because
+    // the values of a recursive enum are endless.
+    if rows
+        .iter()
+        .any(|row| !is_irrefutable_pattern(&row[0], first_type, environment))
+    {
+        if let Some((_, variants)) = get_enum_variants(first_type, environment)
+        {
+            for (variant_name, arguments) in variants.iter() {
+                let variant_rows = specialize_rows(
+                    rows,
+                    variant_name,
+                    arguments.len(),
+                    first_type,
+                    environment,
+                );
+                let mut variant_types: Vec<DataType> =
+                    arguments.iter().map(get_argument_type).collect();
 
-                return Ok(env_type);
+                variant_types.extend(data_types.iter().skip(1).cloned());
+
+                if !is_exhaustive(&variant_rows, &variant_types, environment) {
+                    return false;
+                }
             }
-        } else {
-            return Err(Error::new_unknown_token(expression.get_position()));
+
+            return true;
         }
     }
-    // Identifier
-    else if let Some(identifier_name) = expression.node.get_identifier() {
-        if !environment.get_store().has_key_type(&identifier_name) {
-            return Err(Error::new_unknown_identifier(
-                expression.get_position(),
-                identifier_name,
+
+    let default_rows: Vec<Vec<Pattern>> = rows
+        .iter()
+        .filter(|row| is_irrefutable_pattern(&row[0], first_type, environment))
+        .map(|row| row[1..].to_vec())
+        .collect();
+
+    is_exhaustive(&default_rows, &data_types[1..], environment)
+}
+
+/// Add the type parameters to the type definitions of the environment and
+/// get them with their constraints resolved.
+fn add_type_parameters(
+    type_parameters: &[DataType],
+    environment: &mut Environment,
+) -> Result<Vec<DataType>, Error> {
+    let mut resolved_parameters: Vec<DataType> = Vec::new();
+
+    for type_parameter in type_parameters.iter() {
+        let parameter_name = type_parameter.node.get_parameter().unwrap();
+
+        // Check if the name is already in use.
+        if let Some(definition) =
+            environment.get_store().get_type_definition(&parameter_name)
+        {
+            return Err(Error::new_name_in_use(
+                type_parameter.get_position(),
+                parameter_name,
+                Some(definition.get_position()),
             ));
         }
 
-        return Ok(environment
+        let constraint = match type_parameter.node.get_parameter_constraint() {
+            Some(constraint) => {
+                Some(Box::new(resolve_data_type(&constraint, environment)?))
+            }
+            None => None,
+        };
+
+        let resolved_parameter = DataType::new(
+            type_parameter.get_position(),
+            DataTypes::Parameter(parameter_name.clone(), constraint),
+        );
+
+        environment
             .get_store()
-            .get_data_type_with_outer(&identifier_name)
-            .unwrap());
+            .add_type_definition(&parameter_name, &resolved_parameter);
+
+        resolved_parameters.push(resolved_parameter);
     }
-    // If
-    else if let Some((condition, consequence, alternative)) =
-        expression.node.get_if()
-    {
-        let condition_type = check_expression(&condition, environment)?;
 
-        if !condition_type.node.is_boolean() {
-            return Err(Error::new_expect_type(
-                condition.get_position(),
-                "boolean",
-                &condition_type.node.to_string(),
-            ));
+    Ok(resolved_parameters)
+}
+
+/// Check if the data type has the properties of the constraint, allowing
+/// more properties than the ones of the constraint.
+fn satisfies_constraint(constraint: &DataType, data_type: &DataType) -> bool {
+    if let Some(constraint_data) = constraint.node.get_hashmap() {
+        if let Some(data) = data_type.node.get_hashmap() {
+            return constraint_data.iter().all(|(key, value)| {
+                match data.get(key) {
+                    Some(property) => satisfies_constraint(value, property),
+                    None => false,
+                }
+            });
         }
+    }
 
-        let consequence_type = check_body(consequence, environment)?;
+    constraint.node.is_assignable(&data_type.node)
+}
 
-        if alternative.len() > 0 {
-            let alternative_type = check_body(alternative, environment)?;
+/// Get the data type with its type parameters when it has type parameters.
+fn with_type_parameters(
+    type_parameters: &[DataType],
+    data_type: DataType,
+) -> DataType {
+    if type_parameters.is_empty() {
+        return data_type;
+    }
 
-            if consequence_type.node != alternative_type.node {
-                return Err(Error::new_expect_type(
-                    expression.get_position(),
-                    &consequence_type.node.to_string(),
-                    &alternative_type.node.to_string(),
-                ));
+    DataType::new(
+        data_type.get_position(),
+        DataTypes::Parameterized(type_parameters.to_vec(), Box::new(data_type)),
+    )
+}
+
+/// Replace the type parameters of the data type with their type arguments.
+fn substitute_type_arguments(
+    data_type: &DataType,
+    type_arguments: &HashMap<String, Option<DataType>>,
+) -> DataType {
+    let substitute_values = |values: &Vec<DataType>| -> Vec<DataType> {
+        values
+            .iter()
+            .map(|value| substitute_type_arguments(value, type_arguments))
+            .collect()
+    };
+
+    let node = match data_type.node.clone() {
+        DataTypes::Argument {
+            name,
+            data_type,
+            is_optional,
+        } => DataTypes::Argument {
+            name,
+            data_type: Box::new(substitute_type_arguments(
+                &data_type,
+                type_arguments,
+            )),
+            is_optional,
+        },
+        DataTypes::Array(item_type) => DataTypes::Array(Box::new(
+            substitute_type_arguments(&item_type, type_arguments),
+        )),
+        DataTypes::Function(arguments, return_type) => DataTypes::Function(
+            substitute_values(&arguments),
+            Box::new(substitute_type_arguments(&return_type, type_arguments)),
+        ),
+        DataTypes::HashMap(data) => DataTypes::HashMap(
+            data.iter()
+                .map(|(key, value)| {
+                    (
+                        key.clone(),
+                        Box::new(substitute_type_arguments(
+                            value,
+                            type_arguments,
+                        )),
+                    )
+                })
+                .collect(),
+        ),
+        DataTypes::Option(value_type) => DataTypes::Option(Box::new(
+            substitute_type_arguments(&value_type, type_arguments),
+        )),
+        DataTypes::Parameter(name, constraint) => {
+            match type_arguments.get(&name) {
+                Some(Some(type_argument)) => type_argument.node.clone(),
+                _ => DataTypes::Parameter(name, constraint),
             }
+        }
+        DataTypes::Spread(value_type) => DataTypes::Spread(Box::new(
+            substitute_type_arguments(&value_type, type_arguments),
+        )),
+        DataTypes::Tuple(values) => {
+            DataTypes::Tuple(substitute_values(&values))
+        }
+        DataTypes::Union(values) => {
+            DataTypes::Union(substitute_values(&values))
+        }
+        node => node,
+    };
+
+    DataType::new(data_type.get_position(), node)
+}
 
-            return Ok(alternative_type);
+/// Infer the type arguments that are not known yet from the data type of a
+/// value assigned to a data type with type parameters.
+fn infer_type_arguments(
+    data_type: &DataType,
+    value_type: &DataType,
+    type_arguments: &mut HashMap<String, Option<DataType>>,
+) {
+    let value_type = get_argument_type(value_type);
+
+    if let Some(name) = data_type.node.get_parameter() {
+        if let Some(type_argument) = type_arguments.get_mut(&name) {
+            if type_argument.is_none() && !value_type.node.is_unknown() {
+                *type_argument = Some(DataType::new(
+                    value_type.get_position(),
+                    value_type.node.widen(),
+                ));
+            }
+        }
+    } else if let Some((_, argument_type, _)) = data_type.node.get_argument() {
+        infer_type_arguments(&argument_type, &value_type, type_arguments);
+    } else if let Some(item_type) = data_type.node.get_array() {
+        if let Some(value_item_type) = value_type.node.get_array() {
+            infer_type_arguments(&item_type, &value_item_type, type_arguments);
         }
+    } else if let Some((arguments, return_type)) = data_type.node.get_function()
+    {
+        if let Some((value_arguments, value_return_type)) =
+            value_type.node.get_function()
+        {
+            for (argument, value_argument) in
+                arguments.iter().zip(value_arguments.iter())
+            {
+                infer_type_arguments(argument, value_argument, type_arguments);
+            }
 
-        return Ok(consequence_type);
+            infer_type_arguments(
+                &return_type,
+                &value_return_type,
+                type_arguments,
+            );
+        }
+    } else if let Some(data) = data_type.node.get_hashmap() {
+        if let Some(value_data) = value_type.node.get_hashmap() {
+            for (key, value) in data.iter() {
+                if let Some(value_value) = value_data.get(key) {
+                    infer_type_arguments(value, value_value, type_arguments);
+                }
+            }
+        }
+    } else if let Some(option_type) = data_type.node.get_option() {
+        match value_type.node.get_option() {
+            Some(value_option_type) => infer_type_arguments(
+                &option_type,
+                &value_option_type,
+                type_arguments,
+            ),
+            None => {
+                infer_type_arguments(&option_type, &value_type, type_arguments)
+            }
+        }
+    } else if let Some(spread_type) = data_type.node.get_spread() {
+        infer_type_arguments(&spread_type, &value_type, type_arguments);
+    } else if let Some(values) = data_type.node.get_tuple() {
+        if let Some(value_values) = value_type.node.get_tuple() {
+            for (value, value_value) in values.iter().zip(value_values.iter()) {
+                infer_type_arguments(value, value_value, type_arguments);
+            }
+        }
     }
-    // Infix
-    else if let Some((left_exp, operator, right_exp)) =
-        expression.node.get_infix()
-    {
-        // Check expression:
-        // Get the data type of the left expression.
-        let left_type = check_expression(&left_exp, environment)?;
+}
 
-        // Check expression:
-        // Get the data type of the right expression.
-        let right_type = check_expression(&right_exp, environment)?;
+/// Replace the names of the type definitions (like interfaces) with their
+/// data types.
+pub(crate) fn resolve_data_type(
+    data_type: &DataType,
+    environment: &mut Environment,
+) -> Result<DataType, Error> {
+    let node = match data_type.node.clone() {
+        DataTypes::Argument {
+            name,
+            data_type,
+            is_optional,
+        } => DataTypes::Argument {
+            name,
+            data_type: Box::new(resolve_data_type(&data_type, environment)?),
+            is_optional,
+        },
+        DataTypes::Array(item_type) => DataTypes::Array(Box::new(
+            resolve_data_type(&item_type, environment)?,
+        )),
+        DataTypes::Function(arguments, return_type) => {
+            let mut arguments_types: Vec<DataType> = Vec::new();
 
-        return match operator {
-            Token::Plus => {
-                if !left_type.node.is_string() && !left_type.node.is_number() {
-                    Err(Error::new_expect_type(
-                        left_exp.get_position(),
-                        "string or number",
-                        &left_type.node.to_string(),
-                    ))
-                } else if left_type.node != right_type.node {
-                    Err(Error::new_expect_type(
-                        right_exp.get_position(),
-                        &left_type.node.to_string(),
-                        &right_type.node.to_string(),
+            for argument in arguments.iter() {
+                arguments_types.push(resolve_data_type(argument, environment)?);
+            }
+
+            DataTypes::Function(
+                arguments_types,
+                Box::new(resolve_data_type(&return_type, environment)?),
+            )
+        }
+        DataTypes::HashMap(data) => {
+            let mut resolved_data: OrderedMap<Box<DataType>> =
+                OrderedMap::new();
+
+            for (key, value) in data.iter() {
+                resolved_data.insert(
+                    key.clone(),
+                    Box::new(resolve_data_type(value, environment)?),
+                );
+            }
+
+            DataTypes::HashMap(resolved_data)
+        }
+        DataTypes::Generic(name, arguments) => {
+            let definition = environment
+                .get_store()
+                .get_type_definition_with_outer(&name)
+                .ok_or_else(|| {
+                    Error::new_unknown_identifier(
+                        data_type.get_position(),
+                        name.clone(),
+                    )
+                })?;
+
+            let (type_parameters, definition_type) =
+                match definition.node.get_parameterized() {
+                    Some(parameterized) => parameterized,
+                    None => (Vec::new(), Box::new(definition)),
+                };
+
+            if type_parameters.len() != arguments.len() {
+                return Err(Error::new_expect_arguments(
+                    data_type.get_position(),
+                    type_parameters.len(),
+                    arguments.len(),
+                ));
+            }
+
+            let mut type_arguments: HashMap<String, Option<DataType>> =
+                HashMap::new();
+
+            for (type_parameter, argument) in
+                type_parameters.iter().zip(arguments.iter())
+            {
+                type_arguments.insert(
+                    type_parameter.node.get_parameter().unwrap(),
+                    Some(resolve_data_type(argument, environment)?),
+                );
+            }
+
+            substitute_type_arguments(&definition_type, &type_arguments).node
+        }
+        DataTypes::Identifier(name) => {
+            match environment
+                .get_store()
+                .get_type_definition_with_outer(&name)
+            {
+                Some(definition) => {
+                    // The generic definitions require type arguments.
+                    if let Some((type_parameters, _)) =
+                        definition.node.get_parameterized()
+                    {
+                        return Err(Error::new_expect_arguments(
+                            data_type.get_position(),
+                            type_parameters.len(),
+                            0,
+                        ));
+                    }
+
+                    definition.node
+                }
+                None => {
+                    return Err(Error::new_unknown_identifier(
+                        data_type.get_position(),
+                        name,
                     ))
-                } else {
-                    Ok(right_type)
                 }
             }
+        }
+        DataTypes::Option(value_type) => DataTypes::Option(Box::new(
+            resolve_data_type(&value_type, environment)?,
+        )),
+        DataTypes::Spread(value_type) => DataTypes::Spread(Box::new(
+            resolve_data_type(&value_type, environment)?,
+        )),
+        DataTypes::Tuple(values) => {
+            let mut values_types: Vec<DataType> = Vec::new();
 
-            Token::Minus
-            | Token::Star
-            | Token::Slash
-            | Token::DoubleStar
+            for value in values.iter() {
+                values_types.push(resolve_data_type(value, environment)?);
+            }
+
+            DataTypes::Tuple(values_types)
+        }
+        DataTypes::Union(values) => {
+            let mut values_types: Vec<DataType> = Vec::new();
+
+            for value in values.iter() {
+                values_types.push(resolve_data_type(value, environment)?);
+            }
+
+            DataTypes::Union(values_types)
+        }
+        node => node,
+    };
+
+    Ok(DataType::new(data_type.get_position(), node))
+}
+
+/// Check a destructuring pattern against the data type of its value and add
+/// the pattern names to the store.
+fn check_pattern(
+    pattern: &Pattern,
+    data_type: &DataType,
+    environment: &mut Environment,
+) -> Result<(), Error> {
+    // Array
+    if let Some(values) = pattern.node.get_array() {
+        let item_type = data_type.node.get_array().ok_or_else(|| {
+            Error::new_expect_type(
+                pattern.get_position(),
+                "array",
+                &data_type.node.to_string(),
+            )
+        })?;
+
+        for (index, value) in values.iter().enumerate() {
+            if let Some(rest_name) = value.node.get_rest() {
+                if index + 1 != values.len() {
+                    return Err(Error::new_semantic(
+                        value.get_position(),
+                        "The rest pattern must be the last pattern.",
+                    ));
+                }
+
+                check_pattern(
+                    &Pattern::new(
+                        value.get_position(),
+                        Patterns::Identifier(rest_name),
+                    ),
+                    &DataType::new(
+                        value.get_position(),
+                        DataTypes::Array(item_type.clone()),
+                    ),
+                    environment,
+                )?;
+
+                continue;
+            }
+
+            check_pattern(value, &item_type, environment)?;
+        }
+
+        return Ok(());
+    }
+    // Enum variant
+    else if let Some((variant_name, values)) = pattern.node.get_enum_variant()
+    {
+        let (enum_name, variants) = get_enum_variants(data_type, environment)
+            .ok_or_else(|| {
+            Error::new_expect_type(
+                pattern.get_position(),
+                "enum",
+                &data_type.node.to_string(),
+            )
+        })?;
+
+        let arguments = variants
+            .iter()
+            .find(|(variant, _)| variant == &variant_name)
+            .map(|(_, arguments)| arguments.clone())
+            .ok_or_else(|| {
+                Error::new_unknown_variant(
+                    pattern.get_position(),
+                    enum_name,
+                    variant_name,
+                )
+            })?;
+
+        if arguments.len() != values.len() {
+            return Err(Error::new_expect_arguments(
+                pattern.get_position(),
+                arguments.len(),
+                values.len(),
+            ));
+        }
+
+        for (value, argument) in values.iter().zip(arguments.iter()) {
+            check_pattern(value, &get_argument_type(argument), environment)?;
+        }
+
+        return Ok(());
+    }
+    // HashMap
+    else if let Some(data) = pattern.node.get_hashmap() {
+        let properties = data_type.node.get_hashmap().ok_or_else(|| {
+            Error::new_expect_type(
+                pattern.get_position(),
+                "hashmap",
+                &data_type.node.to_string(),
+            )
+        })?;
+
+        for (key, value) in data.iter() {
+            match properties.get(key) {
+                Some(property_type) => {
+                    check_pattern(value, property_type, environment)?
+                }
+                None => {
+                    return Err(Error::new_unknown_property(
+                        value.get_position(),
+                        key.clone(),
+                    ))
+                }
+            }
+        }
+
+        return Ok(());
+    }
+    // Identifier
+    else if let Some(name) = pattern.node.get_identifier() {
+        // The name of a variant without values matches that variant.
+        if let Some((_, variants)) = get_enum_variants(data_type, environment) {
+            if let Some((_, arguments)) =
+                variants.iter().find(|(variant, _)| variant == &name)
+            {
+                if !arguments.is_empty() {
+                    return Err(Error::new_expect_arguments(
+                        pattern.get_position(),
+                        arguments.len(),
+                        0,
+                    ));
+                }
+
+                return Ok(());
+            }
+        }
+
+        // Check if the name is already in use.
+        if environment.get_store().is_type_in_use(&name) {
+            return Err(Error::new_name_in_use(
+                pattern.get_position(),
+                name.clone(),
+                environment
+                    .get_store()
+                    .get_data_type_with_outer(&name)
+                    .map(|last| last.get_position()),
+            ));
+        }
+
+        environment.get_store().add_data_type(
+            &name,
+            &DataType::new(pattern.get_position(), data_type.node.clone()),
+        );
+
+        return Ok(());
+    }
+    // Tuple
+    else if let Some(values) = pattern.node.get_tuple() {
+        let values_types = data_type
+            .node
+            .get_tuple()
+            .filter(|values_types| values_types.len() == values.len())
+            .ok_or_else(|| {
+                Error::new_expect_type(
+                    pattern.get_position(),
+                    &format!("tuple of {} values", values.len()),
+                    &data_type.node.to_string(),
+                )
+            })?;
+
+        for (value, value_type) in values.iter().zip(values_types.iter()) {
+            check_pattern(value, value_type, environment)?;
+        }
+
+        return Ok(());
+    }
+
+    Err(Error::new_unknown_token(pattern.get_position()))
+}
+
+/// Get the value of an assignment, using the infix operator of a compound
+/// assignment sign (like `+=`).
+pub(crate) fn get_assignment_value(
+    target: &Expression,
+    sign: Token,
+    value: &Expression,
+) -> Expression {
+    let operator = match sign {
+        Token::PlusEqual => Token::Plus,
+        Token::MinusEqual => Token::Minus,
+        Token::StarEqual => Token::Star,
+        Token::SlashEqual => Token::Slash,
+        Token::PercentEqual => Token::Percent,
+        Token::DoubleStarEqual => Token::DoubleStar,
+        _ => return value.clone(),
+    };
+
+    Expression::new(
+        value.get_position(),
+        Expressions::Infix {
+            left: Box::new(target.clone()),
+            operator,
+            right: Box::new(value.clone()),
+        },
+    )
+}
+
+/// Get a member of the class and check if it can be accessed from the
+/// environment.
+fn get_member(
+    class_name: &String,
+    member_name: &String,
+    position: Position,
+    environment: &mut Environment,
+) -> Result<Member, Error> {
+    let member = environment
+        .get_store()
+        .get_member_with_outer(class_name, member_name)
+        .ok_or_else(|| {
+            Error::new_unknown_property(position.clone(), member_name.clone())
+        })?;
+
+    // The private members can only be accessed inside the class.
+    let is_inside_class = match environment
+        .get_store()
+        .get_type_definition_with_outer(&String::from("Self"))
+    {
+        Some(self_type) => {
+            self_type.node.get_instance().as_ref() == Some(class_name)
+        }
+        None => false,
+    };
+
+    if !member.is_public && !is_inside_class {
+        return Err(Error::new_private_member(
+            position,
+            class_name.clone(),
+            member_name.clone(),
+        ));
+    }
+
+    Ok(member)
+}
+
+/// Check if the statement assigns a value to the field of `self`.
+fn is_field_assignment(statement: &Statement, field_name: &String) -> bool {
+    let assignment = statement
+        .node
+        .get_expression()
+        .and_then(|expression| expression.node.get_assignment());
+
+    if let Some((target, _, _)) = assignment {
+        if let Some((instance, field)) = target.node.get_method() {
+            return instance.node.get_identifier()
+                == Some(String::from("self"))
+                && field.node.get_identifier().as_ref() == Some(field_name);
+        }
+    }
+
+    false
+}
+
+/// Get the error of a value that cannot be assigned to the data type, listing
+/// the allowed literals when the data type only accepts literals.
+fn new_assignment_error(
+    position: Position,
+    data_type: &DataType,
+    value_type: &DataType,
+) -> Error {
+    let expected_type = match data_type.node.get_argument() {
+        Some((_, argument_type, _)) => *argument_type,
+        None => data_type.clone(),
+    };
+
+    if expected_type.node.is_literal() {
+        return Error::new_expect_literal(
+            position,
+            expected_type
+                .node
+                .get_union()
+                .unwrap_or_else(|| vec![expected_type.clone()])
+                .iter()
+                .map(|literal| literal.to_string())
+                .collect(),
+            &value_type.node.to_string(),
+        );
+    }
+
+    Error::new_expect_type(
+        position,
+        &data_type.node.to_string(),
+        &value_type.node.to_string(),
+    )
+}
+
+fn check_argument_value(
+    value: &Expression,
+    data_type: DataType,
+    type_arguments: &mut HashMap<String, Option<DataType>>,
+    environment: &mut Environment,
+) -> Result<(), Error> {
+    let value_type = check_expression(value, environment)?;
+
+    infer_type_arguments(&data_type, &value_type, type_arguments);
+
+    let data_type = substitute_type_arguments(&data_type, type_arguments);
+
+    if !data_type.node.is_assignable(&value_type.node) {
+        return Err(new_assignment_error(
+            value.get_position(),
+            &data_type,
+            &value_type,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Check the call arguments (positional, named and spread) against the
+/// function arguments data types, inferring the unknown type arguments.
+fn check_arguments(
+    position: Position,
+    function_arguments: Vec<DataType>,
+    arguments: Vec<Expression>,
+    type_arguments: &mut HashMap<String, Option<DataType>>,
+    environment: &mut Environment,
+) -> Result<(), Error> {
+    let rest_index = function_arguments.iter().position(|function_argument| {
+        function_argument.node.get_spread().is_some()
+    });
+    let mut provided: Vec<Option<Position>> =
+        vec![None; function_arguments.len()];
+    let mut index: usize = 0;
+    let mut has_named_arguments = false;
+
+    for argument in arguments.iter() {
+        if let Some((argument_name, value)) = argument.node.get_named_argument()
+        {
+            has_named_arguments = true;
+
+            let argument_index = function_arguments
+                .iter()
+                .position(|function_argument| {
+                    get_argument_name(function_argument).as_ref()
+                        == Some(&argument_name)
+                })
+                .ok_or_else(|| {
+                    Error::new_unknown_argument(
+                        argument.get_position(),
+                        argument_name.clone(),
+                    )
+                })?;
+
+            if let Some(last_position) = provided[argument_index].clone() {
+                return Err(Error::new_duplicate_argument(
+                    argument.get_position(),
+                    argument_name,
+                    last_position,
+                ));
+            }
+
+            provided[argument_index] = Some(argument.get_position());
+
+            check_argument_value(
+                &value,
+                get_argument_type(&function_arguments[argument_index]),
+                type_arguments,
+                environment,
+            )?;
+
+            continue;
+        }
+
+        if has_named_arguments {
+            return Err(Error::new_invalid_argument(
+                argument.get_position(),
+                "Positional arguments cannot follow named arguments.",
+            ));
+        }
+
+        // Check if the argument is part of the rest argument.
+        if let Some(rest_index) =
+            rest_index.filter(|rest_index| index >= *rest_index)
+        {
+            if provided[rest_index].is_none() {
+                provided[rest_index] = Some(argument.get_position());
+            }
+
+            let rest_type = get_argument_type(&function_arguments[rest_index]);
+
+            if let Some(spread_value) = argument.node.get_spread() {
+                check_argument_value(
+                    &spread_value,
+                    rest_type,
+                    type_arguments,
+                    environment,
+                )?;
+            } else {
+                check_argument_value(
+                    argument,
+                    match rest_type.node.get_array() {
+                        Some(item_type) => *item_type,
+                        None => rest_type,
+                    },
+                    type_arguments,
+                    environment,
+                )?;
+            }
+
+            continue;
+        }
+
+        if argument.node.get_spread().is_some() {
+            return Err(Error::new_invalid_argument(
+                argument.get_position(),
+                "Only a rest argument can receive a spread argument.",
+            ));
+        }
+
+        if index >= function_arguments.len() {
+            return Err(Error::new_expect_arguments(
+                position,
+                function_arguments.len(),
+                arguments.len(),
+            ));
+        }
+
+        provided[index] = Some(argument.get_position());
+
+        check_argument_value(
+            argument,
+            get_argument_type(&function_arguments[index]),
+            type_arguments,
+            environment,
+        )?;
+
+        index += 1;
+    }
+
+    for (argument_index, function_argument) in
+        function_arguments.iter().enumerate()
+    {
+        if provided[argument_index].is_some()
+            || rest_index == Some(argument_index)
+        {
+            continue;
+        }
+
+        match function_argument.node.get_argument() {
+            Some((name, _, is_optional)) => {
+                if !is_optional {
+                    return Err(Error::new_missing_argument(position, name));
+                }
+            }
+            None => {
+                return Err(Error::new_expect_arguments(
+                    position,
+                    function_arguments.len(),
+                    arguments.len(),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the arguments of a function call and get the return data type.
+fn check_call(
+    position: Position,
+    function_type: DataType,
+    arguments: Vec<Expression>,
+    environment: &mut Environment,
+) -> Result<DataType, Error> {
+    let (type_parameters, function_type) =
+        match function_type.node.get_parameterized() {
+            Some((type_parameters, data_type)) => (type_parameters, *data_type),
+            None => (Vec::new(), function_type),
+        };
+
+    if let Some((function_arguments, return_type)) =
+        function_type.node.get_function()
+    {
+        let mut type_arguments: HashMap<String, Option<DataType>> =
+            type_parameters
+                .iter()
+                .filter_map(|type_parameter| {
+                    type_parameter.node.get_parameter()
+                })
+                .map(|name| (name, None))
+                .collect();
+
+        check_arguments(
+            position.clone(),
+            function_arguments,
+            arguments,
+            &mut type_arguments,
+            environment,
+        )?;
+
+        // Check if every type argument was inferred and satisfies
+        // its constraint.
+        for type_parameter in type_parameters.iter() {
+            let name = type_parameter.node.get_parameter().unwrap();
+
+            let type_argument = match type_arguments.get(&name) {
+                Some(Some(type_argument)) => type_argument.clone(),
+                _ => {
+                    return Err(Error::new_cannot_infer(position.clone(), name))
+                }
+            };
+
+            if let Some(constraint) =
+                type_parameter.node.get_parameter_constraint()
+            {
+                let constraint =
+                    substitute_type_arguments(&constraint, &type_arguments);
+
+                if !satisfies_constraint(&constraint, &type_argument) {
+                    return Err(Error::new_unsatisfied_constraint(
+                        position.clone(),
+                        name,
+                        &constraint.node.to_string(),
+                        &type_argument.node.to_string(),
+                    ));
+                }
+            }
+        }
+
+        return Ok(DataType::new(
+            position,
+            substitute_type_arguments(&return_type, &type_arguments).node,
+        ));
+    }
+
+    Ok(function_type)
+}
+
+/// Check the arguments of a `format` call, validating the placeholders of a
+/// literal template against the positional and named values.
+fn check_format(
+    position: Position,
+    arguments: Vec<Expression>,
+    environment: &mut Environment,
+) -> Result<DataType, Error> {
+    let template = arguments
+        .first()
+        .filter(|argument| {
+            argument.node.get_named_argument().is_none()
+                && argument.node.get_spread().is_none()
+        })
+        .ok_or_else(|| {
+            Error::new_missing_argument(
+                position.clone(),
+                String::from("template"),
+            )
+        })?;
+    let template_type = check_expression(template, environment)?;
+    let string_type = DataType::new(position.clone(), DataTypes::String);
+
+    if !string_type.node.is_assignable(&template_type.node) {
+        return Err(new_assignment_error(
+            template.get_position(),
+            &string_type,
+            &template_type,
+        ));
+    }
+
+    let mut values: usize = 0;
+    let mut has_spread = false;
+    let mut names: Vec<(String, Position)> = Vec::new();
+
+    for argument in arguments.iter().skip(1) {
+        if let Some((name, value)) = argument.node.get_named_argument() {
+            if let Some((_, last_position)) =
+                names.iter().find(|(last_name, _)| last_name == &name)
+            {
+                return Err(Error::new_duplicate_argument(
+                    argument.get_position(),
+                    name,
+                    last_position.clone(),
+                ));
+            }
+
+            check_expression(&value, environment)?;
+
+            names.push((name, argument.get_position()));
+            continue;
+        }
+
+        if !names.is_empty() {
+            return Err(Error::new_invalid_argument(
+                argument.get_position(),
+                "Positional arguments cannot follow named arguments.",
+            ));
+        }
+
+        if let Some(spread_value) = argument.node.get_spread() {
+            let spread_type = check_expression(&spread_value, environment)?;
+
+            if spread_type.node.get_array().is_none() {
+                return Err(Error::new_expect_type(
+                    spread_value.get_position(),
+                    "array",
+                    &spread_type.node.to_string(),
+                ));
+            }
+
+            has_spread = true;
+            continue;
+        }
+
+        check_expression(argument, environment)?;
+
+        values += 1;
+    }
+
+    // The placeholders can only be validated when the template is a literal.
+    let template_value = match template.node.get_string() {
+        Some(template_value) => template_value,
+        None => return Ok(string_type),
+    };
+    let segments = builtins::format::parse_template(&template_value).map_err(
+        |message| Error::new_semantic(template.get_position(), &message),
+    )?;
+    let expected_values = builtins::format::get_expected_values(&segments);
+    let mut used_names: Vec<String> = Vec::new();
+
+    for segment in segments.iter() {
+        if let builtins::format::Segment::Placeholder(
+            builtins::format::Placeholder {
+                argument: builtins::format::Argument::Name(name),
+                ..
+            },
+        ) = segment
+        {
+            if !names.iter().any(|(value_name, _)| value_name == name) {
+                return Err(Error::new_missing_argument(
+                    template.get_position(),
+                    name.clone(),
+                ));
+            }
+
+            used_names.push(name.clone());
+        }
+    }
+
+    if !has_spread && expected_values != values {
+        return Err(Error::new_expect_arguments(
+            template.get_position(),
+            expected_values,
+            values,
+        ));
+    }
+
+    if let Some((name, name_position)) = names
+        .into_iter()
+        .find(|(name, _)| !used_names.contains(name))
+    {
+        return Err(Error::new_unknown_argument(name_position, name));
+    }
+
+    Ok(string_type)
+}
+
+pub fn check_expression(
+    expression: &Expression,
+    environment: &mut Environment,
+) -> Result<DataType, Error> {
+    // Argument
+    if let Some((argument_name, argument_type, argument_value)) =
+        expression.node.get_argument()
+    {
+        // Check if the name is already in use.
+        if environment.get_store().is_type_in_use(&argument_name) {
+            return Err(Error::new_name_in_use(
+                expression.get_position(),
+                argument_name.clone(),
+                environment
+                    .get_store()
+                    .get_data_type_with_outer(&argument_name)
+                    .map(|last| last.get_position()),
+            ));
+        }
+
+        let type_name =
+            environment.get_store().get_data_type_name(&argument_type);
+        let argument_type =
+            Box::new(resolve_data_type(&argument_type, environment)?);
+
+        if let Some(argument_value) = argument_value.clone() {
+            let value_type = check_expression(&argument_value, environment)?;
+
+            if !argument_type.node.is_assignable(&value_type.node) {
+                return Err(new_assignment_error(
+                    argument_value.get_position(),
+                    &argument_type,
+                    &value_type,
+                ));
+            }
+        }
+
+        environment
+            .get_store()
+            .add_data_type(&argument_name, &argument_type);
+        environment
+            .get_store()
+            .add_type_name(&argument_name, type_name);
+
+        return Ok(DataType::new(
+            argument_type.get_position(),
+            DataTypes::Argument {
+                name: argument_name,
+                data_type: argument_type,
+                is_optional: argument_value.is_some(),
+            },
+        ));
+    }
+    // Array
+    else if let Some(values) = expression.node.get_array() {
+        let mut data_type =
+            DataType::new(expression.get_position(), DataTypes::Unknown);
+
+        for (index, value) in values.iter().enumerate() {
+            let value_type = check_expression(value, environment)?;
+
+            data_type = if index == 0 {
+                value_type
+            } else {
+                unify_data_types(&data_type, &value_type)
+            };
+        }
+
+        return Ok(DataType::new(
+            expression.get_position(),
+            DataTypes::Array(Box::new(data_type)),
+        ));
+    }
+    // Assignment
+    else if let Some((target, sign, value)) = expression.node.get_assignment()
+    {
+        let new_target_error = || {
+            Error::new_semantic(
+                target.get_position(),
+                "Only the class fields can be assigned.",
+            )
+        };
+
+        let (instance_exp, field_exp) =
+            target.node.get_method().ok_or_else(new_target_error)?;
+
+        let instance_type = check_expression(&instance_exp, environment)?;
+
+        let class_name = instance_type
+            .node
+            .get_instance()
+            .ok_or_else(new_target_error)?;
+
+        let field_name = field_exp
+            .node
+            .get_identifier()
+            .ok_or_else(new_target_error)?;
+
+        let member = get_member(
+            &class_name,
+            &field_name,
+            field_exp.get_position(),
+            environment,
+        )?;
+
+        if member.is_method {
+            return Err(new_target_error());
+        }
+
+        let value_type = check_expression(
+            &get_assignment_value(&target, sign, &value),
+            environment,
+        )?;
+
+        if !member.data_type.node.is_assignable(&value_type.node) {
+            return Err(new_assignment_error(
+                value.get_position(),
+                &member.data_type,
+                &value_type,
+            ));
+        }
+
+        return Ok(DataType::new(expression.get_position(), DataTypes::Void));
+    }
+    // Boolean
+    else if expression.node.get_boolean().is_some() {
+        return Ok(DataType::new(
+            expression.get_position(),
+            DataTypes::Boolean,
+        ));
+    }
+    // Call
+    else if let Some((identifier, arguments)) = expression.node.get_call() {
+        if let Some(identifier_name) = identifier.node.get_identifier() {
+            if !environment.get_store().has_key_type(&identifier_name) {
+                return Err(Error::new_unknown_identifier(
+                    identifier.get_position(),
+                    identifier_name,
+                ));
+            }
+
+            if identifier_name == "format" {
+                return check_format(
+                    expression.get_position(),
+                    arguments,
+                    environment,
+                );
+            }
+
+            let function_type =
+                if environment.get_store().is_builtin(&identifier_name) {
+                    builtins::get_builtin_type(
+                        identifier_name,
+                        identifier.get_position(),
+                    )?
+                } else {
+                    environment
+                        .get_store()
+                        .get_data_type_with_outer(&identifier_name)
+                        .unwrap()
+                };
+
+            return check_call(
+                expression.get_position(),
+                function_type,
+                arguments,
+                environment,
+            );
+        } else {
+            return Err(Error::new_unknown_token(expression.get_position()));
+        }
+    }
+    // HashMap
+    else if let Some(data) = expression.node.get_hashmap() {
+        let mut data_types: OrderedMap<Box<DataType>> = OrderedMap::new();
+
+        for (key, value) in data.iter() {
+            data_types.insert(
+                key.clone(),
+                Box::new(check_expression(value, environment)?),
+            );
+        }
+
+        return Ok(DataType::new(
+            expression.get_position(),
+            DataTypes::HashMap(data_types),
+        ));
+    }
+    // Identifier
+    else if let Some(identifier_name) = expression.node.get_identifier() {
+        if !environment.get_store().has_key_type(&identifier_name) {
+            return Err(Error::new_unknown_identifier(
+                expression.get_position(),
+                identifier_name,
+            ));
+        }
+
+        return Ok(environment
+            .get_store()
+            .get_data_type_with_outer(&identifier_name)
+            .unwrap());
+    }
+    // If
+    else if let Some((condition, consequence, alternative)) =
+        expression.node.get_if()
+    {
+        let condition_type = check_expression(&condition, environment)?;
+
+        if !condition_type.node.is_boolean() {
+            return Err(Error::new_expect_type(
+                condition.get_position(),
+                "boolean",
+                &condition_type.node.to_string(),
+            ));
+        }
+
+        let narrowed_types = get_narrowed_types(&condition, environment);
+
+        let consequence_type = match narrowed_types.clone() {
+            Some((name, data_type, _)) => check_body(
+                consequence,
+                &mut get_narrowed_environment(&name, &data_type, environment),
+            )?,
+            None => check_body(consequence, environment)?,
+        };
+
+        if alternative.len() > 0 {
+            let alternative_type = match narrowed_types {
+                Some((name, _, data_type)) => check_body(
+                    alternative,
+                    &mut get_narrowed_environment(
+                        &name,
+                        &data_type,
+                        environment,
+                    ),
+                )?,
+                None => check_body(alternative, environment)?,
+            };
+
+            // A branch without returns takes the data type of the other one.
+            if consequence_type.node.is_void() {
+                return Ok(alternative_type);
+            } else if alternative_type.node.is_void() {
+                return Ok(consequence_type);
+            }
+
+            return Ok(unify_data_types(&consequence_type, &alternative_type));
+        }
+
+        return Ok(consequence_type);
+    }
+    // Try
+    else if let Some((body, error_name, handler)) = expression.node.get_try()
+    {
+        // The body has its own scope like the handler.
+        let mut body_environment = environment.clone();
+
+        body_environment.set_store(&Store::from_outer(environment.get_store()));
+
+        let body_type = check_body(body, &mut body_environment)?;
+
+        // The handler receives the message of the error.
+        let handler_type = check_body(
+            handler,
+            &mut get_narrowed_environment(
+                &error_name,
+                &DataType::new(expression.get_position(), DataTypes::String),
+                environment,
+            ),
+        )?;
+
+        // A block without returns takes the data type of the other one.
+        if body_type.node.is_void() {
+            return Ok(handler_type);
+        } else if handler_type.node.is_void() {
+            return Ok(body_type);
+        }
+
+        return Ok(unify_data_types(&body_type, &handler_type));
+    }
+    // Infix
+    else if let Some((left_exp, operator, right_exp)) =
+        expression.node.get_infix()
+    {
+        // Check if the infix is a pipeline.
+        if operator == Token::VBarGreater {
+            if let Some(call_exp) = expression.get_pipeline_call() {
+                return check_expression(&call_exp, environment);
+            }
+
+            let right_type = check_expression(&right_exp, environment)?;
+
+            // The values are only passed to the named functions.
+            if right_type.node.get_function().is_some() {
+                return Err(Error::new_semantic(
+                    right_exp.get_position(),
+                    "The pipeline target must be a function name, a call or \
+                     a method.",
+                ));
+            }
+
+            return Err(Error::new_expect_type(
+                right_exp.get_position(),
+                "function",
+                &right_type.node.to_string(),
+            ));
+        }
+
+        // Check expression:
+        // Get the data type of the left expression.
+        let left_type = check_expression(&left_exp, environment)?;
+
+        // Check expression:
+        // Get the data type of the right expression.
+        let right_type = check_expression(&right_exp, environment)?;
+
+        // Only the equality operators compare the literals.
+        let (left_type, right_type) = if operator == Token::DoubleEqual
+            || operator == Token::NotEqual
+        {
+            (left_type, right_type)
+        } else {
+            (
+                DataType::new(left_type.get_position(), left_type.node.widen()),
+                DataType::new(
+                    right_type.get_position(),
+                    right_type.node.widen(),
+                ),
+            )
+        };
+
+        return match operator {
+            Token::Plus => {
+                if !left_type.node.is_string() && !left_type.node.is_number() {
+                    Err(Error::new_expect_type(
+                        left_exp.get_position(),
+                        "string or number",
+                        &left_type.node.to_string(),
+                    ))
+                } else if left_type.node != right_type.node {
+                    Err(Error::new_expect_type(
+                        right_exp.get_position(),
+                        &left_type.node.to_string(),
+                        &right_type.node.to_string(),
+                    ))
+                } else {
+                    Ok(right_type)
+                }
+            }
+
+            Token::Minus
+            | Token::Star
+            | Token::Slash
+            | Token::DoubleStar
             | Token::Percent
             | Token::Less
             | Token::LessEqual
@@ -233,76 +1749,931 @@ pub fn check_expression(
                 } else if left_type.node != right_type.node {
                     Err(Error::new_expect_type(
                         right_exp.get_position(),
-                        &left_type.node.to_string(),
-                        &right_type.node.to_string(),
-                    ))
-                } else if [
-                    Token::Less,
-                    Token::LessEqual,
-                    Token::Greater,
-                    Token::GreaterEqual,
-                ]
-                .contains(&operator)
+                        &left_type.node.to_string(),
+                        &right_type.node.to_string(),
+                    ))
+                } else if [
+                    Token::Less,
+                    Token::LessEqual,
+                    Token::Greater,
+                    Token::GreaterEqual,
+                ]
+                .contains(&operator)
+                {
+                    Ok(DataType::new(
+                        expression.get_position(),
+                        DataTypes::Boolean,
+                    ))
+                } else {
+                    Ok(right_type)
+                }
+            }
+
+            Token::DoubleEqual | Token::NotEqual => {
+                if !left_type.node.is_assignable(&right_type.node)
+                    && !right_type.node.is_assignable(&left_type.node)
+                {
+                    Err(Error::new_expect_type(
+                        right_exp.get_position(),
+                        &left_type.node.to_string(),
+                        &right_type.node.to_string(),
+                    ))
+                } else {
+                    Ok(DataType::new(
+                        expression.get_position(),
+                        DataTypes::Boolean,
+                    ))
+                }
+            }
+
+            _ => Err(Error::new_unknown_token(expression.get_position())),
+        };
+    }
+    // Match
+    else if let Some((value, arms)) = expression.node.get_match() {
+        let value_type = check_expression(&value, environment)?;
+
+        let (enum_name, variants) = get_enum_variants(&value_type, environment)
+            .ok_or_else(|| {
+                Error::new_expect_type(
+                    value.get_position(),
+                    "enum",
+                    &value_type.node.to_string(),
+                )
+            })?;
+
+        let mut match_type: Option<DataType> = None;
+
+        for (arm_pattern, arm_value) in arms.iter() {
+            let mut arm_environment = environment.clone();
+
+            arm_environment
+                .set_store(&Store::from_outer(environment.get_store()));
+
+            check_pattern(arm_pattern, &value_type, &mut arm_environment)?;
+
+            let arm_type = check_expression(arm_value, &mut arm_environment)?;
+
+            match_type = Some(match match_type {
+                Some(match_type) => unify_data_types(&match_type, &arm_type),
+                None => arm_type,
+            });
+        }
+
+        let rows: Vec<Vec<Pattern>> = arms
+            .iter()
+            .map(|(arm_pattern, _)| vec![arm_pattern.clone()])
+            .collect();
+        let mut missing_variants: Vec<String> = Vec::new();
+
+        for (variant_name, arguments) in variants.iter() {
+            let variant_rows = specialize_rows(
+                &rows,
+                variant_name,
+                arguments.len(),
+                &value_type,
+                environment,
+            );
+            let variant_types: Vec<DataType> =
+                arguments.iter().map(get_argument_type).collect();
+
+            if !is_exhaustive(&variant_rows, &variant_types, environment) {
+                missing_variants.push(variant_name.clone());
+            }
+        }
+
+        if !missing_variants.is_empty() {
+            return Err(Error::new_missing_variants(
+                expression.get_position(),
+                enum_name,
+                missing_variants,
+            ));
+        }
+
+        return Ok(DataType::new(
+            expression.get_position(),
+            match match_type {
+                Some(match_type) => match_type.node,
+                None => DataTypes::Void,
+            },
+        ));
+    }
+    // Method
+    else if let Some((left_exp, right_exp)) = expression.node.get_method() {
+        let left_type: DataType = check_expression(&left_exp, environment)?;
+        let left_type = DataType::new(
+            left_type.get_position(),
+            // Use the constraint of a type parameter.
+            match left_type.node.get_parameter_constraint() {
+                Some(constraint) => constraint.node.widen(),
+                None => left_type.node.widen(),
+            },
+        );
+
+        // Call a method implemented for the left data type.
+        if let Some((method_name, arguments)) = right_exp.node.get_call() {
+            if let Some(method_name) = method_name.node.get_identifier() {
+                let receiver_name =
+                    environment.get_store().get_expression_type_name(&left_exp);
+
+                if let Some(method_type) =
+                    environment.get_store().get_method_with_outer(
+                        receiver_name.as_ref(),
+                        &left_type,
+                        &method_name,
+                    )
+                {
+                    return check_call(
+                        expression.get_position(),
+                        method_type,
+                        arguments,
+                        environment,
+                    );
+                }
+
+                // The interface methods cannot be called before they are
+                // implemented.
+                if environment
+                    .get_store()
+                    .get_signature_with_outer(
+                        receiver_name.as_ref(),
+                        &left_type,
+                        &method_name,
+                    )
+                    .is_some()
+                {
+                    return Err(Error::new_semantic(
+                        right_exp.get_position(),
+                        &format!(
+                            "The `{}` method of `{}` is not implemented.",
+                            method_name,
+                            receiver_name
+                                .unwrap_or_else(|| left_type.node.to_string())
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // Call a builtin method of the left data type.
+        if let Some((method_name, arguments)) = right_exp.node.get_call() {
+            if let Some((_, method_type)) =
+                method_name.node.get_identifier().and_then(|method_name| {
+                    builtins::get_method(
+                        &left_type,
+                        &method_name,
+                        right_exp.get_position(),
+                    )
+                })
+            {
+                return check_call(
+                    expression.get_position(),
+                    method_type,
+                    arguments,
+                    environment,
+                );
+            }
+        }
+
+        // Get a member of a class instance.
+        if let Some(class_name) = left_type.node.get_instance() {
+            let (member_exp, arguments) = match right_exp.node.get_call() {
+                Some((member_exp, arguments)) => (*member_exp, Some(arguments)),
+                None => (*right_exp.clone(), None),
+            };
+
+            let member_name =
+                member_exp.node.get_identifier().ok_or_else(|| {
+                    Error::new_unknown_token(member_exp.get_position())
+                })?;
+
+            let member = get_member(
+                &class_name,
+                &member_name,
+                member_exp.get_position(),
+                environment,
+            )?;
+
+            return match arguments {
+                Some(arguments) => check_call(
+                    expression.get_position(),
+                    member.data_type,
+                    arguments,
+                    environment,
+                ),
+                None => Ok(DataType::new(
+                    expression.get_position(),
+                    member.data_type.node,
+                )),
+            };
+        }
+
+        let method_store = Store::from_outer(environment.get_store());
+        let mut method_environment = environment.clone();
+
+        method_environment.set_store(&method_store);
+
+        if let Some(values_types) = left_type.node.get_tuple() {
+            // Get the tuple value by its index.
+            if let Some(index) = right_exp.node.get_number() {
+                return match values_types.get(index as usize) {
+                    Some(value_type) => Ok(DataType::new(
+                        expression.get_position(),
+                        value_type.node.clone(),
+                    )),
+                    None => Err(Error::new_unknown_property(
+                        right_exp.get_position(),
+                        index.to_string(),
+                    )),
+                };
+            }
+        } else if let Some(properties) = left_type.node.get_hashmap() {
+            // Check if the property exists.
+            if let Some(property_name) = right_exp.node.get_identifier() {
+                if !properties.contains_key(&property_name) {
+                    return Err(Error::new_unknown_property(
+                        right_exp.get_position(),
+                        property_name,
+                    ));
+                }
+            }
+
+            // Check the call of a function property (like `Math.sqrt`) with
+            // the arguments of the current scope.
+            if let Some((function_name, arguments)) = right_exp.node.get_call()
+            {
+                if let Some(function_type) = function_name
+                    .node
+                    .get_identifier()
+                    .and_then(|function_name| properties.get(&function_name))
                 {
+                    return check_call(
+                        expression.get_position(),
+                        *function_type.clone(),
+                        arguments,
+                        environment,
+                    );
+                }
+            }
+
+            for (key, value) in properties.iter() {
+                method_environment.get_store().add_data_type(key, value);
+            }
+        }
+
+        let right_type: DataType =
+            check_expression(&right_exp, &mut method_environment)?;
+
+        return Ok(right_type);
+    }
+    // Null
+    else if expression.node.is_null() {
+        return Ok(DataType::new(
+            expression.get_position(),
+            DataTypes::Option(Box::new(DataType::new(
+                expression.get_position(),
+                DataTypes::Unknown,
+            ))),
+        ));
+    }
+    // Number
+    else if let Some(value) = expression.node.get_number() {
+        return Ok(DataType::new(
+            expression.get_position(),
+            DataTypes::NumberLiteral(value),
+        ));
+    }
+    // Prefix
+    else if let Some((operator, value)) = expression.node.get_prefix() {
+        let value_type = check_expression(&value, environment)?;
+        let value_type =
+            DataType::new(value_type.get_position(), value_type.node.widen());
+
+        return match operator {
+            Token::Typeof => {
+                Ok(DataType::new(expression.get_position(), DataTypes::String))
+            }
+
+            Token::Not => {
+                if !value_type.node.is_boolean() {
+                    Err(Error::new_expect_type(
+                        value.get_position(),
+                        "boolean",
+                        &value_type.node.to_string(),
+                    ))
+                } else {
                     Ok(DataType::new(
                         expression.get_position(),
                         DataTypes::Boolean,
                     ))
+                }
+            }
+
+            Token::Minus => {
+                if !value_type.node.is_number() {
+                    Err(Error::new_expect_type(
+                        value.get_position(),
+                        "number",
+                        &value_type.node.to_string(),
+                    ))
                 } else {
-                    Ok(right_type)
+                    Ok(DataType::new(
+                        expression.get_position(),
+                        DataTypes::Number,
+                    ))
+                }
+            }
+
+            _ => Err(Error::new_unknown_token(expression.get_position())),
+        };
+    }
+    // Spread
+    else if let Some(value) = expression.node.get_spread() {
+        // Check if the spread is a rest argument.
+        if value.node.get_argument().is_some() {
+            return Ok(DataType::new(
+                expression.get_position(),
+                DataTypes::Spread(Box::new(check_expression(
+                    &value,
+                    environment,
+                )?)),
+            ));
+        }
+
+        return Err(Error::new_invalid_argument(
+            expression.get_position(),
+            "Cannot use a spread expression here.",
+        ));
+    }
+    // String
+    else if let Some(value) = expression.node.get_string() {
+        return Ok(DataType::new(
+            expression.get_position(),
+            DataTypes::StringLiteral(value),
+        ));
+    }
+    // Ternary
+    else if let Some((condition, consequence, alternative)) =
+        expression.node.get_ternary()
+    {
+        let condition_type = check_expression(&condition, environment)?;
+
+        if !condition_type.node.is_boolean() {
+            return Err(Error::new_expect_type(
+                condition.get_position(),
+                "boolean",
+                &condition_type.node.to_string(),
+            ));
+        }
+
+        let narrowed_types = get_narrowed_types(&condition, environment);
+
+        let (consequence_type, alternative_type) = match narrowed_types {
+            Some((name, consequence_type, alternative_type)) => (
+                check_expression(
+                    &consequence,
+                    &mut get_narrowed_environment(
+                        &name,
+                        &consequence_type,
+                        environment,
+                    ),
+                )?,
+                check_expression(
+                    &alternative,
+                    &mut get_narrowed_environment(
+                        &name,
+                        &alternative_type,
+                        environment,
+                    ),
+                )?,
+            ),
+            None => (
+                check_expression(&consequence, environment)?,
+                check_expression(&alternative, environment)?,
+            ),
+        };
+
+        return Ok(DataType::new(
+            expression.get_position(),
+            unify_data_types(&consequence_type, &alternative_type).node,
+        ));
+    }
+    // Tuple
+    else if let Some(values) = expression.node.get_tuple() {
+        let mut values_types: Vec<DataType> = Vec::new();
+
+        for value in values.iter() {
+            values_types.push(check_expression(value, environment)?);
+        }
+
+        return Ok(DataType::new(
+            expression.get_position(),
+            DataTypes::Tuple(values_types),
+        ));
+    }
+
+    Err(Error::new_expect_type(
+        expression.get_position(),
+        "Unknown Expression",
+        "Unknown",
+    ))
+}
+
+/// Get the data type of a method without its `self` argument.
+fn without_self(method_type: &DataType) -> DataType {
+    match method_type.node.get_function() {
+        Some((arguments, return_type)) => DataType::new(
+            method_type.get_position(),
+            DataTypes::Function(arguments[1..].to_vec(), return_type),
+        ),
+        None => method_type.clone(),
+    }
+}
+
+/// Check a method with a `self` first argument and get its name and data type
+/// without the `self` argument.
+fn check_method(
+    method: &Statement,
+    environment: &mut Environment,
+) -> Result<(String, DataType), Error> {
+    let (
+        method_name,
+        method_type_parameters,
+        method_arguments,
+        method_return_type,
+        method_body,
+    ) = method.node.get_function().unwrap();
+
+    let method_name = match method_name.node.get_identifier() {
+        Some(method_name) => method_name,
+        None => {
+            return Err(Error::new_unknown_token(method_name.get_position()))
+        }
+    };
+
+    // Check if the first argument is `self`.
+    let has_self = match method_arguments.first() {
+        Some(argument) => match argument.node.get_argument() {
+            Some((argument_name, _, _)) => argument_name == "self",
+            None => false,
+        },
+        None => false,
+    };
+
+    if !has_self {
+        return Err(Error::new_missing_argument(
+            method.get_position(),
+            String::from("self"),
+        ));
+    }
+
+    let method_type = check_function(
+        method.get_position(),
+        &method_name,
+        method_type_parameters,
+        method_arguments,
+        method_return_type,
+        method_body,
+        environment,
+    )?;
+
+    // Remove the `self` argument from the method data type.
+    Ok((
+        method_name,
+        match method_type.node.get_parameterized() {
+            Some((type_parameters, data_type)) => {
+                with_type_parameters(&type_parameters, without_self(&data_type))
+            }
+            None => without_self(&method_type),
+        },
+    ))
+}
+
+/// Check a function and get its data type.
+fn check_function(
+    position: Position,
+    identifier_name: &String,
+    function_type_parameters: Vec<DataType>,
+    function_arguments: Vec<Expression>,
+    function_return_type: Box<DataType>,
+    function_body: Vec<Statement>,
+    environment: &mut Environment,
+) -> Result<DataType, Error> {
+    // Create a new internal storage.
+    let store = Store::from_outer(environment.get_store());
+
+    // Create a new internal environment.
+    let mut function_environment = environment.clone();
+
+    function_environment.set_store(&store);
+
+    let function_type_parameters = add_type_parameters(
+        &function_type_parameters,
+        &mut function_environment,
+    )?;
+
+    // Get the function return data type without type definitions.
+    let function_return_type = Box::new(resolve_data_type(
+        &function_return_type,
+        &mut function_environment,
+    )?);
+
+    // Initialize the function arguments data types.
+    let mut arguments_types: Vec<DataType> = Vec::new();
+
+    for (index, arg) in function_arguments.iter().enumerate() {
+        // Check if the argument is a rest argument.
+        if let Some(rest_argument) = arg.node.get_spread() {
+            if index + 1 != function_arguments.len() {
+                return Err(Error::new_invalid_argument(
+                    arg.get_position(),
+                    "The rest argument must be the last argument.",
+                ));
+            }
+
+            let (_, rest_type, rest_value) =
+                rest_argument.node.get_argument().unwrap();
+
+            if let Some(rest_value) = rest_value {
+                return Err(Error::new_invalid_argument(
+                    rest_value.get_position(),
+                    "A rest argument cannot have a default value.",
+                ));
+            }
+
+            if rest_type.node.get_array().is_none() {
+                return Err(Error::new_expect_type(
+                    rest_type.get_position(),
+                    "array",
+                    &rest_type.node.to_string(),
+                ));
+            }
+        }
+
+        // Check expression:
+        // Append the argument data type to the function arguments data types list.
+        arguments_types.push(check_expression(arg, &mut function_environment)?);
+    }
+
+    let function_type: DataType = with_type_parameters(
+        &function_type_parameters,
+        DataType::new(
+            position.clone(),
+            DataTypes::Function(
+                arguments_types.clone(),
+                function_return_type.clone(),
+            ),
+        ),
+    );
+
+    function_environment
+        .get_store()
+        .add_data_type(identifier_name, &function_type);
+
+    let function_body_type =
+        check_body(function_body, &mut function_environment)?;
+
+    // Use the data type of the body when the function does not have
+    // a return data type.
+    if function_return_type.node.is_unknown() {
+        return Ok(with_type_parameters(
+            &function_type_parameters,
+            DataType::new(
+                position.clone(),
+                DataTypes::Function(
+                    arguments_types,
+                    Box::new(DataType::new(
+                        function_body_type.get_position(),
+                        function_body_type.node.widen(),
+                    )),
+                ),
+            ),
+        ));
+    } else if !function_return_type
+        .node
+        .is_assignable(&function_body_type.node)
+    {
+        return Err(new_assignment_error(
+            function_body_type.get_position(),
+            &function_return_type,
+            &function_body_type,
+        ));
+    }
+
+    Ok(function_type)
+}
+
+pub fn check_statement(
+    statement: &Statement,
+    environment: &mut Environment,
+) -> Result<DataType, Error> {
+    // Class
+    if let Some((class_name, fields, constructor, methods)) =
+        statement.node.get_class()
+    {
+        // Check if the name is already in use.
+        if let Some(definition) = environment
+            .get_store()
+            .get_type_definition_with_outer(&class_name)
+        {
+            return Err(Error::new_name_in_use(
+                statement.get_position(),
+                class_name,
+                Some(definition.get_position()),
+            ));
+        } else if environment.get_store().is_type_in_use(&class_name) {
+            return Err(Error::new_name_in_use(
+                statement.get_position(),
+                class_name.clone(),
+                environment
+                    .get_store()
+                    .get_data_type_with_outer(&class_name)
+                    .map(|last| last.get_position()),
+            ));
+        }
+
+        let class_type = DataType::new(
+            statement.get_position(),
+            DataTypes::Instance(class_name.clone()),
+        );
+
+        environment
+            .get_store()
+            .add_type_definition(&class_name, &class_type);
+
+        // Create an environment where `Self` is the class instance.
+        let mut class_environment = environment.clone();
+
+        class_environment
+            .set_store(&Store::from_outer(environment.get_store()));
+
+        class_environment
+            .get_store()
+            .add_type_definition(&String::from("Self"), &class_type);
+
+        // Initialize the fields without value list.
+        let mut uninitialized_fields: Vec<Expression> = Vec::new();
+
+        for (is_public, field) in fields.iter() {
+            let (field_name, field_type, field_value) =
+                field.node.get_argument().unwrap();
+
+            // Check if the name is already in use.
+            if let Some(member) = class_environment
+                .get_store()
+                .get_member_with_outer(&class_name, &field_name)
+            {
+                return Err(Error::new_name_in_use(
+                    field.get_position(),
+                    field_name,
+                    Some(member.data_type.get_position()),
+                ));
+            }
+
+            let field_type =
+                resolve_data_type(&field_type, &mut class_environment)?;
+
+            if let Some(field_value) = field_value {
+                let value_type =
+                    check_expression(&field_value, &mut class_environment)?;
+
+                if !field_type.node.is_assignable(&value_type.node) {
+                    return Err(new_assignment_error(
+                        field_value.get_position(),
+                        &field_type,
+                        &value_type,
+                    ));
                 }
+            } else {
+                uninitialized_fields.push(field.clone());
+            }
+
+            let member = Member {
+                is_method: false,
+                is_public: *is_public,
+                data_type: DataType::new(field.get_position(), field_type.node),
+            };
+
+            environment.get_store().add_member(
+                &class_name,
+                &field_name,
+                &member,
+            );
+
+            class_environment.get_store().add_member(
+                &class_name,
+                &field_name,
+                &member,
+            );
+        }
+
+        for (is_public, method) in methods.iter() {
+            let (method_name, method_type) =
+                check_method(method, &mut class_environment)?;
+
+            // Check if the name is already in use.
+            if let Some(member) = class_environment
+                .get_store()
+                .get_member_with_outer(&class_name, &method_name)
+            {
+                return Err(Error::new_name_in_use(
+                    method.get_position(),
+                    method_name,
+                    Some(member.data_type.get_position()),
+                ));
+            }
+
+            let member = Member {
+                is_method: true,
+                is_public: *is_public,
+                data_type: DataType::new(
+                    method.get_position(),
+                    method_type.node,
+                ),
+            };
+
+            environment.get_store().add_member(
+                &class_name,
+                &method_name,
+                &member,
+            );
+
+            // Allow the next methods to call this method.
+            class_environment.get_store().add_member(
+                &class_name,
+                &method_name,
+                &member,
+            );
+        }
+
+        let mut constructor_arguments: Vec<DataType> = Vec::new();
+        let mut constructor_body: Vec<Statement> = Vec::new();
+
+        if let Some(constructor) = constructor {
+            let (_, constructor_type) =
+                check_method(&constructor, &mut class_environment)?;
+
+            constructor_arguments = constructor_type
+                .node
+                .get_function()
+                .ok_or_else(|| {
+                    Error::new_semantic(
+                        constructor.get_position(),
+                        "A constructor cannot have type parameters.",
+                    )
+                })?
+                .0;
+
+            constructor_body = constructor.node.get_function().unwrap().4;
+        }
+
+        // Check if the fields without value are assigned in the constructor.
+        for field in uninitialized_fields.iter() {
+            let (field_name, _, _) = field.node.get_argument().unwrap();
+
+            if !constructor_body
+                .iter()
+                .any(|statement| is_field_assignment(statement, &field_name))
+            {
+                return Err(Error::new_uninitialized_field(
+                    field.get_position(),
+                    field_name,
+                ));
             }
+        }
+
+        // The class name is the constructor function.
+        environment.get_store().add_data_type(
+            &class_name,
+            &DataType::new(
+                statement.get_position(),
+                DataTypes::Function(
+                    constructor_arguments,
+                    Box::new(class_type),
+                ),
+            ),
+        );
+
+        return Ok(DataType::new(statement.get_position(), DataTypes::Void));
+    }
+    // Enum
+    else if let Some((enum_name, variants)) = statement.node.get_enum() {
+        // Check if the name is already in use.
+        if let Some(definition) = environment
+            .get_store()
+            .get_type_definition_with_outer(&enum_name)
+        {
+            return Err(Error::new_name_in_use(
+                statement.get_position(),
+                enum_name,
+                Some(definition.get_position()),
+            ));
+        } else if environment.get_store().is_type_in_use(&enum_name) {
+            return Err(Error::new_name_in_use(
+                statement.get_position(),
+                enum_name.clone(),
+                environment
+                    .get_store()
+                    .get_data_type_with_outer(&enum_name)
+                    .map(|last| last.get_position()),
+            ));
+        }
 
-            _ => Err(Error::new_unknown_token(expression.get_position())),
-        };
-    }
-    // Method
-    else if let Some((left_exp, right_exp)) = expression.node.get_method() {
-        let left_type: DataType = check_expression(&left_exp, environment)?;
+        // Add the enum without variants first to allow recursive enums.
+        environment.get_store().add_type_definition(
+            &enum_name,
+            &DataType::new(
+                statement.get_position(),
+                DataTypes::Enum(enum_name.clone(), Vec::new()),
+            ),
+        );
 
-        let method_store = Store::from_outer(environment.get_store());
-        let mut method_environment = environment.clone();
+        let mut variants_types: Vec<VariantType> = Vec::new();
 
-        method_environment.set_store(&method_store);
+        for (variant_name, arguments) in variants.iter() {
+            if let Some((_, last_arguments)) = variants_types
+                .iter()
+                .find(|(variant, _)| variant == variant_name)
+            {
+                return Err(Error::new_name_in_use(
+                    statement.get_position(),
+                    variant_name.clone(),
+                    Some(match last_arguments.first() {
+                        Some(argument) => argument.get_position(),
+                        None => statement.get_position(),
+                    }),
+                ));
+            }
 
-        if left_type.node.is_number() {
-            for (key, value) in builtins::numbers::get_hashmap_typechecker() {
-                method_environment.get_store().add_data_type(&key, &value);
+            let mut arguments_types: Vec<DataType> = Vec::new();
+
+            for argument in arguments.iter() {
+                let (argument_name, argument_type, _) =
+                    argument.node.get_argument().unwrap();
+
+                arguments_types.push(DataType::new(
+                    argument.get_position(),
+                    DataTypes::Argument {
+                        name: argument_name,
+                        data_type: Box::new(resolve_data_type(
+                            &argument_type,
+                            environment,
+                        )?),
+                        is_optional: false,
+                    },
+                ));
             }
+
+            variants_types.push((variant_name.clone(), arguments_types));
         }
 
-        let right_type: DataType =
-            check_expression(&right_exp, &mut method_environment)?;
+        let enum_type = DataType::new(
+            statement.get_position(),
+            DataTypes::Enum(enum_name.clone(), variants_types.clone()),
+        );
 
-        return Ok(right_type);
-    }
-    // Number
-    else if let Some(_) = expression.node.get_number() {
-        return Ok(DataType::new(expression.get_position(), DataTypes::Number));
-    }
-    // String
-    else if let Some(_) = expression.node.get_string() {
-        return Ok(DataType::new(expression.get_position(), DataTypes::String));
-    }
+        environment
+            .get_store()
+            .add_type_definition(&enum_name, &enum_type);
 
-    Err(Error::new_expect_type(
-        expression.get_position(),
-        "Unknown Expression",
-        "Unknown",
-    ))
-}
+        // The enum value contains the constructors of the variants.
+        let mut constructors: OrderedMap<Box<DataType>> = OrderedMap::new();
 
-pub fn check_statement(
-    statement: &Statement,
-    environment: &mut Environment,
-) -> Result<DataType, Error> {
+        for (variant_name, arguments) in variants_types.iter() {
+            constructors.insert(
+                variant_name.clone(),
+                Box::new(if arguments.is_empty() {
+                    enum_type.clone()
+                } else {
+                    DataType::new(
+                        statement.get_position(),
+                        DataTypes::Function(
+                            arguments.clone(),
+                            Box::new(enum_type.clone()),
+                        ),
+                    )
+                }),
+            );
+        }
+
+        environment.get_store().add_data_type(
+            &enum_name,
+            &DataType::new(
+                statement.get_position(),
+                DataTypes::HashMap(constructors),
+            ),
+        );
+
+        return Ok(DataType::new(statement.get_position(), DataTypes::Void));
+    }
     // Expression
-    if let Some(expression) = statement.node.get_expression() {
+    else if let Some(expression) = statement.node.get_expression() {
         return check_expression(&expression, environment);
     }
     // Function
     else if let Some((
         function_name,
+        function_type_parameters,
         function_arguments,
         function_return_type,
         function_body,
@@ -311,64 +2682,291 @@ pub fn check_statement(
         // Get the function name string.
         if let Some(identifier_name) = function_name.node.get_identifier() {
             // Check if the name is already in use.
-            if environment.get_store().has_key_type(&identifier_name) {
+            if environment.get_store().is_type_in_use(&identifier_name) {
                 return Err(Error::new_name_in_use(
                     function_name.get_position(),
                     identifier_name.clone(),
                     environment
                         .get_store()
                         .get_data_type_with_outer(&identifier_name)
-                        .unwrap()
-                        .get_position(),
+                        .map(|last| last.get_position()),
                 ));
             }
 
-            // Create a new internal storage.
-            let store = Store::from_outer(&environment.get_store());
+            let type_name = environment
+                .get_store()
+                .get_data_type_name(&function_return_type);
+            let function_type = check_function(
+                statement.get_position(),
+                &identifier_name,
+                function_type_parameters,
+                function_arguments,
+                function_return_type,
+                function_body,
+                environment,
+            )?;
 
-            // Create a new internal environment.
-            let mut function_environment = environment.clone();
+            environment
+                .get_store()
+                .add_data_type(&identifier_name, &function_type);
+            environment
+                .get_store()
+                .add_type_name(&identifier_name, type_name);
 
-            function_environment.set_store(&store);
+            return Ok(function_type);
+        } else {
+            return Err(Error::new_unknown_token(function_name.get_position()));
+        }
+    }
+    // Impl
+    else if let Some((impl_type, impl_methods)) = statement.node.get_impl() {
+        // The methods are kept for the name of the data type (like `Label`).
+        let impl_name = impl_type.node.get_identifier();
+        let impl_type = resolve_data_type(&impl_type, environment)?;
 
-            // Initialize the function arguments data types.
-            let mut arguments_types: Vec<DataType> = Vec::new();
+        // Create an environment where `Self` is the implemented data type.
+        let mut impl_environment = environment.clone();
 
-            for arg in function_arguments.iter() {
-                // Check expression:
-                // Append the argument data type to the function arguments data types list.
-                arguments_types
-                    .push(check_expression(arg, &mut function_environment)?);
+        impl_environment.set_store(&Store::from_outer(environment.get_store()));
+
+        impl_environment
+            .get_store()
+            .add_type_definition(&String::from("Self"), &impl_type);
+        impl_environment
+            .get_store()
+            .add_type_name("Self", impl_name.clone());
+
+        let signatures = match &impl_name {
+            Some(impl_name) => {
+                environment.get_store().get_signatures_with_outer(impl_name)
             }
+            None => Vec::new(),
+        };
 
-            let function_type: DataType = DataType::new(
-                statement.get_position(),
-                DataTypes::Function(arguments_types, function_return_type),
+        // Allow the methods to call the interface methods, which must be
+        // implemented in the block.
+        for (method_name, method_type) in signatures.iter() {
+            impl_environment.get_store().add_method(
+                impl_name.clone(),
+                &impl_type,
+                method_name,
+                method_type,
             );
+        }
 
-            function_environment
-                .get_store()
-                .add_data_type(&identifier_name, &function_type);
+        let mut methods_names: Vec<String> = Vec::new();
 
-            let function_body_type =
-                check_body(function_body, &mut function_environment)?;
+        for impl_method in impl_methods.iter() {
+            let (method_name, method_type) =
+                check_method(impl_method, &mut impl_environment)?;
 
-            if let Some((_, return_type)) = function_type.node.get_function() {
-                if return_type.node != function_body_type.node {
+            // Check if the method matches the interface signature.
+            if let Some(signature) =
+                environment.get_store().get_signature_with_outer(
+                    impl_name.as_ref(),
+                    &impl_type,
+                    &method_name,
+                )
+            {
+                if signature.node != method_type.node {
                     return Err(Error::new_expect_type(
-                        function_body_type.get_position(),
-                        &return_type.node.to_string(),
-                        &function_body_type.node.to_string(),
+                        impl_method.get_position(),
+                        &signature.node.to_string(),
+                        &method_type.node.to_string(),
                     ));
                 }
             }
 
-            environment.get_store().add_data_type(&identifier_name, &function_type);
+            environment.get_store().add_method(
+                impl_name.clone(),
+                &impl_type,
+                &method_name,
+                &method_type,
+            );
 
-            return Ok(function_type);
-        } else {
-            return Err(Error::new_unknown_token(function_name.get_position()));
+            // Allow the next methods to call this method.
+            impl_environment.get_store().add_method(
+                impl_name.clone(),
+                &impl_type,
+                &method_name,
+                &method_type,
+            );
+
+            methods_names.push(method_name);
+        }
+
+        if let Some((method_name, _)) = signatures
+            .iter()
+            .find(|(method_name, _)| !methods_names.contains(method_name))
+        {
+            return Err(Error::new_semantic(
+                statement.get_position(),
+                &format!(
+                    "The `{}` method of `{}` is not implemented.",
+                    method_name,
+                    impl_name.unwrap()
+                ),
+            ));
+        }
+
+        return Ok(DataType::new(statement.get_position(), DataTypes::Void));
+    }
+    // Interface
+    else if let Some((
+        interface_name,
+        type_parameters,
+        extends,
+        properties,
+        methods,
+    )) = statement.node.get_interface()
+    {
+        // Check if the name is already in use.
+        if let Some(definition) = environment
+            .get_store()
+            .get_type_definition_with_outer(&interface_name)
+        {
+            return Err(Error::new_name_in_use(
+                statement.get_position(),
+                interface_name,
+                Some(definition.get_position()),
+            ));
+        }
+
+        // Create an environment with the type parameters.
+        let mut interface_environment = environment.clone();
+
+        interface_environment
+            .set_store(&Store::from_outer(environment.get_store()));
+
+        let type_parameters =
+            add_type_parameters(&type_parameters, &mut interface_environment)?;
+
+        // Merge the properties of the extended interfaces.
+        let mut inherited_data: OrderedMap<Box<DataType>> = OrderedMap::new();
+
+        for parent in extends.iter() {
+            let parent_type =
+                resolve_data_type(parent, &mut interface_environment)?;
+
+            let parent_data =
+                parent_type.node.get_hashmap().ok_or_else(|| {
+                    Error::new_expect_type(
+                        parent.get_position(),
+                        "interface",
+                        &parent_type.node.to_string(),
+                    )
+                })?;
+
+            for (key, value) in parent_data.into_iter() {
+                if let Some(last_type) = inherited_data.get(&key) {
+                    if last_type.node != value.node {
+                        return Err(Error::new_conflicting_property(
+                            parent.get_position(),
+                            key,
+                            &last_type.node.to_string(),
+                            &value.node.to_string(),
+                        ));
+                    }
+
+                    continue;
+                }
+
+                inherited_data.insert(key, value);
+            }
+        }
+
+        let mut data: OrderedMap<Box<DataType>> = OrderedMap::new();
+
+        for property in properties.iter() {
+            let (property_name, property_type, _) =
+                property.node.get_argument().unwrap();
+
+            if let Some(last_type) = data.get(&property_name) {
+                return Err(Error::new_name_in_use(
+                    property.get_position(),
+                    property_name,
+                    Some(last_type.get_position()),
+                ));
+            }
+
+            let property_type =
+                resolve_data_type(&property_type, &mut interface_environment)?;
+
+            // Check if the property can replace the inherited property.
+            if let Some(inherited_type) = inherited_data.get(&property_name) {
+                if !inherited_type.node.is_assignable(&property_type.node) {
+                    return Err(Error::new_conflicting_property(
+                        property.get_position(),
+                        property_name,
+                        &inherited_type.node.to_string(),
+                        &property_type.node.to_string(),
+                    ));
+                }
+            }
+
+            data.insert(property_name, Box::new(property_type));
+        }
+
+        // Keep the inherited properties before the interface properties, which
+        // replace the inherited ones with the same names.
+        let data: OrderedMap<Box<DataType>> =
+            inherited_data.into_iter().chain(data).collect();
+
+        let interface_type = DataType::new(
+            statement.get_position(),
+            DataTypes::HashMap(data.clone()),
+        );
+        let interface_definition =
+            with_type_parameters(&type_parameters, interface_type.clone());
+
+        environment
+            .get_store()
+            .add_type_definition(&interface_name, &interface_definition);
+
+        // Allow the method signatures to use the interface.
+        interface_environment
+            .get_store()
+            .add_type_definition(&interface_name, &interface_definition);
+
+        // Add the method signatures for the values of the interface.
+        let mut methods_types: Vec<(String, DataType)> = Vec::new();
+
+        for method in methods.iter() {
+            let (method_name, method_type, _) =
+                method.node.get_argument().unwrap();
+
+            // Check if the name is already in use.
+            if let Some(last_type) = data
+                .get(&method_name)
+                .map(|data_type| *data_type.clone())
+                .or_else(|| {
+                    methods_types
+                        .iter()
+                        .find(|(name, _)| name == &method_name)
+                        .map(|(_, data_type)| data_type.clone())
+                })
+            {
+                return Err(Error::new_name_in_use(
+                    method.get_position(),
+                    method_name,
+                    Some(last_type.get_position()),
+                ));
+            }
+
+            let method_type =
+                resolve_data_type(&method_type, &mut interface_environment)?;
+
+            environment.get_store().add_signature(
+                &interface_name,
+                &interface_type,
+                &method_name,
+                &method_type,
+            );
+
+            methods_types.push((method_name, method_type));
         }
+
+        return Ok(DataType::new(statement.get_position(), DataTypes::Void));
     }
     // Return
     else if let Some(value_exp) = statement.node.get_return() {
@@ -378,6 +2976,108 @@ pub fn check_statement(
 
         return Ok(DataType::new(statement.get_position(), DataTypes::Void));
     }
+    // Type alias
+    else if let Some((alias_name, alias_type)) =
+        statement.node.get_type_alias()
+    {
+        // Check if the name is already in use.
+        if let Some(definition) = environment
+            .get_store()
+            .get_type_definition_with_outer(&alias_name)
+        {
+            return Err(Error::new_name_in_use(
+                statement.get_position(),
+                alias_name,
+                Some(definition.get_position()),
+            ));
+        }
+
+        let alias_type = resolve_data_type(&alias_type, environment)?;
+
+        environment.get_store().add_type_definition(
+            &alias_name,
+            &DataType::new(statement.get_position(), alias_type.node),
+        );
+
+        return Ok(DataType::new(statement.get_position(), DataTypes::Void));
+    }
+    // Variable
+    else if let Some((is_mutable, pattern, declared_type, value)) =
+        statement.node.get_variable()
+    {
+        let data_type = match &declared_type {
+            Some(data_type) => Some(resolve_data_type(data_type, environment)?),
+            None => None,
+        };
+        let type_name = match (&declared_type, &value) {
+            (Some(declared_type), _) => {
+                environment.get_store().get_data_type_name(declared_type)
+            }
+            (None, Some(value)) => {
+                environment.get_store().get_expression_type_name(value)
+            }
+            (None, None) => None,
+        };
+
+        let variable_type = match value {
+            Some(value) => {
+                let value_type = check_expression(&value, environment)?;
+
+                match data_type {
+                    // The `JSON.parse` values are converted to the declared
+                    // data type when they are evaluated.
+                    Some(data_type)
+                        if builtins::json::is_parse_call(
+                            &value,
+                            environment.get_store(),
+                        ) =>
+                    {
+                        if !builtins::json::is_json_type(&data_type.node) {
+                            return Err(Error::new_semantic(
+                                data_type.get_position(),
+                                &format!(
+                                    "The `{}` data type cannot be parsed from JSON.",
+                                    declared_type.unwrap().to_string()
+                                ),
+                            ));
+                        }
+
+                        data_type
+                    }
+                    Some(data_type) => {
+                        if !data_type.node.is_assignable(&value_type.node) {
+                            return Err(new_assignment_error(
+                                value.get_position(),
+                                &data_type,
+                                &value_type,
+                            ));
+                        }
+
+                        data_type
+                    }
+                    // Only the constants keep the inferred literals.
+                    None if !is_mutable && value_type.node.is_literal() => {
+                        value_type
+                    }
+                    None => DataType::new(
+                        value_type.get_position(),
+                        value_type.node.widen(),
+                    ),
+                }
+            }
+            None => data_type.unwrap(),
+        };
+
+        check_pattern(&pattern, &variable_type, environment)?;
+
+        if let Some(variable_name) = pattern.node.get_identifier() {
+            environment
+                .get_store()
+                .add_type_name(&variable_name, type_name);
+        }
+
+        return Ok(variable_type);
+    }
 
     Err(Error::new_expect_type(
         statement.get_position(),
@@ -385,3 +3085,503 @@ pub fn check_statement(
         "Unknown",
     ))
 }
+
+#[test]
+fn test_arguments() {
+    use crate::test_utils::check_with;
+    use sflynlang_parser::ErrorType;
+
+    let check_call = |call: &str| {
+        check_with(
+            "func greet(name: string, greeting: string = 'Hi'): string { return greeting + name; }\nfunc join(first: string, ...rest: string[]) {}",
+            call,
+        )
+    };
+
+    assert_error!(
+        check_call("greet(greeting: 'Hey')"),
+        ErrorType::MissingArgument(_)
+    );
+    assert_error!(
+        check_call("greet('Sflyn', name: 'Sflyn')"),
+        ErrorType::DuplicateArgument(_, _)
+    );
+    assert_error!(
+        check_call("greet(nmae: 'Sflyn')"),
+        ErrorType::UnknownArgument(_)
+    );
+    assert_error!(check_call("greet(name: 10)"), ErrorType::ExpectType(_, _));
+    assert_error!(
+        check_call("greet('Sflyn', 'Hey', '!')"),
+        ErrorType::ExpectArguments(2, 3)
+    );
+
+    assert_error!(
+        check_call("greet(name: 'Sflyn', 'Hey')"),
+        ErrorType::InvalidArgument(_)
+    );
+
+    assert_error!(
+        check_call("join(...['Sflyn'])"),
+        ErrorType::InvalidArgument(_)
+    );
+    assert_error!(check_call("join('Sflyn', 10)"), ErrorType::ExpectType(_, _));
+    assert_error!(
+        check_call("join('Sflyn', ...[10])"),
+        ErrorType::ExpectType(_, _)
+    );
+}
+
+#[test]
+fn test_patterns() {
+    use crate::test_utils::check_with;
+    use sflynlang_parser::ErrorType;
+
+    let check_variable = |variable: &str| {
+        check_with(
+            "interface Label { message: string; size: number; }\nconst label: Label = { message: 'Size 10', size: 10 };",
+            variable,
+        )
+    };
+
+    assert_error!(
+        check_variable("const { message, sizee } = label;"),
+        ErrorType::UnknownProperty(_)
+    );
+    assert_error!(
+        check_variable("const [message] = label;"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_variable("const { message } = [label];"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_variable("let [first, ...rest]: string[] = ['Size', 10];"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_variable("const [...rest, last] = ['Size', '10'];"),
+        ErrorType::Semantic(_)
+    );
+    assert_error!(
+        check_variable(
+            "func show({ size }: Label) { const text: string = size; }"
+        ),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_variable("const (message, size) = ('Size 10', 10, true);"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_variable("const pair = ('Size 10', 10);\nprint(pair.2);"),
+        ErrorType::UnknownProperty(_)
+    );
+}
+
+#[test]
+fn test_unions() {
+    use crate::test_utils::check_with;
+
+    let check_source = |source: &str| {
+        check_with(
+            "type Id = string | number;\nconst id: Id = 'Sflyn';",
+            source,
+        )
+    };
+
+    assert!(check_source("const other: Id = 10;").is_ok());
+    assert!(check_source("const other: Id = true;").is_err());
+    assert!(check_source("const other: string = id;").is_err());
+    assert!(check_source(
+        "const other: string = typeof id == 'string' ? id : '';"
+    )
+    .is_ok());
+    assert!(check_source(
+        "func name(): string {\nif (typeof id == 'number') { return ''; }\nreturn id;\n}"
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_literals() {
+    use crate::test_utils::check_errors;
+    use sflynlang_parser::ErrorType;
+
+    let errors = check_errors(
+        "type Method = 'GET' | 'POST';\nconst method = 'GET';\nlet other = 'GET';\nconst first: Method = method;\nconst second: Method = other;",
+    );
+
+    // Only the mutable variable is widened to `string`.
+    match errors.as_slice() {
+        [error] => match error.get_error_type() {
+            ErrorType::ExpectLiteral(literals, got) => {
+                assert_eq!(literals, vec!["\"GET\"", "\"POST\""]);
+                assert_eq!(got, "string");
+            }
+            error_type => panic!("Unexpected error type: {:?}", error_type),
+        },
+        _ => panic!("Expect one error, got {} instead.", errors.len()),
+    }
+}
+
+#[test]
+fn test_enums() {
+    use crate::test_utils::check_errors;
+    use sflynlang_parser::ErrorType;
+
+    let errors = check_errors(
+        "enum List {\n  Cons(head: number, tail: List),\n  Nil,\n}\nconst list = List.Cons(1, List.Nil);\nconst first = match (list) { Cons(head, Nil) => head, Nil => 0 };\nconst second = match (list) { Cons(head, tail) => head, _ => 0 };",
+    );
+
+    // Only the first match does not handle every value of `Cons`.
+    match errors.as_slice() {
+        [error] => match error.get_error_type() {
+            ErrorType::MissingVariants(enum_name, variants) => {
+                assert_eq!(enum_name, "List");
+                assert_eq!(variants, vec!["Cons"]);
+            }
+            error_type => panic!("Unexpected error type: {:?}", error_type),
+        },
+        _ => panic!("Expect one error, got {} instead.", errors.len()),
+    }
+}
+
+#[test]
+fn test_generics() {
+    use crate::test_utils::check_errors;
+    use sflynlang_parser::ErrorType;
+
+    let errors = check_errors(
+        "interface Box<T> {\n  value: T;\n}\nfunc unbox<T>(box: Box<T>): T {\n  return box.value;\n}\nfunc first<T>(list: T[]): T? {\n  return null;\n}\nconst name: string = unbox({ value: 'Sflyn' });\nconst other: number = unbox({ value: 'Sflyn' });\nconst empty = first([]);",
+    );
+
+    match errors.as_slice() {
+        [first_error, second_error] => {
+            match first_error.get_error_type() {
+                ErrorType::ExpectType(expected, got) => {
+                    assert_eq!(expected, "number");
+                    assert_eq!(got, "string");
+                }
+                error_type => panic!("Unexpected error type: {:?}", error_type),
+            }
+
+            match second_error.get_error_type() {
+                ErrorType::CannotInfer(name) => assert_eq!(name, "T"),
+                error_type => panic!("Unexpected error type: {:?}", error_type),
+            }
+        }
+        _ => panic!("Expect two errors, got {} instead.", errors.len()),
+    }
+}
+
+#[test]
+fn test_constraints() {
+    use crate::test_utils::check_with;
+    use sflynlang_parser::ErrorType;
+
+    let check_constraint = |code: &str| {
+        check_with(
+            "interface Named {\n  name: string;\n}\ninterface Sized {\n  size: number;\n}\nfunc show<T extends Named>(value: T): string {\n  return value.name;\n}",
+            code,
+        )
+    };
+
+    assert_error!(
+        check_constraint("show({ size: 10 });"),
+        ErrorType::UnsatisfiedConstraint(_, _, _)
+    );
+    assert_error!(
+        check_constraint("interface Label extends Named { name: number; }"),
+        ErrorType::ConflictingProperty(_, _, _)
+    );
+    assert_error!(
+        check_constraint(
+            "interface Label extends Named, Sized { size: string; }"
+        ),
+        ErrorType::ConflictingProperty(_, _, _)
+    );
+    assert_error!(
+        check_constraint(
+            "interface Label extends Named, Sized { color: string; }\nconst label: Label = { name: 'Sflyn', color: 'red' };"
+        ),
+        ErrorType::ExpectType(_, _)
+    );
+
+    // The values of an interface can be used as the values of its parents.
+    assert!(check_constraint(
+        "interface Pet extends Named { age: number; }\nfunc greet(named: Named): string { return named.name; }\nfunc toNamed(pet: Pet): Named { return pet; }\nconst pet: Pet = { name: 'Rex', age: 3 };\nconst named: Named = pet;\ngreet(pet);\nshow(toNamed(pet));"
+    )
+    .is_ok());
+    assert_error!(
+        check_constraint("const named: Named = { size: 10 };"),
+        ErrorType::ExpectType(_, _)
+    );
+}
+
+#[test]
+fn test_impl() {
+    use crate::test_utils::{check_with, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    let check_impl = |code: &str| {
+        check_with(
+            "interface Label {\n  message: string;\n  describe(): string;\n}",
+            code,
+        )
+    };
+
+    assert_error!(
+        check_impl(
+            "impl Label {\n  func describe(): string {\n    return 'Label';\n  }\n}"
+        ),
+        ErrorType::MissingArgument(_)
+    );
+    assert_error!(
+        check_impl(
+            "impl Label {\n  func describe(self): number {\n    return 1;\n  }\n}"
+        ),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_impl(
+            "impl Label {\n  func describe(self): string {\n    return self.message;\n  }\n}\nconst label: Label = { message: 'Size' };\nconst size: number = label.describe();"
+        ),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_impl(
+            "impl Label {\n  func describe(self): string {\n    return self.message;\n  }\n}\nconst label: Label = { message: 'Size' };\nlabel.describe(10);"
+        ),
+        ErrorType::ExpectArguments(_, _)
+    );
+
+    // The interface methods must be implemented.
+    assert_error!(
+        check_impl(
+            "const label: Label = { message: 'Size' };\nlabel.describe();"
+        ),
+        ErrorType::Semantic(_)
+    );
+    assert_error!(
+        check_impl(
+            "impl Label {\n  func shout(self): string {\n    return self.message;\n  }\n}"
+        ),
+        ErrorType::Semantic(_)
+    );
+
+    // The values use the methods of their declared interface, even when
+    // another interface has the same properties.
+    let mut environment = run_code(
+        "interface Label {\n  message: string;\n  describe(): string;\n}\ninterface Tag {\n  message: string;\n  describe(): string;\n}\nimpl Label {\n  func describe(self): string {\n    return 'Label ' + self.message;\n  }\n  func shout(self): string {\n    return self.describe() + '!';\n  }\n}\nimpl Tag {\n  func describe(self): string {\n    return 'Tag ' + self.message;\n  }\n}\nfunc newLabel(message: string): Label {\n  return { message: message };\n}\nfunc show(tag: Tag): string {\n  return tag.describe();\n}\nconst label = newLabel('a');\nconst tag: Tag = { message: 'b' };\nconst described = label.describe() + ', ' + show(tag) + ', ' + label.shout();",
+    )
+    .unwrap();
+
+    assert_eq!(
+        get_value(&mut environment, "described"),
+        Some(String::from("Label a, Tag b, Label a!"))
+    );
+}
+
+#[test]
+fn test_classes() {
+    use crate::test_utils::check_with;
+    use sflynlang_parser::ErrorType;
+
+    let check_class = |code: &str| {
+        check_with(
+            "class Counter {\n  public name: string;\n  private count: number = 0;\n  constructor(self, name: string) {\n    self.name = name;\n  }\n  private func reset(self) {\n    self.count = 0;\n  }\n}\nconst counter = Counter('clicks');",
+            code,
+        )
+    };
+
+    assert_error!(
+        check_class("counter.count;"),
+        ErrorType::PrivateMember(_, _)
+    );
+    assert_error!(
+        check_class("counter.reset();"),
+        ErrorType::PrivateMember(_, _)
+    );
+    assert_error!(
+        check_class("counter.name = 10;"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(check_class("counter.size;"), ErrorType::UnknownProperty(_));
+    assert_error!(
+        check_class("counter = Counter('views');"),
+        ErrorType::Semantic(_)
+    );
+    assert_error!(check_class("Counter(10);"), ErrorType::ExpectType(_, _));
+    assert_error!(
+        check_class("class Label {\n  message: string;\n}"),
+        ErrorType::UninitializedField(_)
+    );
+}
+
+#[test]
+fn test_hashmap_order() {
+    use crate::test_utils::{check_code, get_error_type};
+    use sflynlang_parser::ErrorType;
+
+    let result = check_code(
+        "const user = { name: 'Sflyn', meta: { stars: 3 }, age: 1 };\nconst name: string = user;",
+    );
+
+    // The data types are shown in one line with their keys in order.
+    match get_error_type(result) {
+        ErrorType::ExpectType(_, got) => assert_eq!(
+            got,
+            "{ \"name\": string, \"meta\": { \"stars\": number }, \"age\": number }"
+        ),
+        error_type => panic!("Unexpected error type: {:?}", error_type),
+    }
+}
+
+#[test]
+fn test_try() {
+    use crate::test_utils::{check_code, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    // The body and the handler have their own scopes.
+    assert_error!(
+        check_code("try { const inner = 1; } catch (error) {}\nprint(inner);"),
+        ErrorType::UnknownIdentifier(_)
+    );
+    assert_error!(
+        check_code("try {} catch (error) {}\nprint(error);"),
+        ErrorType::UnknownIdentifier(_)
+    );
+
+    let mut environment = run_code(
+        "func parse(text: string): string {\ntry {\nconst value: number = JSON.parse(text);\nreturn value.toString();\n} catch (error) {\nconst value = 'invalid';\nreturn value;\n}\n}\nconst first = parse('1');\nconst second = parse('x');\ntry { const value = 1; } catch (error) {}\nconst value = 2;",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("first"), Some(String::from("1")));
+    assert_eq!(get_value("second"), Some(String::from("invalid")));
+    assert_eq!(get_value("value"), Some(String::from("2")));
+}
+
+#[test]
+fn test_ternary() {
+    use crate::test_utils::{check_code, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    // The type of the branches is unified.
+    assert!(check_code(
+        "const flag = true;\nconst value: number | string = flag ? 1 : 'a';"
+    )
+    .is_ok());
+    assert!(check_code(
+        "const flag = true;\nconst value: number? = flag ? 1 : null;"
+    )
+    .is_ok());
+    assert_error!(
+        check_code("const flag = true;\nconst value: number = flag ? 1 : 'a';"),
+        ErrorType::ExpectType(_, _)
+    );
+    assert_error!(
+        check_code("const value = 1 ? 'a' : 'b';"),
+        ErrorType::ExpectType(_, _)
+    );
+
+    // The ternaries are nested from the right.
+    let mut environment = run_code(
+        "func sign(x: number): string { return x > 0 ? 'positive' : x < 0 ? 'negative' : 'zero'; }\nconst positive = sign(2);\nconst negative = sign(-2);\nconst zero = sign(0);\nconst picked = false ? 1 : true ? 2 : 3;",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("positive"), Some(String::from("positive")));
+    assert_eq!(get_value("negative"), Some(String::from("negative")));
+    assert_eq!(get_value("zero"), Some(String::from("zero")));
+    assert_eq!(get_value("picked"), Some(String::from("2")));
+}
+
+#[test]
+fn test_pipeline() {
+    use crate::test_utils::{check_code, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    // The values are passed to the functions, the calls and the methods.
+    let mut environment = run_code(
+        "func double(x: number): number { return x * 2; }\nconst root = 16 |> Math.sqrt;\nconst max = 3 |> Math.max(7);\nconst chained = 2 |> double |> (double) |> Math.pow(2);",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("root"), Some(String::from("4")));
+    assert_eq!(get_value("max"), Some(String::from("7")));
+    assert_eq!(get_value("chained"), Some(String::from("64")));
+
+    assert_error!(check_code("2 |> 10;"), ErrorType::ExpectType(_, _));
+}
+
+#[test]
+fn test_builtin_names() {
+    use crate::test_utils::{check_code, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    // The builtin functions have no declared position.
+    assert_error!(
+        check_code("func input(x: string): string { return x; }"),
+        ErrorType::NameInUse(_, None)
+    );
+    assert_error!(
+        check_code("const input: string = 'a';"),
+        ErrorType::NameInUse(_, None)
+    );
+    assert_error!(
+        check_code("func f(readLine: string) {}"),
+        ErrorType::NameInUse(_, None)
+    );
+    assert_error!(
+        check_code("func format(x: string): string { return x; }"),
+        ErrorType::NameInUse(_, None)
+    );
+    assert_error!(
+        check_code("const println = 1;"),
+        ErrorType::NameInUse(_, None)
+    );
+
+    // The builtin namespaces can be shadowed by the program.
+    let mut environment = run_code(
+        "func size(fs: string): number { return fs.length(); }\nconst [time, offset] = [1, 2];\nconst length = size('abc');\nconst sum = time + offset;\nfunc largest(): number { return Math.max(1, 2); }\nconst max = largest();",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("length"), Some(String::from("3")));
+    assert_eq!(get_value("sum"), Some(String::from("3")));
+    assert_eq!(get_value("max"), Some(String::from("2")));
+}
+
+#[test]
+fn test_tuples() {
+    use crate::test_utils::{check_code, get_value, run_code};
+    use sflynlang_parser::ErrorType;
+
+    assert!(check_code("const pair: (number, string) = (1, 'a');").is_ok());
+    assert_error!(
+        check_code("const pair: (number, string) = ('a', 1);"),
+        ErrorType::ExpectType(_, _)
+    );
+
+    // The items are read by their indexes and the tuples are destructured.
+    let mut environment = run_code(
+        "const pair = (1, 'a');\nconst nested = ((2, 'b'), true);\nconst first = pair.0;\nconst second = pair.1;\nconst inner = nested.0.1;\nconst ((digit, letter), flag) = ((3, 'c'), false);",
+    )
+    .unwrap();
+    let mut get_value = |name: &str| get_value(&mut environment, name);
+
+    assert_eq!(get_value("pair"), Some(String::from("(1, \"a\")")));
+    assert_eq!(get_value("first"), Some(String::from("1")));
+    assert_eq!(get_value("second"), Some(String::from("a")));
+    assert_eq!(get_value("inner"), Some(String::from("b")));
+    assert_eq!(get_value("digit"), Some(String::from("3")));
+    assert_eq!(get_value("letter"), Some(String::from("c")));
+    assert_eq!(get_value("flag"), Some(String::from("false")));
+}
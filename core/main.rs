@@ -1,6 +1,6 @@
 pub mod utils;
 
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg};
 use std::{fs, path::Path};
 
 fn main() {
@@ -8,7 +8,9 @@ fn main() {
         .about("High-level programming language.")
         .version("0.1.0")
         .author("Sflynlang Contributors")
+        .setting(AppSettings::TrailingVarArg)
         .arg(Arg::new("FILE").index(1).required(true))
+        .arg(Arg::new("ARGS").index(2).multiple(true))
         .arg(Arg::new("debug").short('d').long("debug"))
         .get_matches();
 
@@ -30,11 +32,21 @@ fn main() {
                 sflynlang_parser::File::new(run_file.to_string(), file_content);
 
             if let Some(statements) = sflynlang_parser::run(&file) {
-                exit_code = sflynlang_compiler::run(
-                    statements,
-                    matches.is_present("debug"),
-                    &file,
+                let mut environment = sflynlang_compiler::Environment::new();
+
+                environment.set_debug_mode(matches.is_present("debug"));
+                environment.set_args(
+                    matches
+                        .values_of("ARGS")
+                        .map(|args| args.map(String::from).collect())
+                        .unwrap_or_default(),
+                );
+                environment.set_reserved_path(
+                    utils::restricted_names::is_windows_reserved_path,
                 );
+
+                exit_code =
+                    sflynlang_compiler::run(statements, environment, &file);
             } else {
                 exit_code = 1;
             }
@@ -1,9 +1,14 @@
 mod data_types;
 mod expressions;
 mod node;
+mod patterns;
 mod statements;
 
-pub use data_types::{DataType, DataTypes};
-pub use expressions::{Expression, Expressions};
+pub use data_types::{DataType, DataTypes, VariantType};
+pub use expressions::{Expression, Expressions, MatchArm};
 pub use node::Node;
-pub use statements::{Statement, Statements};
+pub use patterns::{Pattern, Patterns};
+pub use statements::{
+    ClassField, ClassMethod, ClassParts, InterfaceParts, Statement, Statements,
+    Variant,
+};
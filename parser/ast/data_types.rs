@@ -1,8 +1,11 @@
-use crate::ast::Node;
-use std::{collections::HashMap, fmt};
+use crate::{ast::Node, OrderedMap};
+use std::fmt;
 
 pub type DataType = Node<DataTypes>;
 
+/// Name and values data types of an enum variant.
+pub type VariantType = (String, Vec<DataType>);
+
 impl DataType {
     pub fn to_string(&self) -> String {
         self.node.to_string()
@@ -17,19 +20,45 @@ impl fmt::Display for DataType {
 
 #[derive(Clone, Debug)]
 pub enum DataTypes {
+    Argument {
+        name: String,
+        data_type: Box<DataType>,
+        is_optional: bool,
+    },
     Array(Box<DataType>),
     Boolean,
+    Enum(String, Vec<VariantType>),
     Function(Vec<DataType>, Box<DataType>),
-    HashMap(HashMap<String, Box<DataType>>),
+    Generic(String, Vec<DataType>),
+    HashMap(OrderedMap<Box<DataType>>),
     Identifier(String),
+    Instance(String),
     Number,
+    NumberLiteral(f64),
     Option(Box<DataType>),
+    Parameter(String, Option<Box<DataType>>),
+    Parameterized(Vec<DataType>, Box<DataType>),
+    Spread(Box<DataType>),
     String,
+    StringLiteral(String),
+    Tuple(Vec<DataType>),
+    Union(Vec<DataType>),
     Unknown,
     Void,
 }
 
 impl DataTypes {
+    pub fn get_argument(&self) -> Option<(String, Box<DataType>, bool)> {
+        match self {
+            Self::Argument {
+                name,
+                data_type,
+                is_optional,
+            } => Some((name.clone(), data_type.clone(), *is_optional)),
+            _ => None,
+        }
+    }
+
     pub fn get_array(&self) -> Option<Box<DataType>> {
         match self {
             Self::Array(data_type) => Some(data_type.clone()),
@@ -44,6 +73,15 @@ impl DataTypes {
         }
     }
 
+    pub fn get_enum(&self) -> Option<(String, Vec<VariantType>)> {
+        match self {
+            Self::Enum(name, variants) => {
+                Some((name.clone(), variants.clone()))
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_function(&self) -> Option<(Vec<DataType>, Box<DataType>)> {
         match self {
             Self::Function(arguments, return_type) => {
@@ -53,7 +91,16 @@ impl DataTypes {
         }
     }
 
-    pub fn get_hashmap(&self) -> Option<HashMap<String, Box<DataType>>> {
+    pub fn get_generic(&self) -> Option<(String, Vec<DataType>)> {
+        match self {
+            Self::Generic(name, arguments) => {
+                Some((name.clone(), arguments.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_hashmap(&self) -> Option<OrderedMap<Box<DataType>>> {
         match self {
             Self::HashMap(data) => Some(data.clone()),
             _ => None,
@@ -67,6 +114,13 @@ impl DataTypes {
         }
     }
 
+    pub fn get_instance(&self) -> Option<String> {
+        match self {
+            Self::Instance(class_name) => Some(class_name.clone()),
+            _ => None,
+        }
+    }
+
     pub fn is_number(&self) -> bool {
         match self {
             Self::Number => true,
@@ -74,6 +128,13 @@ impl DataTypes {
         }
     }
 
+    pub fn get_number_literal(&self) -> Option<f64> {
+        match self {
+            Self::NumberLiteral(value) => Some(*value),
+            _ => None,
+        }
+    }
+
     pub fn get_option(&self) -> Option<Box<DataType>> {
         match self {
             Self::Option(data_type) => Some(data_type.clone()),
@@ -81,6 +142,36 @@ impl DataTypes {
         }
     }
 
+    pub fn get_parameter(&self) -> Option<String> {
+        match self {
+            Self::Parameter(name, _) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_parameter_constraint(&self) -> Option<Box<DataType>> {
+        match self {
+            Self::Parameter(_, constraint) => constraint.clone(),
+            _ => None,
+        }
+    }
+
+    pub fn get_parameterized(&self) -> Option<(Vec<DataType>, Box<DataType>)> {
+        match self {
+            Self::Parameterized(parameters, data_type) => {
+                Some((parameters.clone(), data_type.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_spread(&self) -> Option<Box<DataType>> {
+        match self {
+            Self::Spread(data_type) => Some(data_type.clone()),
+            _ => None,
+        }
+    }
+
     pub fn is_string(&self) -> bool {
         match self {
             Self::String => true,
@@ -88,6 +179,27 @@ impl DataTypes {
         }
     }
 
+    pub fn get_string_literal(&self) -> Option<String> {
+        match self {
+            Self::StringLiteral(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_tuple(&self) -> Option<Vec<DataType>> {
+        match self {
+            Self::Tuple(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_union(&self) -> Option<Vec<DataType>> {
+        match self {
+            Self::Union(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+
     pub fn is_unknown(&self) -> bool {
         match self {
             Self::Unknown => true,
@@ -102,10 +214,197 @@ impl DataTypes {
         }
     }
 
+    /// Check if the data type is a literal or an union of literals.
+    pub fn is_literal(&self) -> bool {
+        match self {
+            Self::NumberLiteral(_) | Self::StringLiteral(_) => true,
+            Self::Union(values) => {
+                values.iter().all(|value| value.node.is_literal())
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the data type without literals (`"GET"` is widened to `string`).
+    pub fn widen(&self) -> Self {
+        let widen_values = |values: &Vec<DataType>| -> Vec<DataType> {
+            values
+                .iter()
+                .map(|value| {
+                    DataType::new(value.get_position(), value.node.widen())
+                })
+                .collect()
+        };
+
+        match self {
+            Self::Array(data_type) => Self::Array(Box::new(DataType::new(
+                data_type.get_position(),
+                data_type.node.widen(),
+            ))),
+            Self::HashMap(data) => Self::HashMap(
+                data.iter()
+                    .map(|(key, value)| {
+                        (
+                            key.clone(),
+                            Box::new(DataType::new(
+                                value.get_position(),
+                                value.node.widen(),
+                            )),
+                        )
+                    })
+                    .collect(),
+            ),
+            Self::NumberLiteral(_) => Self::Number,
+            Self::Option(data_type) => Self::Option(Box::new(DataType::new(
+                data_type.get_position(),
+                data_type.node.widen(),
+            ))),
+            Self::StringLiteral(_) => Self::String,
+            Self::Tuple(values) => Self::Tuple(widen_values(values)),
+            Self::Union(values) => {
+                let mut union_values: Vec<DataType> = Vec::new();
+
+                for value in widen_values(values) {
+                    if !union_values
+                        .iter()
+                        .any(|union_value| union_value.node == value.node)
+                    {
+                        union_values.push(value);
+                    }
+                }
+
+                if union_values.len() == 1 {
+                    union_values.remove(0).node
+                } else {
+                    Self::Union(union_values)
+                }
+            }
+            data_type => data_type.clone(),
+        }
+    }
+
+    /// Check if a value of the other data type can be assigned to this data
+    /// type.
+    pub fn is_assignable(&self, other: &Self) -> bool {
+        // The unknown data type accepts every value (like the `print` values).
+        if self.is_unknown() {
+            return true;
+        }
+
+        // The argument names are not part of the data type.
+        if let Some((_, self_type, _)) = self.get_argument() {
+            return self_type.node.is_assignable(other);
+        } else if let Some((_, other_type, _)) = other.get_argument() {
+            return self.is_assignable(&other_type.node);
+        }
+
+        // A type parameter can be assigned as its constraint.
+        if let Some(constraint) = other.get_parameter_constraint() {
+            if self.is_assignable(&constraint.node) {
+                return true;
+            }
+        }
+
+        // Every member of the other union must be assignable.
+        if let Some(other_types) = other.get_union() {
+            return other_types
+                .iter()
+                .all(|other_type| self.is_assignable(&other_type.node));
+        }
+
+        // An union accepts the data types of its members.
+        if let Some(self_types) = self.get_union() {
+            return self_types
+                .iter()
+                .any(|self_type| self_type.node.is_assignable(other));
+        }
+
+        // A literal can be assigned to its data type.
+        if (self.is_number() && other.get_number_literal().is_some())
+            || (self.is_string() && other.get_string_literal().is_some())
+        {
+            return true;
+        }
+
+        // An option accepts null and the data types of its value.
+        if let Some(self_type) = self.get_option() {
+            return match other.get_option() {
+                Some(other_type) => {
+                    other_type.node.is_unknown()
+                        || self_type.node.is_assignable(&other_type.node)
+                }
+                None => self_type.node.is_assignable(other),
+            };
+        }
+
+        if let Some(self_type) = self.get_array() {
+            if let Some(other_type) = other.get_array() {
+                // An empty array can be assigned to any array.
+                return other_type.node.is_unknown()
+                    || self_type.node.is_assignable(&other_type.node);
+            }
+        } else if let Some(self_data) = self.get_hashmap() {
+            // The other hashmap can have more properties (like the values of
+            // the interfaces that extend this one).
+            if let Some(other_data) = other.get_hashmap() {
+                return self_data.iter().all(|(key, value)| {
+                    match other_data.get(key) {
+                        Some(other_value) => {
+                            value.node.is_assignable(&other_value.node)
+                        }
+                        None => false,
+                    }
+                });
+            }
+        } else if let Some(self_values) = self.get_tuple() {
+            if let Some(other_values) = other.get_tuple() {
+                return self_values.len() == other_values.len()
+                    && self_values.iter().zip(other_values.iter()).all(
+                        |(self_value, other_value)| {
+                            self_value.node.is_assignable(&other_value.node)
+                        },
+                    );
+            }
+        }
+
+        self == other
+    }
+
+    /// Get the name of the data type returned by the `typeof` operator.
+    pub fn get_typeof(&self) -> String {
+        match self {
+            Self::Argument { data_type, .. } => data_type.node.get_typeof(),
+            Self::Array(_) => String::from("array"),
+            Self::Boolean => String::from("boolean"),
+            Self::Enum(name, _) => name.clone(),
+            Self::Function(_, _) => String::from("function"),
+            Self::HashMap(_) => String::from("hashmap"),
+            Self::Identifier(name) => name.clone(),
+            Self::Instance(class_name) => class_name.clone(),
+            Self::Number | Self::NumberLiteral(_) => String::from("number"),
+            Self::Option(_) => String::from("null"),
+            Self::String | Self::StringLiteral(_) => String::from("string"),
+            Self::Tuple(_) => String::from("tuple"),
+            Self::Void => String::from("void"),
+            _ => String::from("unknown"),
+        }
+    }
+
     pub fn to_string(&self) -> String {
         match self {
+            Self::Argument {
+                name,
+                data_type,
+                is_optional,
+            } => format!(
+                "{}{}: {}",
+                name,
+                if *is_optional { "?" } else { "" },
+                data_type
+            ),
             Self::Array(data_type) => format!("{}[]", data_type),
             Self::Boolean => String::from("boolean"),
+            Self::Enum(name, _) => name.clone(),
             Self::Function(arguments, return_type) => format!(
                 "({}) => {}",
                 arguments
@@ -115,21 +414,56 @@ impl DataTypes {
                     .join(", "),
                 return_type
             ),
-            Self::HashMap(data) => format!(
-                "{{\n{}\n}}",
-                data.iter()
-                    .map(|(key, value)| format!(
-                        "{}: {}",
-                        key,
-                        value.to_string()
-                    ))
+            Self::Generic(name, arguments) => format!(
+                "{}<{}>",
+                name,
+                arguments
+                    .iter()
+                    .map(|argument| argument.to_string())
                     .collect::<Vec<String>>()
-                    .join(",\n")
+                    .join(", ")
             ),
+            Self::HashMap(data) => {
+                data.to_inline_string(|value| value.to_string())
+            }
             Self::Identifier(value) => value.clone(),
+            Self::Instance(class_name) => class_name.clone(),
             Self::Number => String::from("number"),
+            Self::NumberLiteral(value) => value.to_string(),
             Self::Option(data_type) => format!("Option<{}>", data_type),
+            Self::Parameter(name, _) => name.clone(),
+            Self::Parameterized(parameters, data_type) => format!(
+                "<{}>{}",
+                parameters
+                    .iter()
+                    .map(|parameter| {
+                        match parameter.node.get_parameter_constraint() {
+                            Some(constraint) => {
+                                format!("{} extends {}", parameter, constraint)
+                            }
+                            None => parameter.to_string(),
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                data_type
+            ),
+            Self::Spread(data_type) => format!("...{}", data_type),
             Self::String => String::from("string"),
+            Self::StringLiteral(value) => format!("{:?}", value),
+            Self::Tuple(values) => format!(
+                "({})",
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::Union(values) => values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<String>>()
+                .join(" | "),
             Self::Unknown => String::from("unknown"),
             Self::Void => String::from("void"),
         }
@@ -138,10 +472,22 @@ impl DataTypes {
 
 impl PartialEq for DataTypes {
     fn eq(&self, other: &Self) -> bool {
+        // The argument names are not part of the data type.
+        if let Some((_, self_type, _)) = self.get_argument() {
+            return self_type.node == *other;
+        } else if let Some((_, other_type, _)) = other.get_argument() {
+            return *self == other_type.node;
+        }
+
         if let Some(self_type) = self.get_array() {
             if let Some(other_type) = other.get_array() {
                 return self_type.node == other_type.node;
             }
+        } else if let Some((self_name, _)) = self.get_enum() {
+            // The enums are compared by name.
+            if let Some((other_name, _)) = other.get_enum() {
+                return self_name == other_name;
+            }
         } else if let Some((self_arguments, self_return_type)) =
             self.get_function()
         {
@@ -165,10 +511,20 @@ impl PartialEq for DataTypes {
                     return self_return_type.node == other_return_type.node;
                 }
             }
+        } else if let Some((self_name, self_arguments)) = self.get_generic() {
+            if let Some((other_name, other_arguments)) = other.get_generic() {
+                return self_name == other_name
+                    && self_arguments.len() == other_arguments.len()
+                    && self_arguments.iter().zip(other_arguments.iter()).all(
+                        |(self_argument, other_argument)| {
+                            self_argument.node == other_argument.node
+                        },
+                    );
+            }
         } else if let Some(self_data) = self.get_hashmap() {
             if let Some(other_data) = other.get_hashmap() {
                 if self_data.len() == other_data.len() {
-                    for (key, value) in other_data.iter() {
+                    for (key, value) in self_data.iter() {
                         if other_data.contains_key(key) {
                             let odata: &DataType = other_data.get(key).unwrap();
 
@@ -187,10 +543,59 @@ impl PartialEq for DataTypes {
             if let Some(other_identifier) = other.get_identifier() {
                 return self_identifier == other_identifier;
             }
+        } else if let Some(self_class) = self.get_instance() {
+            // The instances are compared by class name.
+            if let Some(other_class) = other.get_instance() {
+                return self_class == other_class;
+            }
+        } else if let Some(self_value) = self.get_number_literal() {
+            if let Some(other_value) = other.get_number_literal() {
+                return self_value == other_value;
+            }
         } else if let Some(self_type) = self.get_option() {
             if let Some(other_type) = other.get_option() {
                 return self_type.node == other_type.node;
             }
+        } else if let Some(self_name) = self.get_parameter() {
+            if let Some(other_name) = other.get_parameter() {
+                return self_name == other_name;
+            }
+        } else if let Some((self_parameters, self_type)) =
+            self.get_parameterized()
+        {
+            if let Some((other_parameters, other_type)) =
+                other.get_parameterized()
+            {
+                return self_parameters.len() == other_parameters.len()
+                    && self_type.node == other_type.node;
+            }
+        } else if let Some(self_type) = self.get_spread() {
+            if let Some(other_type) = other.get_spread() {
+                return self_type.node == other_type.node;
+            }
+        } else if let Some(self_value) = self.get_string_literal() {
+            if let Some(other_value) = other.get_string_literal() {
+                return self_value == other_value;
+            }
+        } else if let Some(self_values) = self.get_tuple() {
+            if let Some(other_values) = other.get_tuple() {
+                return self_values.len() == other_values.len()
+                    && self_values.iter().zip(other_values.iter()).all(
+                        |(self_value, other_value)| {
+                            self_value.node == other_value.node
+                        },
+                    );
+            }
+        } else if let Some(self_types) = self.get_union() {
+            if let Some(other_types) = other.get_union() {
+                // The order of the members is not part of the data type.
+                return self_types.len() == other_types.len()
+                    && self_types.iter().all(|self_type| {
+                        other_types
+                            .iter()
+                            .any(|other_type| self_type.node == other_type.node)
+                    });
+            }
         }
 
         (self.is_boolean() && other.is_boolean())
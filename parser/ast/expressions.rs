@@ -1,12 +1,57 @@
 use crate::{
-    ast::{DataType, Node, Statement},
-    Token,
+    ast::{DataType, Node, Pattern, Statement},
+    OrderedMap, Token,
 };
-use std::{collections::HashMap, fmt};
+use std::fmt;
 
 pub type Expression = Node<Expressions>;
 
+/// Pattern and value of a match arm.
+pub type MatchArm = (Pattern, Expression);
+
+/// Get the call of the callee (a function name, a call or a method like
+/// `Math.sqrt`) with the value as its first argument.
+fn new_pipeline_call(
+    callee: &Expression,
+    value: Expression,
+) -> Option<Expression> {
+    let node = match &callee.node {
+        Expressions::Identifier(_) => {
+            Expressions::Call(Box::new(callee.clone()), vec![value])
+        }
+        Expressions::Call(identifier, arguments) => {
+            let mut arguments = arguments.clone();
+
+            arguments.insert(0, value);
+
+            Expressions::Call(identifier.clone(), arguments)
+        }
+        Expressions::Method(instance, member) => Expressions::Method(
+            instance.clone(),
+            Box::new(new_pipeline_call(member, value)?),
+        ),
+        Expressions::Group(callee) => {
+            return new_pipeline_call(callee, value);
+        }
+        _ => return None,
+    };
+
+    Some(Expression::new(callee.get_position(), node))
+}
+
 impl Expression {
+    /// Get the call expression of a pipeline (`value |> callee`), where the
+    /// value is passed as the first argument of the callee.
+    pub fn get_pipeline_call(&self) -> Option<Expression> {
+        let (value, operator, callee) = self.node.get_infix()?;
+
+        if operator != Token::VBarGreater {
+            return None;
+        }
+
+        new_pipeline_call(&callee, *value)
+    }
+
     pub fn to_string(&self) -> String {
         self.node.to_string()
     }
@@ -40,7 +85,7 @@ pub enum Expressions {
 
     Group(Box<Expression>),
 
-    HashMap(HashMap<String, Expression>),
+    HashMap(OrderedMap<Expression>),
 
     Identifier(String),
 
@@ -58,13 +103,41 @@ pub enum Expressions {
         right: Box<Expression>,
     },
 
+    Match {
+        value: Box<Expression>,
+        arms: Vec<MatchArm>,
+    },
+
     Method(Box<Expression>, Box<Expression>),
 
+    NamedArgument {
+        name: String,
+        value: Box<Expression>,
+    },
+
+    Null,
+
     Number(f64),
 
     Prefix(Token, Box<Expression>),
 
+    Spread(Box<Expression>),
+
     String(String),
+
+    Ternary {
+        condition: Box<Expression>,
+        consequence: Box<Expression>,
+        alternative: Box<Expression>,
+    },
+
+    Try {
+        body: Vec<Statement>,
+        error_name: String,
+        handler: Vec<Statement>,
+    },
+
+    Tuple(Vec<Expression>),
 }
 
 impl Expressions {
@@ -124,7 +197,7 @@ impl Expressions {
         }
     }
 
-    pub fn get_hashmap(&self) -> Option<HashMap<String, Expression>> {
+    pub fn get_hashmap(&self) -> Option<OrderedMap<Expression>> {
         match self {
             Self::HashMap(data) => Some(data.clone()),
             _ => None,
@@ -177,6 +250,13 @@ impl Expressions {
         }
     }
 
+    pub fn get_match(&self) -> Option<(Box<Expression>, Vec<MatchArm>)> {
+        match self {
+            Self::Match { value, arms } => Some((value.clone(), arms.clone())),
+            _ => None,
+        }
+    }
+
     pub fn get_method(&self) -> Option<(Box<Expression>, Box<Expression>)> {
         match self {
             Self::Method(identifier, property) => {
@@ -186,6 +266,19 @@ impl Expressions {
         }
     }
 
+    pub fn get_named_argument(&self) -> Option<(String, Box<Expression>)> {
+        match self {
+            Self::NamedArgument { name, value } => {
+                Some((name.clone(), value.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
     pub fn get_number(&self) -> Option<f64> {
         match self {
             Self::Number(value) => Some(value.clone()),
@@ -202,6 +295,13 @@ impl Expressions {
         }
     }
 
+    pub fn get_spread(&self) -> Option<Box<Expression>> {
+        match self {
+            Self::Spread(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
     pub fn get_string(&self) -> Option<String> {
         match self {
             Self::String(value) => Some(value.clone()),
@@ -209,6 +309,41 @@ impl Expressions {
         }
     }
 
+    pub fn get_ternary(
+        &self,
+    ) -> Option<(Box<Expression>, Box<Expression>, Box<Expression>)> {
+        match self {
+            Self::Ternary {
+                condition,
+                consequence,
+                alternative,
+            } => Some((
+                condition.clone(),
+                consequence.clone(),
+                alternative.clone(),
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn get_try(&self) -> Option<(Vec<Statement>, String, Vec<Statement>)> {
+        match self {
+            Self::Try {
+                body,
+                error_name,
+                handler,
+            } => Some((body.clone(), error_name.clone(), handler.clone())),
+            _ => None,
+        }
+    }
+
+    pub fn get_tuple(&self) -> Option<Vec<Expression>> {
+        match self {
+            Self::Tuple(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+
     pub fn to_string(&self) -> String {
         match self {
             Self::Array(values) => format!(
@@ -248,13 +383,9 @@ impl Expressions {
                     .join(", ")
             ),
             Self::Group(value) => format!("({})", value),
-            Self::HashMap(data) => format!(
-                "{{\n{}\n}}",
-                data.iter()
-                    .map(|(key, value)| format!("{}: {}", key, value))
-                    .collect::<Vec<String>>()
-                    .join(",\n")
-            ),
+            Self::HashMap(data) => {
+                data.to_pretty_string(|value| value.to_string())
+            }
             Self::Identifier(value) => value.clone(),
             Self::If {
                 condition,
@@ -289,12 +420,55 @@ impl Expressions {
                 operator,
                 right,
             } => format!("{} {} {}", left, operator, right),
+            Self::Match { value, arms } => format!(
+                "match ({}) {{\n{}\n}}",
+                value,
+                arms.iter()
+                    .map(|(pattern, arm)| format!("{} => {}", pattern, arm))
+                    .collect::<Vec<String>>()
+                    .join(",\n")
+            ),
             Self::Method(identifier, property) => {
                 format!("{}.{}", identifier, property)
             }
+            Self::NamedArgument { name, value } => {
+                format!("{}: {}", name, value)
+            }
+            Self::Null => String::from("null"),
             Self::Number(_) => String::from("Number"),
             Self::Prefix(operator, value) => format!("{}{}", operator, value),
+            Self::Spread(value) => format!("...{}", value),
             Self::String(_) => String::from("String"),
+            Self::Ternary {
+                condition,
+                consequence,
+                alternative,
+            } => format!("{} ? {} : {}", condition, consequence, alternative),
+            Self::Try {
+                body,
+                error_name,
+                handler,
+            } => format!(
+                "try {{\n{}\n}} catch ({}) {{\n{}\n}}",
+                body.iter()
+                    .map(|stmt| stmt.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+                error_name,
+                handler
+                    .iter()
+                    .map(|stmt| stmt.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            Self::Tuple(values) => format!(
+                "({})",
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
         }
     }
 }
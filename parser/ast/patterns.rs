@@ -0,0 +1,118 @@
+use crate::ast::Node;
+use std::fmt;
+
+pub type Pattern = Node<Patterns>;
+
+impl Pattern {
+    pub fn to_string(&self) -> String {
+        self.node.to_string()
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Patterns {
+    Array(Vec<Pattern>),
+    EnumVariant(String, Vec<Pattern>),
+    HashMap(Vec<(String, Pattern)>),
+    Identifier(String),
+    Rest(String),
+    Tuple(Vec<Pattern>),
+}
+
+impl Patterns {
+    pub fn get_array(&self) -> Option<Vec<Pattern>> {
+        match self {
+            Self::Array(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_enum_variant(&self) -> Option<(String, Vec<Pattern>)> {
+        match self {
+            Self::EnumVariant(name, values) => {
+                Some((name.clone(), values.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_hashmap(&self) -> Option<Vec<(String, Pattern)>> {
+        match self {
+            Self::HashMap(data) => Some(data.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_identifier(&self) -> Option<String> {
+        match self {
+            Self::Identifier(name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_rest(&self) -> Option<String> {
+        match self {
+            Self::Rest(name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_tuple(&self) -> Option<Vec<Pattern>> {
+        match self {
+            Self::Tuple(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Patterns {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            Self::Array(values) => format!(
+                "[{}]",
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::EnumVariant(name, values) => format!(
+                "{}({})",
+                name,
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::HashMap(data) => format!(
+                "{{ {} }}",
+                data.iter()
+                    .map(|(key, value)| match value.node.get_identifier() {
+                        Some(name) if &name == key => name,
+                        _ => format!("{}: {}", key, value),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::Identifier(name) => name.clone(),
+            Self::Rest(name) => format!("...{}", name),
+            Self::Tuple(values) => format!(
+                "({})",
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        };
+
+        write!(f, "{}", value)
+    }
+}
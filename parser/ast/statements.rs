@@ -1,8 +1,60 @@
-use crate::ast::{DataType, Expression, Node};
+use crate::ast::{DataType, Expression, Node, Pattern};
 use std::fmt;
 
 pub type Statement = Node<Statements>;
 
+/// Name and arguments of an enum variant.
+pub type Variant = (String, Vec<Expression>);
+
+/// Visibility (`true` when it is public) and argument of a class field.
+pub type ClassField = (bool, Expression);
+
+/// Visibility (`true` when it is public) and function of a class method.
+pub type ClassMethod = (bool, Statement);
+
+/// Name, fields, constructor and methods of a class.
+pub type ClassParts = (
+    String,
+    Vec<ClassField>,
+    Option<Box<Statement>>,
+    Vec<ClassMethod>,
+);
+
+/// Name, type parameters, extended interfaces, properties and methods of an
+/// interface.
+pub type InterfaceParts = (
+    String,
+    Vec<DataType>,
+    Vec<DataType>,
+    Vec<Expression>,
+    Vec<Expression>,
+);
+
+/// Get the visibility keyword of a class member.
+fn visibility_to_string(is_public: bool) -> &'static str {
+    if is_public {
+        "public"
+    } else {
+        "private"
+    }
+}
+
+/// Get the type parameters as a string like `<T, U>`.
+fn type_parameters_to_string(type_parameters: &[DataType]) -> String {
+    if type_parameters.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        "<{}>",
+        type_parameters
+            .iter()
+            .map(|type_parameter| type_parameter.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
 impl Statement {
     pub fn to_string(&self) -> String {
         self.node.to_string()
@@ -17,28 +69,74 @@ impl fmt::Display for Statement {
 
 #[derive(Clone, Debug)]
 pub enum Statements {
+    Class {
+        name: String,
+        fields: Vec<ClassField>,
+        constructor: Option<Box<Statement>>,
+        methods: Vec<ClassMethod>,
+    },
+
+    Enum(String, Vec<Variant>),
+
     Expression(Box<Expression>),
 
     Function {
         name: Box<Expression>,
+        type_parameters: Vec<DataType>,
         arguments: Vec<Expression>,
         return_type: Box<DataType>,
         body: Vec<Statement>,
     },
 
-    Interface(String, Vec<Expression>),
+    Impl(DataType, Vec<Statement>),
+
+    Interface {
+        name: String,
+        type_parameters: Vec<DataType>,
+        extends: Vec<DataType>,
+        properties: Vec<Expression>,
+        methods: Vec<Expression>,
+    },
 
     Return(Option<Expression>),
 
+    TypeAlias(String, DataType),
+
     Variable {
         is_mutable: bool,
-        name: String,
+        pattern: Pattern,
         data_type: Option<DataType>,
         value: Option<Expression>,
     },
 }
 
 impl Statements {
+    pub fn get_class(&self) -> Option<ClassParts> {
+        match self {
+            Self::Class {
+                name,
+                fields,
+                constructor,
+                methods,
+            } => Some((
+                name.clone(),
+                fields.clone(),
+                constructor.clone(),
+                methods.clone(),
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn get_enum(&self) -> Option<(String, Vec<Variant>)> {
+        match self {
+            Self::Enum(name, variants) => {
+                Some((name.clone(), variants.clone()))
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_expression(&self) -> Option<Box<Expression>> {
         match self {
             Self::Expression(value) => Some(value.clone()),
@@ -50,6 +148,7 @@ impl Statements {
         &self,
     ) -> Option<(
         Box<Expression>,
+        Vec<DataType>,
         Vec<Expression>,
         Box<DataType>,
         Vec<Statement>,
@@ -57,11 +156,13 @@ impl Statements {
         match self {
             Self::Function {
                 name,
+                type_parameters,
                 arguments,
                 return_type,
                 body,
             } => Some((
                 name.clone(),
+                type_parameters.clone(),
                 arguments.clone(),
                 return_type.clone(),
                 body.clone(),
@@ -70,15 +171,34 @@ impl Statements {
         }
     }
 
-    pub fn get_interface(&self) -> Option<(String, Vec<Expression>)> {
+    pub fn get_impl(&self) -> Option<(DataType, Vec<Statement>)> {
         match self {
-            Self::Interface(name, properties) => {
-                Some((name.clone(), properties.clone()))
+            Self::Impl(data_type, methods) => {
+                Some((data_type.clone(), methods.clone()))
             }
             _ => None,
         }
     }
 
+    pub fn get_interface(&self) -> Option<InterfaceParts> {
+        match self {
+            Self::Interface {
+                name,
+                type_parameters,
+                extends,
+                properties,
+                methods,
+            } => Some((
+                name.clone(),
+                type_parameters.clone(),
+                extends.clone(),
+                properties.clone(),
+                methods.clone(),
+            )),
+            _ => None,
+        }
+    }
+
     pub fn get_return(&self) -> Option<Option<Expression>> {
         match self {
             Self::Return(value) => Some(value.clone()),
@@ -86,18 +206,27 @@ impl Statements {
         }
     }
 
+    pub fn get_type_alias(&self) -> Option<(String, DataType)> {
+        match self {
+            Self::TypeAlias(name, data_type) => {
+                Some((name.clone(), data_type.clone()))
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_variable(
         &self,
-    ) -> Option<(bool, String, Option<DataType>, Option<Expression>)> {
+    ) -> Option<(bool, Pattern, Option<DataType>, Option<Expression>)> {
         match self {
             Self::Variable {
                 is_mutable,
-                name,
+                pattern,
                 data_type,
                 value,
             } => Some((
                 is_mutable.clone(),
-                name.clone(),
+                pattern.clone(),
                 data_type.clone(),
                 value.clone(),
             )),
@@ -107,15 +236,68 @@ impl Statements {
 
     pub fn to_string(&self) -> String {
         match self {
+            Self::Class {
+                name,
+                fields,
+                constructor,
+                methods,
+            } => format!(
+                "class {} {{\n{}\n}}",
+                name,
+                fields
+                    .iter()
+                    .map(|(is_public, field)| format!(
+                        "{} {};",
+                        visibility_to_string(*is_public),
+                        field
+                    ))
+                    .chain(
+                        constructor
+                            .iter()
+                            .map(|constructor| { constructor.to_string() })
+                    )
+                    .chain(methods.iter().map(|(is_public, method)| {
+                        format!(
+                            "{} {}",
+                            visibility_to_string(*is_public),
+                            method
+                        )
+                    }))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            Self::Enum(name, variants) => format!(
+                "enum {} {{\n{}\n}}",
+                name,
+                variants
+                    .iter()
+                    .map(|(variant, arguments)| if arguments.is_empty() {
+                        variant.clone()
+                    } else {
+                        format!(
+                            "{}({})",
+                            variant,
+                            arguments
+                                .iter()
+                                .map(|arg| arg.to_string())
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",\n"),
+            ),
             Self::Expression(value) => value.to_string(),
             Self::Function {
                 name,
+                type_parameters,
                 arguments,
                 return_type,
                 body,
             } => format!(
-                "func {} ({}): {} {{\n{}\n}}",
+                "func {}{} ({}): {} {{\n{}\n}}",
                 name,
+                type_parameters_to_string(type_parameters),
                 arguments
                     .iter()
                     .map(|arg| arg.to_string())
@@ -127,11 +309,40 @@ impl Statements {
                     .collect::<Vec<String>>()
                     .join("\n"),
             ),
-            Self::Interface(name, properties) => format!(
-                "interface {} {{\n{}\n}}",
+            Self::Impl(data_type, methods) => format!(
+                "impl {} {{\n{}\n}}",
+                data_type,
+                methods
+                    .iter()
+                    .map(|method| method.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            Self::Interface {
+                name,
+                type_parameters,
+                extends,
+                properties,
+                methods,
+            } => format!(
+                "interface {}{}{} {{\n{}\n}}",
                 name,
+                type_parameters_to_string(type_parameters),
+                if extends.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        " extends {}",
+                        extends
+                            .iter()
+                            .map(|parent| parent.to_string())
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                },
                 properties
                     .iter()
+                    .chain(methods.iter())
                     .map(|prop| prop.to_string())
                     .collect::<Vec<String>>()
                     .join(";\n"),
@@ -143,15 +354,18 @@ impl Statements {
                     None => String::new(),
                 },
             ),
+            Self::TypeAlias(name, data_type) => {
+                format!("type {} = {};", name, data_type)
+            }
             Self::Variable {
                 is_mutable,
-                name,
+                pattern,
                 data_type,
                 value,
             } => format!(
                 "{} {}{}{};",
                 if *is_mutable { "let" } else { "const" },
-                name,
+                pattern,
                 match data_type {
                     Some(data_type) => format!(": {}", data_type),
                     None => String::new(),
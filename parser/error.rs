@@ -9,14 +9,30 @@ use codespan_reporting::term::{
 
 #[derive(Clone, Debug)]
 pub enum ErrorType {
+    CannotInfer(String),
+    ConflictingProperty(String, String, String),
+    DuplicateArgument(String, Position),
+    Exit(i32),
     ExpectArguments(usize, usize),
+    ExpectLiteral(Vec<String>, String),
     ExpectToken(String, String),
     ExpectType(String, String),
+    InvalidArgument(String),
     Lexical(String),
-    NameInUse(String, Position),
+    MissingArgument(String),
+    MissingVariants(String, Vec<String>),
+    NameInUse(String, Option<Position>),
+    PrivateMember(String, String),
+    Runtime(String),
+    Semantic(String),
+    UninitializedField(String),
+    UnknownArgument(String),
     UnknownIdentifier(String),
     UnknownPosition(Position),
+    UnknownProperty(String),
     UnknownToken,
+    UnknownVariant(String, String),
+    UnsatisfiedConstraint(String, String, String),
 }
 
 #[derive(Clone, Debug)]
@@ -57,6 +73,121 @@ impl Error {
         }
     }
 
+    /// Create a new cannot infer error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("const item = first([]);"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(13, 22, 1, 14);
+    ///
+    ///     let error = Error::new_cannot_infer(error_position, String::from("T"));
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_cannot_infer(position: Position, name: String) -> Self {
+        Self::new(position, ErrorType::CannotInfer(name))
+    }
+
+    /// Create a new conflicting property error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("interface Label extends Named { name: number; }"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(33, 45, 1, 34);
+    ///
+    ///     let error = Error::new_conflicting_property(
+    ///         error_position,
+    ///         String::from("name"),
+    ///         "string",
+    ///         "number",
+    ///     );
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_conflicting_property(
+        position: Position,
+        name: String,
+        first_type: &str,
+        second_type: &str,
+    ) -> Self {
+        Self::new(
+            position,
+            ErrorType::ConflictingProperty(
+                name,
+                String::from(first_type),
+                String::from(second_type),
+            ),
+        )
+    }
+
+    /// Create a new duplicate argument error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("greet('Sflyn', name: 'Sflyn')"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(15, 19, 1, 16);
+    ///     let last_position = Position::new(6, 13, 1, 7);
+    ///
+    ///     let error = Error::new_duplicate_argument(error_position, String::from("name"), last_position);
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_duplicate_argument(
+        position: Position,
+        name: String,
+        last_position: Position,
+    ) -> Self {
+        Self::new(position, ErrorType::DuplicateArgument(name, last_position))
+    }
+
+    /// Create a new exit error to stop the program with the exit code (it
+    /// cannot be caught).
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("process.exit(2)"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(0, 15, 1, 1);
+    ///
+    ///     let error = Error::new_exit(error_position, 2);
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_exit(position: Position, code: i32) -> Self {
+        Self::new(position, ErrorType::Exit(code))
+    }
+
     /// Create a new expect arguments length error.
     ///
     /// # Example
@@ -84,6 +215,40 @@ impl Error {
         Self::new(position, ErrorType::ExpectArguments(expect, got))
     }
 
+    /// Create a new expect literal error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("const method: Method = 'PUT';"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(23, 28, 1, 24);
+    ///
+    ///     let error = Error::new_expect_literal(
+    ///         error_position,
+    ///         vec![String::from("\"GET\""), String::from("\"POST\"")],
+    ///         "\"PUT\"",
+    ///     );
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_expect_literal(
+        position: Position,
+        literals: Vec<String>,
+        got: &str,
+    ) -> Self {
+        Self::new(
+            position,
+            ErrorType::ExpectLiteral(literals, got.to_string()),
+        )
+    }
+
     /// Create a new expect token error.
     ///
     /// # Example
@@ -144,6 +309,31 @@ impl Error {
         )
     }
 
+    /// Create a new invalid argument error, for the arguments that are not
+    /// allowed in their place.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("greet(name: 'Sflyn', 'Hi')"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(21, 25, 1, 22);
+    ///     let error_message = "Positional arguments cannot follow named arguments.";
+    ///
+    ///     let error = Error::new_invalid_argument(error_position, error_message);
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_invalid_argument(position: Position, message: &str) -> Self {
+        Self::new(position, ErrorType::InvalidArgument(message.to_string()))
+    }
+
     /// Create a new lexical error.
     ///
     /// # Example
@@ -168,6 +358,60 @@ impl Error {
         Self::new(position, ErrorType::Lexical(message.to_string()))
     }
 
+    /// Create a new missing argument error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("greet(greeting: 'Hi')"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(0, 21, 1, 1);
+    ///
+    ///     let error = Error::new_missing_argument(error_position, String::from("name"));
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_missing_argument(position: Position, name: String) -> Self {
+        Self::new(position, ErrorType::MissingArgument(name))
+    }
+
+    /// Create a new missing variants error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("match (shape) { Circle(r) => r }"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(0, 32, 1, 1);
+    ///
+    ///     let error = Error::new_missing_variants(
+    ///         error_position,
+    ///         String::from("Shape"),
+    ///         vec![String::from("Rect")],
+    ///     );
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_missing_variants(
+        position: Position,
+        enum_name: String,
+        variants: Vec<String>,
+    ) -> Self {
+        Self::new(position, ErrorType::MissingVariants(enum_name, variants))
+    }
+
     /// Create a new name in use error.
     ///
     /// # Example
@@ -183,7 +427,7 @@ impl Error {
     ///     let error_position = Position::new(23, 27, 2, 5);
     ///     let last_position = Position::new(4, 8, 1, 5);
     ///
-    ///     let error = Error::new_name_in_use(error_position, String::from("name"), last_position);
+    ///     let error = Error::new_name_in_use(error_position, String::from("name"), Some(last_position));
     ///
     ///     error.show(&file);
     /// }
@@ -191,11 +435,138 @@ impl Error {
     pub fn new_name_in_use(
         position: Position,
         name: String,
-        last_position: Position,
+        last_position: Option<Position>,
     ) -> Self {
         Self::new(position, ErrorType::NameInUse(name, last_position))
     }
 
+    /// Create a new private member error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("counter.count"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(8, 13, 1, 9);
+    ///
+    ///     let error = Error::new_private_member(
+    ///         error_position,
+    ///         String::from("Counter"),
+    ///         String::from("count"),
+    ///     );
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_private_member(
+        position: Position,
+        class_name: String,
+        member: String,
+    ) -> Self {
+        Self::new(position, ErrorType::PrivateMember(class_name, member))
+    }
+
+    /// Create a new runtime error, found while evaluating the program (it can
+    /// be caught).
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("fs.readFile('missing.txt')"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(12, 25, 1, 13);
+    ///     let error_message = "The `missing.txt` file does not exist.";
+    ///
+    ///     let error = Error::new_runtime(error_position, error_message);
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_runtime(position: Position, message: &str) -> Self {
+        Self::new(position, ErrorType::Runtime(message.to_string()))
+    }
+
+    /// Create a new semantic error, for the well-formed code that the
+    /// typechecker cannot accept.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(String::from("test.sf"), String::from("10 = 5;"));
+    ///
+    ///     let error_position = Position::new(0, 2, 1, 1);
+    ///     let error_message = "Only the class fields can be assigned.";
+    ///
+    ///     let error = Error::new_semantic(error_position, error_message);
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_semantic(position: Position, message: &str) -> Self {
+        Self::new(position, ErrorType::Semantic(message.to_string()))
+    }
+
+    /// Create a new uninitialized field error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("class Counter {\n  count: number;\n}"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(18, 31, 2, 3);
+    ///
+    ///     let error = Error::new_uninitialized_field(
+    ///         error_position,
+    ///         String::from("count"),
+    ///     );
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_uninitialized_field(position: Position, field: String) -> Self {
+        Self::new(position, ErrorType::UninitializedField(field))
+    }
+
+    /// Create a new unknown argument error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("greet(nmae: 'Sflyn')"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(6, 10, 1, 7);
+    ///
+    ///     let error = Error::new_unknown_argument(error_position, String::from("nmae"));
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_unknown_argument(position: Position, name: String) -> Self {
+        Self::new(position, ErrorType::UnknownArgument(name))
+    }
+
     /// Create a new unknown identifier error.
     ///
     /// # Example
@@ -245,6 +616,29 @@ impl Error {
         )
     }
 
+    /// Create a new unknown property error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("const { sizee } = label;"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(8, 5, 1, 9);
+    ///
+    ///     let error = Error::new_unknown_property(error_position, String::from("sizee"));
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_unknown_property(position: Position, name: String) -> Self {
+        Self::new(position, ErrorType::UnknownProperty(name))
+    }
+
     /// Create a new unknown token error.
     ///
     /// # Example
@@ -268,6 +662,77 @@ impl Error {
         Self::new(position, ErrorType::UnknownToken)
     }
 
+    /// Create a new unknown variant error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("match (shape) { Square(size) => size }"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(16, 28, 1, 17);
+    ///
+    ///     let error = Error::new_unknown_variant(
+    ///         error_position,
+    ///         String::from("Shape"),
+    ///         String::from("Square"),
+    ///     );
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_unknown_variant(
+        position: Position,
+        enum_name: String,
+        variant: String,
+    ) -> Self {
+        Self::new(position, ErrorType::UnknownVariant(enum_name, variant))
+    }
+
+    /// Create a new unsatisfied constraint error.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sflynlang_parser::{Error, File, Position};
+    ///
+    /// fn main() {
+    ///     let file = File::new(
+    ///         String::from("test.sf"),
+    ///         String::from("show(10);"),
+    ///     );
+    ///
+    ///     let error_position = Position::new(5, 7, 1, 6);
+    ///
+    ///     let error = Error::new_unsatisfied_constraint(
+    ///         error_position,
+    ///         String::from("T"),
+    ///         "Printable",
+    ///         "number",
+    ///     );
+    ///
+    ///     error.show(&file);
+    /// }
+    /// ```
+    pub fn new_unsatisfied_constraint(
+        position: Position,
+        name: String,
+        constraint: &str,
+        got: &str,
+    ) -> Self {
+        Self::new(
+            position,
+            ErrorType::UnsatisfiedConstraint(
+                name,
+                String::from(constraint),
+                String::from(got),
+            ),
+        )
+    }
+
     /// Get the position object of the error.
     pub fn get_position(&self) -> Position {
         self.position.clone()
@@ -278,11 +743,77 @@ impl Error {
         self.error_type.clone()
     }
 
+    /// Get the message of the error (the one of its primary label), like the
+    /// message received by a `catch` block.
+    pub fn get_message(&self) -> String {
+        self.to_diagnostic()
+            .labels
+            .first()
+            .map(|label| label.message.clone())
+            .unwrap_or_default()
+    }
+
     /// Convert the error to a diagnostic object.
     ///
     /// Read more about the Diagnostic object [clicking here](https://docs.rs/codespan-reporting/0.9.5/codespan_reporting/diagnostic/struct.Diagnostic.html).
     pub fn to_diagnostic(&self) -> Diagnostic<()> {
         match self.get_error_type() {
+            // Get the cannot infer error.
+            ErrorType::CannotInfer(name) => Diagnostic::error()
+                .with_message("Cannot infer data type")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "Cannot infer the `{}` type parameter from the arguments.",
+                    name
+                ))]),
+
+            // Get the conflicting property error.
+            ErrorType::ConflictingProperty(name, first_type, second_type) => {
+                Diagnostic::error()
+                    .with_message("Conflicting property")
+                    .with_labels(vec![Label::primary(
+                        (),
+                        self.get_position().get_range(),
+                    )
+                    .with_message(format!(
+                        "The `{}` property cannot be `{}` and `{}` at once.",
+                        name, first_type, second_type
+                    ))])
+            }
+
+            // Get the duplicate argument error.
+            ErrorType::DuplicateArgument(name, last_position) => {
+                Diagnostic::error()
+                    .with_message("Duplicate argument")
+                    .with_labels(vec![
+                        Label::primary((), self.get_position().get_range())
+                            .with_message(format!(
+                                "The `{}` argument is already provided.",
+                                name
+                            )),
+                        Label::secondary((), last_position.get_range())
+                            .with_message(format!(
+                                "The `{}` argument is provided here.",
+                                name
+                            )),
+                    ])
+            }
+
+            // Get the exit error.
+            ErrorType::Exit(code) => Diagnostic::error()
+                .with_message("Exit")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "The program exits with the `{}` code.",
+                    code
+                ))]),
+
             // Get the expect arguments error.
             ErrorType::ExpectArguments(expected, got) => Diagnostic::error()
                 .with_message("Expected arguments")
@@ -295,6 +826,23 @@ impl Error {
                     expected, got
                 ))]),
 
+            // Get the expect literal error.
+            ErrorType::ExpectLiteral(literals, got) => Diagnostic::error()
+                .with_message("Expected literal")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "Expect one of {}, got `{}` instead.",
+                    literals
+                        .iter()
+                        .map(|literal| format!("`{}`", literal))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    got
+                ))]),
+
             // Get the expect token error.
             ErrorType::ExpectToken(expected, got) => Diagnostic::error()
                 .with_message("Expected token")
@@ -319,6 +867,15 @@ impl Error {
                     expected, got
                 ))]),
 
+            // Get the invalid argument error.
+            ErrorType::InvalidArgument(message) => Diagnostic::error()
+                .with_message("Invalid argument")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(message)]),
+
             // Get the lexical error.
             ErrorType::Lexical(message) => Diagnostic::error()
                 .with_message("Lexical")
@@ -328,21 +885,124 @@ impl Error {
                 )
                 .with_message(message)]),
 
+            // Get the missing argument error.
+            ErrorType::MissingArgument(name) => Diagnostic::error()
+                .with_message("Missing argument")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "The `{}` argument is required.",
+                    name
+                ))]),
+
+            // Get the missing variants error.
+            ErrorType::MissingVariants(enum_name, variants) => {
+                Diagnostic::error()
+                    .with_message("Non-exhaustive match")
+                    .with_labels(vec![Label::primary(
+                        (),
+                        self.get_position().get_range(),
+                    )
+                    .with_message(format!(
+                        "The {} {} of `{}` {} not handled.",
+                        variants
+                            .iter()
+                            .map(|variant| format!("`{}`", variant))
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                        if variants.len() == 1 {
+                            "variant"
+                        } else {
+                            "variants"
+                        },
+                        enum_name,
+                        if variants.len() == 1 { "is" } else { "are" }
+                    ))])
+            }
+
             // Get the name in use error.
-            ErrorType::NameInUse(name, last_position) => Diagnostic::error()
-                .with_message("The identifier is already in use")
-                .with_labels(vec![
-                    Label::primary((), self.get_position().get_range())
-                        .with_message(format!(
-                            "The `{}` identifier is already in use.",
-                            name
-                        )),
-                    Label::secondary((), last_position.get_range())
-                        .with_message(format!(
-                            "The `{}` identifier is used here.",
-                            name
-                        )),
-                ]),
+            ErrorType::NameInUse(name, last_position) => {
+                let mut labels = vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "The `{}` identifier is already in use.",
+                    name
+                ))];
+
+                // Builtin names have no position to point at.
+                if let Some(last_position) = last_position {
+                    labels.push(
+                        Label::secondary((), last_position.get_range())
+                            .with_message(format!(
+                                "The `{}` identifier is used here.",
+                                name
+                            )),
+                    );
+                }
+
+                Diagnostic::error()
+                    .with_message("The identifier is already in use")
+                    .with_labels(labels)
+            }
+
+            // Get the private member error.
+            ErrorType::PrivateMember(class_name, member) => Diagnostic::error()
+                .with_message("Private member")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "The `{}` member of the `{}` class is private.",
+                    member, class_name
+                ))]),
+
+            // Get the runtime error.
+            ErrorType::Runtime(message) => Diagnostic::error()
+                .with_message("Runtime")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(message)]),
+
+            // Get the semantic error.
+            ErrorType::Semantic(message) => Diagnostic::error()
+                .with_message("Semantic")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(message)]),
+
+            // Get the uninitialized field error.
+            ErrorType::UninitializedField(field) => Diagnostic::error()
+                .with_message("Uninitialized field")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "The `{}` field must have a value or be assigned in the \
+                     constructor.",
+                    field
+                ))]),
+
+            // Get the unknown argument error.
+            ErrorType::UnknownArgument(name) => Diagnostic::error()
+                .with_message("Unknown argument")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "The function does not have a `{}` argument.",
+                    name
+                ))]),
 
             // Get the unknown identifier error.
             ErrorType::UnknownIdentifier(name) => Diagnostic::error()
@@ -368,6 +1028,18 @@ impl Error {
                     position
                 ))]),
 
+            // Get the unknown property error.
+            ErrorType::UnknownProperty(name) => Diagnostic::error()
+                .with_message("Unknown property")
+                .with_labels(vec![Label::primary(
+                    (),
+                    self.get_position().get_range(),
+                )
+                .with_message(format!(
+                    "The value does not have a `{}` property.",
+                    name
+                ))]),
+
             // Get the unknown token error.
             ErrorType::UnknownToken => Diagnostic::error()
                 .with_message("Unknown token")
@@ -376,6 +1048,34 @@ impl Error {
                     self.get_position().get_range(),
                 )
                 .with_message("Cannot recognize this token.")]),
+
+            // Get the unknown variant error.
+            ErrorType::UnknownVariant(enum_name, variant) => {
+                Diagnostic::error()
+                    .with_message("Unknown variant")
+                    .with_labels(vec![Label::primary(
+                        (),
+                        self.get_position().get_range(),
+                    )
+                    .with_message(format!(
+                        "The `{}` enum does not have a `{}` variant.",
+                        enum_name, variant
+                    ))])
+            }
+
+            // Get the unsatisfied constraint error.
+            ErrorType::UnsatisfiedConstraint(name, constraint, got) => {
+                Diagnostic::error()
+                    .with_message("Unsatisfied constraint")
+                    .with_labels(vec![Label::primary(
+                        (),
+                        self.get_position().get_range(),
+                    )
+                    .with_message(format!(
+                        "The `{}` type parameter must extend `{}`, got `{}` instead.",
+                        name, constraint, got
+                    ))])
+            }
         }
     }
 
@@ -4,6 +4,8 @@ use crate::{Error, Position, Tok, Token};
 pub struct Lexer {
     content: String,
 
+    previous_character: Option<char>,
+
     current_line: usize,
     current_column: usize,
     current_position: usize,
@@ -20,6 +22,8 @@ impl Lexer {
         let mut lexer = Self {
             content,
 
+            previous_character: None,
+
             current_line: 1,
             current_column: 1,
             current_position: 0,
@@ -50,6 +54,9 @@ impl Lexer {
         // Get the current character.
         let current_character = self.current_character;
 
+        // Replace the previous character with the current character.
+        self.previous_character = current_character;
+
         // Replace the current character with the next character.
         self.current_character = self.next_character;
 
@@ -105,9 +112,30 @@ impl Lexer {
         // Initialize an empty value.
         let mut value = String::new();
 
-        while self.is_number_begin() {
-            // Append the current character to the value and read the next character.
-            value.push(self.read_next_character().unwrap());
+        // Check if the number is a tuple index (like `pair.0`), which cannot
+        // have decimals.
+        let is_tuple_index = self.previous_character == Some('.');
+
+        loop {
+            if self.is_number_begin() {
+                // Append the current character to the value and read the next character.
+                value.push(self.read_next_character().unwrap());
+                continue;
+            }
+
+            // Check if the current character is the decimal point of the number.
+            if !is_tuple_index
+                && !value.contains('.')
+                && self.current_character == Some('.')
+                && matches!(self.next_character, Some(next) if next.is_numeric())
+            {
+                // Append the decimal point to the value and read the next character.
+                value.push(self.read_next_character().unwrap());
+                continue;
+            }
+
+            // End the loop.
+            break;
         }
 
         if let Ok(value) = value.parse::<f64>() {
@@ -205,11 +233,35 @@ impl Lexer {
 
         // Get the current character.
         match self.current_character {
-            // Check if the current character is a dot.
-            Some('.') => {
-                // Set the token as a dot.
-                token = Token::Dot;
-            }
+            // Check if the current character is a dot and get the next character.
+            Some('.') => match self.next_character {
+                // Check if the next character is a dot.
+                Some('.') => {
+                    // Read the next character.
+                    self.read_next_character();
+
+                    // Check if the next character is not a dot.
+                    if self.next_character != Some('.') {
+                        // Return an error.
+                        return Err(Error::new_lexical(
+                            position,
+                            "Maybe do you wish to use `...` instead of `..`?",
+                        ));
+                    }
+
+                    // Read the next character.
+                    self.read_next_character();
+
+                    // Set the token as a triple dot.
+                    token = Token::TripleDot;
+                }
+
+                // Is other character.
+                _ => {
+                    // Set the token as a dot.
+                    token = Token::Dot;
+                }
+            },
 
             // Check if the current character is a comma.
             Some(',') => {
@@ -229,6 +281,12 @@ impl Lexer {
                 token = Token::Semicolon;
             }
 
+            // Check if the current character is a question.
+            Some('?') => {
+                // Set the token as a question.
+                token = Token::Question;
+            }
+
             // Check if the current character is an equal and get the next character.
             Some('=') => match self.next_character {
                 // Check if the next character is an equal.
@@ -471,13 +529,19 @@ impl Lexer {
                     token = Token::DoubleVBar;
                 }
 
+                // Check if the next character is a greater.
+                Some('>') => {
+                    // Read the next character.
+                    self.read_next_character();
+
+                    // Set the token as a vertical bar greater.
+                    token = Token::VBarGreater;
+                }
+
                 // Is other character.
                 _ => {
-                    // Return an error.
-                    return Err(Error::new_lexical(
-                        position,
-                        "Maybe do you wish to use `||` instead of `|`?",
-                    ));
+                    // Set the token as a vertical bar.
+                    token = Token::VBar;
                 }
             },
 
@@ -599,7 +663,7 @@ fn test_lexer() {
         format!(
             "{}\n{}",
             "identifier 'string' \"string\" 10 let const func return if else",
-            ". , : ; = == ! != + += - -= * *= ** **= / /= % %= < <= > >= () {} [] || && =>"
+            ". , : ; = == ! != + += - -= * *= ** **= / /= % %= < <= > >= () {} [] || && => ... ? |> |"
         ),
     );
 
@@ -675,7 +739,11 @@ fn test_lexer() {
         is_valid_token!(41, 131, 2, 2, 70, Token::DoubleVBar);
         is_valid_token!(42, 134, 2, 2, 73, Token::DoubleAmper);
         is_valid_token!(43, 137, 2, 2, 76, Token::EqualGreater);
-        is_valid_token!(44, 139, 1, 2, 78, Token::EndOfFile);
+        is_valid_token!(44, 140, 3, 2, 79, Token::TripleDot);
+        is_valid_token!(45, 144, 1, 2, 83, Token::Question);
+        is_valid_token!(46, 146, 2, 2, 85, Token::VBarGreater);
+        is_valid_token!(47, 149, 1, 2, 88, Token::VBar);
+        is_valid_token!(48, 150, 1, 2, 89, Token::EndOfFile);
     }
     // Does not have tokens.
     else if let Err(error) = lexer_run {
@@ -683,3 +751,45 @@ fn test_lexer() {
         panic!("The file does not have tokens.");
     }
 }
+
+#[test]
+fn test_numbers() {
+    use crate::{Lexer, Token};
+
+    let mut lexer =
+        Lexer::new(String::from("0 2.25 3. 1.5.5 pair.0 pair.1.2 .5"));
+    let tokens: Vec<Token> = lexer
+        .run()
+        .unwrap()
+        .iter()
+        .map(|tok| tok.get_token())
+        .collect();
+
+    let pair = || Token::Identifier(String::from("pair"));
+
+    // The tuple indexes and the numbers without decimal digits after the point
+    // do not read decimals.
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Num(0.0),
+            Token::Num(2.25),
+            Token::Num(3.0),
+            Token::Dot,
+            Token::Num(1.5),
+            Token::Dot,
+            Token::Num(5.0),
+            pair(),
+            Token::Dot,
+            Token::Num(0.0),
+            pair(),
+            Token::Dot,
+            Token::Num(1.0),
+            Token::Dot,
+            Token::Num(2.0),
+            Token::Dot,
+            Token::Num(5.0),
+            Token::EndOfFile,
+        ]
+    );
+}
@@ -1,6 +1,7 @@
 pub mod ast;
 mod error;
 mod lexer;
+mod ordered_map;
 pub mod parser;
 mod position;
 mod precedence;
@@ -8,6 +9,7 @@ mod token;
 
 pub use error::{Error, ErrorType};
 pub use lexer::Lexer;
+pub use ordered_map::OrderedMap;
 pub use parser::Parser;
 pub use position::Position;
 pub use precedence::Precedence;
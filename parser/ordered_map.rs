@@ -0,0 +1,216 @@
+use std::{collections::HashMap, iter::FromIterator, slice, vec};
+
+/// A map of string keys that keeps the insertion order of its entries (used by
+/// the hashmaps to print and iterate them in a deterministic order).
+///
+/// # Example
+/// ```
+/// use sflynlang_parser::OrderedMap;
+///
+/// let mut map: OrderedMap<i64> = OrderedMap::new();
+///
+/// map.insert(String::from("b"), 1);
+/// map.insert(String::from("a"), 2);
+///
+/// assert_eq!(map.keys().collect::<Vec<&String>>(), vec!["b", "a"]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderedMap<V> {
+    entries: Vec<(String, V)>,
+    /// The index of each key in the entries.
+    indexes: HashMap<String, usize>,
+}
+
+impl<V> OrderedMap<V> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            indexes: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.indexes.contains_key(key)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.indexes.get(key).map(|index| &self.entries[*index].1)
+    }
+
+    /// Add the entry at the end of the map or replace the value of the key
+    /// (keeping its position) and get the previous value.
+    pub fn insert(&mut self, key: String, value: V) -> Option<V> {
+        match self.indexes.get(&key) {
+            Some(index) => {
+                Some(std::mem::replace(&mut self.entries[*index].1, value))
+            }
+            None => {
+                self.indexes.insert(key.clone(), self.entries.len());
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Remove the entry of the key and get its value.
+    ///
+    /// # Example
+    /// ```
+    /// use sflynlang_parser::OrderedMap;
+    ///
+    /// let mut map: OrderedMap<i64> = OrderedMap::new();
+    ///
+    /// map.insert(String::from("a"), 1);
+    /// map.insert(String::from("b"), 2);
+    /// map.insert(String::from("c"), 3);
+    ///
+    /// assert_eq!(map.remove("a"), Some(1));
+    /// assert_eq!(map.get("c"), Some(&3));
+    /// assert_eq!(map.keys().collect::<Vec<&String>>(), vec!["b", "c"]);
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let index = self.indexes.remove(key)?;
+
+        // Move back the indexes of the next entries.
+        for (entry_key, _) in &self.entries[index + 1..] {
+            if let Some(entry_index) = self.indexes.get_mut(entry_key) {
+                *entry_index -= 1;
+            }
+        }
+
+        Some(self.entries.remove(index).1)
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, (String, V)> {
+        self.entries.iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Get the entries between braces in one line, with the quoted keys.
+    ///
+    /// # Example
+    /// ```
+    /// use sflynlang_parser::OrderedMap;
+    ///
+    /// let mut map: OrderedMap<i64> = OrderedMap::new();
+    ///
+    /// map.insert(String::from("b"), 1);
+    /// map.insert(String::from("a"), 2);
+    ///
+    /// assert_eq!(
+    ///     map.to_inline_string(|value| value.to_string()),
+    ///     "{ \"b\": 1, \"a\": 2 }",
+    /// );
+    /// ```
+    pub fn to_inline_string<F>(&self, value_to_string: F) -> String
+    where
+        F: Fn(&V) -> String,
+    {
+        if self.is_empty() {
+            return String::from("{}");
+        }
+
+        format!(
+            "{{ {} }}",
+            self.entries
+                .iter()
+                .map(|(key, value)| {
+                    format!("{:?}: {}", key, value_to_string(value))
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    /// Get the entries between braces, one per line with the quoted keys and
+    /// the values indented by two spaces.
+    ///
+    /// # Example
+    /// ```
+    /// use sflynlang_parser::OrderedMap;
+    ///
+    /// let mut map: OrderedMap<i64> = OrderedMap::new();
+    ///
+    /// map.insert(String::from("b"), 1);
+    /// map.insert(String::from("a"), 2);
+    ///
+    /// assert_eq!(
+    ///     map.to_pretty_string(|value| value.to_string()),
+    ///     "{\n  \"b\": 1,\n  \"a\": 2\n}",
+    /// );
+    /// ```
+    pub fn to_pretty_string<F>(&self, value_to_string: F) -> String
+    where
+        F: Fn(&V) -> String,
+    {
+        if self.is_empty() {
+            return String::from("{}");
+        }
+
+        format!(
+            "{{\n{}\n}}",
+            self.entries
+                .iter()
+                .map(|(key, value)| {
+                    format!(
+                        "  {:?}: {}",
+                        key,
+                        value_to_string(value).replace('\n', "\n  ")
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(",\n")
+        )
+    }
+}
+
+impl<V> Default for OrderedMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> FromIterator<(String, V)> for OrderedMap<V> {
+    fn from_iter<I: IntoIterator<Item = (String, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+
+        map
+    }
+}
+
+impl<V> IntoIterator for OrderedMap<V> {
+    type Item = (String, V);
+    type IntoIter = vec::IntoIter<(String, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a OrderedMap<V> {
+    type Item = &'a (String, V);
+    type IntoIter = slice::Iter<'a, (String, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
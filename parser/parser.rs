@@ -1,5 +1,6 @@
 pub mod data_types;
 pub mod expressions;
+pub mod patterns;
 pub mod statements;
 
 use crate::{ast::Statement, Error, Position, Precedence, Tok, Token};
@@ -15,7 +15,7 @@ pub fn parse(parser: &mut Parser) -> Result<DataType, Error> {
     }
     // Check if the current token is a left parentheses.
     else if parser.current_token_is(Token::LeftParentheses)? {
-        // Get the current token position as the function position.
+        // Get the current token position as the function or tuple position.
         let function_position = parser.get_current_token()?.get_position();
 
         // Read the next token.
@@ -25,8 +25,23 @@ pub fn parse(parser: &mut Parser) -> Result<DataType, Error> {
         let mut arguments: Vec<DataType> = Vec::new();
 
         while !parser.current_token_is(Token::RightParentheses)? {
-            // Append the data type to the arguments list.
-            arguments.push(parse(parser)?);
+            // Check if the current token is a triple dot.
+            if parser.current_token_is(Token::TripleDot)? {
+                // Get the current token position as the rest position.
+                let rest_position = parser.get_current_token()?.get_position();
+
+                // Read the next token.
+                parser.read_next_token()?;
+
+                // Append the rest data type to the arguments list.
+                arguments.push(DataType::new(
+                    rest_position,
+                    DataTypes::Spread(Box::new(parse(parser)?)),
+                ));
+            } else {
+                // Append the data type to the arguments list.
+                arguments.push(parse(parser)?);
+            }
 
             // Check if the next token is a comma and read the next token.
             parser.expect_token(Token::Comma)?;
@@ -35,30 +50,75 @@ pub fn parse(parser: &mut Parser) -> Result<DataType, Error> {
             parser.read_next_token()?;
         }
 
+        // Check if the next token is not an equal greater and the data
+        // types can be a group or a tuple.
+        if !parser.next_token_is(Token::EqualGreater)?
+            && !arguments.is_empty()
+            && arguments
+                .iter()
+                .all(|argument| argument.node.get_spread().is_none())
+        {
+            node = Some(DataType::new(
+                function_position,
+                if arguments.len() == 1 {
+                    arguments[0].node.clone()
+                } else {
+                    DataTypes::Tuple(arguments)
+                },
+            ));
+        }
         // Check if the next token is not an equal greater.
-        if !parser.expect_token(Token::EqualGreater)? {
+        else if !parser.expect_token(Token::EqualGreater)? {
             return Err(Error::new_expect_token(
                 parser.get_next_token()?.get_position(),
                 "=>",
                 &parser.get_next_token()?.get_token().to_string(),
             ));
-        }
-
-        parser.read_next_token()?;
+        } else {
+            parser.read_next_token()?;
 
-        node = Some(DataType::new(
-            function_position,
-            DataTypes::Function(arguments, Box::new(parse(parser)?)),
-        ));
+            node = Some(DataType::new(
+                function_position,
+                DataTypes::Function(arguments, Box::new(parse(parser)?)),
+            ));
+        }
     }
     // Check if the current token is an identifier.
     else if let Some(identifier_value) =
         parser.get_current_token()?.get_token().get_identifier()
     {
-        node = Some(DataType::new(
-            parser.get_current_token()?.get_position(),
-            DataTypes::Identifier(identifier_value),
-        ));
+        // Get the current token position as the identifier position.
+        let identifier_position = parser.get_current_token()?.get_position();
+
+        // Check if the next token is a less and read the next token.
+        if parser.expect_token(Token::Less)? {
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Initialize the type arguments list.
+            let mut arguments: Vec<DataType> = Vec::new();
+
+            while !parser.current_token_is(Token::Greater)? {
+                // Append the data type to the type arguments list.
+                arguments.push(parse(parser)?);
+
+                // Check if the next token is a comma and read the next token.
+                parser.expect_token(Token::Comma)?;
+
+                // Read the next token.
+                parser.read_next_token()?;
+            }
+
+            node = Some(DataType::new(
+                identifier_position,
+                DataTypes::Generic(identifier_value, arguments),
+            ));
+        } else {
+            node = Some(DataType::new(
+                identifier_position,
+                DataTypes::Identifier(identifier_value),
+            ));
+        }
     }
     // Check if the current token is a number.
     else if parser.current_token_is(Token::Number)? {
@@ -67,6 +127,15 @@ pub fn parse(parser: &mut Parser) -> Result<DataType, Error> {
             DataTypes::Number,
         ))
     }
+    // Check if the current token is a number literal.
+    else if let Some(number_value) =
+        parser.get_current_token()?.get_token().get_number()
+    {
+        node = Some(DataType::new(
+            parser.get_current_token()?.get_position(),
+            DataTypes::NumberLiteral(number_value),
+        ));
+    }
     // Check if the current token is a string.
     else if parser.current_token_is(Token::String)? {
         node = Some(DataType::new(
@@ -74,6 +143,15 @@ pub fn parse(parser: &mut Parser) -> Result<DataType, Error> {
             DataTypes::String,
         ));
     }
+    // Check if the current token is a string literal.
+    else if let Some(string_value) =
+        parser.get_current_token()?.get_token().get_string()
+    {
+        node = Some(DataType::new(
+            parser.get_current_token()?.get_position(),
+            DataTypes::StringLiteral(string_value),
+        ));
+    }
     // Check if the current token is a void.
     else if parser.current_token_is(Token::Void)? {
         node = Some(DataType::new(
@@ -114,9 +192,141 @@ pub fn parse(parser: &mut Parser) -> Result<DataType, Error> {
             continue;
         }
 
+        // Check if the next token is a question and read the next token.
+        if parser.expect_token(Token::Question)? {
+            // Set the option data type to the node_out.
+            node_out = DataType::new(
+                node_out.get_position(),
+                DataTypes::Option(Box::new(node_out)),
+            );
+
+            continue;
+        }
+
         break;
     }
 
+    // Check if the next token is a vertical bar and read the next token.
+    if parser.expect_token(Token::VBar)? {
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Get the other data types of the union.
+        let other_type = parse(parser)?;
+
+        // Initialize the union data types list.
+        let mut union_types: Vec<DataType> = vec![node_out.clone()];
+
+        match other_type.node.get_union() {
+            Some(mut other_types) => union_types.append(&mut other_types),
+            None => union_types.push(other_type),
+        }
+
+        node_out = DataType::new(
+            node_out.get_position(),
+            DataTypes::Union(union_types),
+        );
+    }
+
     // Return the data type.
     Ok(node_out)
 }
+
+/// Parse the type parameters (like `<T, U>`) of a function or an interface.
+pub fn parse_parameters(parser: &mut Parser) -> Result<Vec<DataType>, Error> {
+    // Initialize the type parameters list.
+    let mut parameters: Vec<DataType> = Vec::new();
+
+    // Check if the next token is not a less.
+    if !parser.expect_token(Token::Less)? {
+        return Ok(parameters);
+    }
+
+    // Read the next token.
+    parser.read_next_token()?;
+
+    while !parser.current_token_is(Token::Greater)? {
+        // Get the current token as the type parameter name.
+        let parameter_name =
+            match parser.get_current_token()?.get_token().get_identifier() {
+                Some(parameter_name) => parameter_name,
+                None => {
+                    return Err(Error::new_expect_token(
+                        parser.get_current_token()?.get_position(),
+                        "Identifier",
+                        &parser.get_current_token()?.get_token().to_string(),
+                    ))
+                }
+            };
+
+        // Get the current token position as the type parameter position.
+        let parameter_position = parser.get_current_token()?.get_position();
+
+        // Initialize the type parameter constraint.
+        let mut constraint: Option<Box<DataType>> = None;
+
+        // Check if the next token is an extends and read the next token.
+        if parser.expect_token(Token::Extends)? {
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Get the type parameter constraint.
+            constraint = Some(Box::new(parse(parser)?));
+        }
+
+        // Append the type parameter to the type parameters list.
+        parameters.push(DataType::new(
+            parameter_position,
+            DataTypes::Parameter(parameter_name, constraint),
+        ));
+
+        // Check if the next token is a comma and read the next token.
+        parser.expect_token(Token::Comma)?;
+
+        // Read the next token.
+        parser.read_next_token()?;
+    }
+
+    Ok(parameters)
+}
+
+#[test]
+fn test_tuples() {
+    use crate::Lexer;
+
+    let parse_type = |code: &str| {
+        let mut lexer = Lexer::new(String::from(code));
+        let mut parser = Parser::new(lexer.run().unwrap());
+
+        parse(&mut parser).unwrap().node
+    };
+
+    // The tuples are the groups of several types without an arrow.
+    let tuple = parse_type("(number, string)").get_tuple().unwrap();
+
+    assert_eq!(tuple.len(), 2);
+    assert!(tuple[0].node.is_number());
+    assert!(tuple[1].node.is_string());
+
+    let (arguments, return_type) =
+        parse_type("(number) => string").get_function().unwrap();
+
+    assert_eq!(arguments.len(), 1);
+    assert!(arguments[0].node.is_number());
+    assert!(return_type.node.is_string());
+
+    let (arguments, _) = parse_type("(number, string) => void")
+        .get_function()
+        .unwrap();
+
+    assert_eq!(arguments.len(), 2);
+
+    // One type between parentheses is a group.
+    assert!(parse_type("(number)").is_number());
+
+    let nested = parse_type("(number, (string, boolean))")
+        .get_tuple()
+        .unwrap();
+
+    assert!(nested[1].node.get_tuple().is_some());
+}
@@ -1,11 +1,9 @@
 use crate::{
     ast::{Expression, Expressions},
-    parser::{data_types, statements, Statement},
-    Error, Parser, Precedence, Token,
+    parser::{data_types, patterns, statements, Statement},
+    Error, OrderedMap, Parser, Precedence, Token,
 };
 
-use std::collections::HashMap;
-
 pub fn parse(
     parser: &mut Parser,
     precedence: Precedence,
@@ -49,7 +47,7 @@ pub fn parse(
             Expressions::Boolean(parser.current_token_is(Token::True)?),
         ));
     }
-    // Parse group:
+    // Parse group or tuple:
     // Check if the current token is a left parentheses.
     else if parser.current_token_is(Token::LeftParentheses)? {
         // Get the current token position as the group position.
@@ -62,19 +60,45 @@ pub fn parse(
         // Get the group expression.
         let group_exp = parse(parser, Precedence::Lowest)?;
 
+        // Parse tuple:
+        // Check if the next token is a comma and read the next token.
+        if parser.expect_token(Token::Comma)? {
+            // Initialize the tuple values list.
+            let mut values: Vec<Expression> = vec![group_exp];
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            while !parser.current_token_is(Token::RightParentheses)? {
+                // Parse expression:
+                // Get the value expression and append it to the values list.
+                values.push(parse(parser, Precedence::Lowest)?);
+
+                // Check if the next token is a comma and read the next token.
+                parser.expect_token(Token::Comma)?;
+
+                // Read the next token.
+                parser.read_next_token()?;
+            }
+
+            node = Some(Expression::new(
+                group_position,
+                Expressions::Tuple(values),
+            ));
+        }
         // Check if the next token is not a right parentheses.
-        if !parser.expect_token(Token::RightParentheses)? {
+        else if !parser.expect_token(Token::RightParentheses)? {
             return Err(Error::new_expect_token(
                 parser.get_next_token()?.get_position(),
                 ")",
                 &parser.get_next_token()?.get_token().to_string(),
             ));
+        } else {
+            node = Some(Expression::new(
+                group_position,
+                Expressions::Group(Box::new(group_exp)),
+            ));
         }
-
-        node = Some(Expression::new(
-            group_position,
-            Expressions::Group(Box::new(group_exp)),
-        ));
     }
     // Parse hashmap:
     // Check if the current token is a left brace.
@@ -89,7 +113,7 @@ pub fn parse(
         parser.skip_eol()?;
 
         // Initialize the hashmap data.
-        let mut hashmap_data: HashMap<String, Expression> = HashMap::new();
+        let mut hashmap_data: OrderedMap<Expression> = OrderedMap::new();
 
         while !parser.current_token_is(Token::RightBrace)? {
             // Get the identifier token value.
@@ -238,6 +262,168 @@ pub fn parse(
             },
         ));
     }
+    // Parse match:
+    // Check if the current token is a match.
+    else if parser.current_token_is(Token::Match)? {
+        // Get the current token position as the match position.
+        let match_position = parser.get_current_token()?.get_position();
+
+        // Check if the next token is not a left parentheses.
+        if !parser.expect_token(Token::LeftParentheses)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "(",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Parse expression:
+        // Get the match value.
+        let match_value = parse(parser, Precedence::Lowest)?;
+
+        // Check if the next token is not a right parentheses.
+        if !parser.expect_token(Token::RightParentheses)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                ")",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Check if the next token is not a left brace.
+        if !parser.expect_token(Token::LeftBrace)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "{",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Ignore the end of lines.
+        parser.skip_eol()?;
+
+        // Initialize the match arms list.
+        let mut match_arms = Vec::new();
+
+        while !parser.current_token_is(Token::RightBrace)? {
+            // Parse pattern:
+            // Get the arm pattern.
+            let arm_pattern = patterns::parse(parser)?;
+
+            // Check if the next token is not an equal greater.
+            if !parser.expect_token(Token::EqualGreater)? {
+                return Err(Error::new_expect_token(
+                    parser.get_next_token()?.get_position(),
+                    "=>",
+                    &parser.get_next_token()?.get_token().to_string(),
+                ));
+            }
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Parse expression:
+            // Get the arm expression and append it to the arms list.
+            match_arms.push((arm_pattern, parse(parser, Precedence::Lowest)?));
+
+            // Check if the next token is a comma and read the next token.
+            parser.expect_token(Token::Comma)?;
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Ignore the end of lines.
+            parser.skip_eol()?;
+        }
+
+        node = Some(Expression::new(
+            match_position,
+            Expressions::Match {
+                value: Box::new(match_value),
+                arms: match_arms,
+            },
+        ));
+    }
+    // Parse try:
+    // Check if the current token is a try.
+    else if parser.current_token_is(Token::Try)? {
+        // Get the current token position as the try position.
+        let try_position = parser.get_current_token()?.get_position();
+
+        // Parse body statements:
+        // Get the statements that can fail between braces.
+        let try_body = statements::parse_body(parser)?;
+
+        // Check if the next token is not a catch.
+        if !parser.expect_token(Token::Catch)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "catch",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Check if the next token is not a left parentheses.
+        if !parser.expect_token(Token::LeftParentheses)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "(",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Get the name of the error message.
+        let error_name =
+            match parser.get_current_token()?.get_token().get_identifier() {
+                Some(error_name) => error_name,
+                None => {
+                    return Err(Error::new_expect_token(
+                        parser.get_current_token()?.get_position(),
+                        "Identifier",
+                        &parser.get_current_token()?.get_token().to_string(),
+                    ))
+                }
+            };
+
+        // Check if the next token is not a right parentheses.
+        if !parser.expect_token(Token::RightParentheses)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                ")",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Parse body statements:
+        // Get the statements that handle the error between braces.
+        let try_handler = statements::parse_body(parser)?;
+
+        node = Some(Expression::new(
+            try_position,
+            Expressions::Try {
+                body: try_body,
+                error_name,
+                handler: try_handler,
+            },
+        ));
+    }
+    // Parse null:
+    // Check if the current token is a null.
+    else if parser.current_token_is(Token::Null)? {
+        node = Some(Expression::new(
+            parser.get_current_token()?.get_position(),
+            Expressions::Null,
+        ));
+    }
     // Parse number:
     // Check if the current token is a number.
     else if let Some(number_value) =
@@ -249,9 +435,10 @@ pub fn parse(
         ));
     }
     // Parse prefix:
-    // Check if the current token is a minus or a not.
+    // Check if the current token is a minus, a not or a typeof.
     else if parser.current_token_is(Token::Minus)?
         || parser.current_token_is(Token::Not)?
+        || parser.current_token_is(Token::Typeof)?
     {
         // Get the current token position as the prefix position.
         let prefix_position = parser.get_current_token()?.get_position();
@@ -270,6 +457,20 @@ pub fn parse(
             ),
         ));
     }
+    // Parse spread:
+    // Check if the current token is a triple dot.
+    else if parser.current_token_is(Token::TripleDot)? {
+        // Get the current token position as the spread position.
+        let spread_position = parser.get_current_token()?.get_position();
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        node = Some(Expression::new(
+            spread_position,
+            Expressions::Spread(Box::new(parse(parser, Precedence::Prefix)?)),
+        ));
+    }
     // Parse string:
     // Check if the current token is a string.
     else if let Some(string_value) =
@@ -300,9 +501,43 @@ pub fn parse(
         parser.read_next_token()?;
 
         while !parser.current_token_is(Token::RightParentheses)? {
+            // Parse named argument:
+            // Check if the current token is an identifier followed by a colon.
+            if parser
+                .get_current_token()?
+                .get_token()
+                .get_identifier()
+                .is_some()
+                && parser.next_token_is(Token::Colon)?
+            {
+                // Get the current token position as the named argument position.
+                let argument_position =
+                    parser.get_current_token()?.get_position();
+
+                // Get the current token as the named argument name.
+                let argument_name = parser
+                    .get_current_token()?
+                    .get_token()
+                    .get_identifier()
+                    .unwrap();
+
+                // Read the identifier and the colon tokens.
+                parser.read_next_token()?;
+                parser.read_next_token()?;
+
+                call_arguments.push(Expression::new(
+                    argument_position,
+                    Expressions::NamedArgument {
+                        name: argument_name,
+                        value: Box::new(parse(parser, Precedence::Lowest)?),
+                    },
+                ));
+            }
             // Parse expression:
             // Get the argument expresion and append it to the arguments list.
-            call_arguments.push(parse(parser, Precedence::Lowest)?);
+            else {
+                call_arguments.push(parse(parser, Precedence::Lowest)?);
+            }
 
             // Check if the next token is a comma and read the next token.
             parser.expect_token(Token::Comma)?;
@@ -383,7 +618,8 @@ pub fn parse(
         // Parse infix:
         // Check if the next token is a plus, minus, star, slash, percent,
         // double star, double equal, not equal, less, less equal, greater,
-        // greater equal, double vertical bar or a double amper and read the next token.
+        // greater equal, double vertical bar, double amper or a vertical bar greater
+        // and read the next token.
         else if parser.expect_token(Token::Plus)?
             || parser.expect_token(Token::Minus)?
             || parser.expect_token(Token::Star)?
@@ -398,6 +634,7 @@ pub fn parse(
             || parser.expect_token(Token::GreaterEqual)?
             || parser.expect_token(Token::DoubleVBar)?
             || parser.expect_token(Token::DoubleAmper)?
+            || parser.expect_token(Token::VBarGreater)?
         {
             // Get the current token position as the infix position.
             let infix_position = parser.get_current_token()?.get_position();
@@ -422,6 +659,63 @@ pub fn parse(
 
             continue;
         }
+        // Parse ternary:
+        // Check if the next token is a question and read the next token.
+        else if parser.expect_token(Token::Question)? {
+            // Get the current token position as the ternary position.
+            let ternary_position = parser.get_current_token()?.get_position();
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Check if the consequence is an identifier followed by a colon
+            // to not parse it as an argument.
+            let ternary_consequence = if parser
+                .get_current_token()?
+                .get_token()
+                .get_identifier()
+                .is_some()
+                && parser.next_token_is(Token::Colon)?
+            {
+                Expression::new(
+                    parser.get_current_token()?.get_position(),
+                    Expressions::Identifier(
+                        parser
+                            .get_current_token()?
+                            .get_token()
+                            .get_identifier()
+                            .unwrap(),
+                    ),
+                )
+            } else {
+                // Parse expression:
+                // Get the consequence expression.
+                parse(parser, Precedence::Lowest)?
+            };
+
+            // Check if the next token is not a colon.
+            if !parser.expect_token(Token::Colon)? {
+                return Err(Error::new_expect_token(
+                    parser.get_next_token()?.get_position(),
+                    ":",
+                    &parser.get_next_token()?.get_token().to_string(),
+                ));
+            }
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            node_out = Expression::new(
+                ternary_position,
+                Expressions::Ternary {
+                    condition: Box::new(node_out),
+                    consequence: Box::new(ternary_consequence),
+                    alternative: Box::new(parse(parser, Precedence::Pipeline)?),
+                },
+            );
+
+            continue;
+        }
         // Parse method:
         // Check if the next token is a dot and read the next token.
         else if parser.expect_token(Token::Dot)? {
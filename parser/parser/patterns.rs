@@ -0,0 +1,177 @@
+use crate::{
+    ast::{Pattern, Patterns},
+    Error, Parser, Token,
+};
+
+pub fn parse(parser: &mut Parser) -> Result<Pattern, Error> {
+    // Get the current token position as the pattern position.
+    let pattern_position = parser.get_current_token()?.get_position();
+
+    // Parse array pattern:
+    // Check if the current token is a left bracket.
+    if parser.current_token_is(Token::LeftBracket)? {
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Initialize the values patterns list.
+        let mut values: Vec<Pattern> = Vec::new();
+
+        while !parser.current_token_is(Token::RightBracket)? {
+            // Check if the current token is a triple dot.
+            if parser.current_token_is(Token::TripleDot)? {
+                // Get the current token position as the rest position.
+                let rest_position = parser.get_current_token()?.get_position();
+
+                // Read the next token.
+                parser.read_next_token()?;
+
+                // Get the current token as the rest name.
+                if let Some(rest_name) =
+                    parser.get_current_token()?.get_token().get_identifier()
+                {
+                    values.push(Pattern::new(
+                        rest_position,
+                        Patterns::Rest(rest_name),
+                    ));
+                } else {
+                    return Err(Error::new_expect_token(
+                        parser.get_current_token()?.get_position(),
+                        "Identifier",
+                        &parser.get_current_token()?.get_token().to_string(),
+                    ));
+                }
+            } else {
+                // Parse pattern:
+                // Get the current pattern and append it to the values list.
+                values.push(parse(parser)?);
+            }
+
+            // Check if the next token is a comma and read the next token.
+            parser.expect_token(Token::Comma)?;
+
+            // Read the next token.
+            parser.read_next_token()?;
+        }
+
+        return Ok(Pattern::new(pattern_position, Patterns::Array(values)));
+    }
+
+    // Parse tuple pattern:
+    // Check if the current token is a left parentheses.
+    if parser.current_token_is(Token::LeftParentheses)? {
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Initialize the values patterns list.
+        let mut values: Vec<Pattern> = Vec::new();
+
+        while !parser.current_token_is(Token::RightParentheses)? {
+            // Parse pattern:
+            // Get the current pattern and append it to the values list.
+            values.push(parse(parser)?);
+
+            // Check if the next token is a comma and read the next token.
+            parser.expect_token(Token::Comma)?;
+
+            // Read the next token.
+            parser.read_next_token()?;
+        }
+
+        return Ok(Pattern::new(pattern_position, Patterns::Tuple(values)));
+    }
+
+    // Parse hashmap pattern:
+    // Check if the current token is a left brace.
+    if parser.current_token_is(Token::LeftBrace)? {
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Ignore the end of lines.
+        parser.skip_eol()?;
+
+        // Initialize the hashmap patterns list.
+        let mut data: Vec<(String, Pattern)> = Vec::new();
+
+        while !parser.current_token_is(Token::RightBrace)? {
+            // Get the identifier token value as the key.
+            if let Some(key) =
+                parser.get_current_token()?.get_token().get_identifier()
+            {
+                // Get the current token position as the key position.
+                let key_position = parser.get_current_token()?.get_position();
+
+                // Check if the next token is a colon and read the next token.
+                if parser.expect_token(Token::Colon)? {
+                    // Read the next token.
+                    parser.read_next_token()?;
+
+                    // Parse pattern:
+                    // Get the pattern of the key value.
+                    data.push((key, parse(parser)?));
+                } else {
+                    data.push((
+                        key.clone(),
+                        Pattern::new(key_position, Patterns::Identifier(key)),
+                    ));
+                }
+
+                // Check if the next token is a comma and read the next token.
+                parser.expect_token(Token::Comma)?;
+
+                // Read the next token.
+                parser.read_next_token()?;
+
+                // Ignore the end of lines.
+                parser.skip_eol()?;
+            } else {
+                return Err(Error::new_expect_token(
+                    parser.get_current_token()?.get_position(),
+                    "Identifier",
+                    &parser.get_current_token()?.get_token().to_string(),
+                ));
+            }
+        }
+
+        return Ok(Pattern::new(pattern_position, Patterns::HashMap(data)));
+    }
+
+    // Parse identifier or enum variant pattern:
+    // Check if the current token is an identifier.
+    if let Some(identifier_value) =
+        parser.get_current_token()?.get_token().get_identifier()
+    {
+        // Parse enum variant pattern:
+        // Check if the next token is a left parentheses and read the next token.
+        if parser.expect_token(Token::LeftParentheses)? {
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Initialize the values patterns list.
+            let mut values: Vec<Pattern> = Vec::new();
+
+            while !parser.current_token_is(Token::RightParentheses)? {
+                // Parse pattern:
+                // Get the current pattern and append it to the values list.
+                values.push(parse(parser)?);
+
+                // Check if the next token is a comma and read the next token.
+                parser.expect_token(Token::Comma)?;
+
+                // Read the next token.
+                parser.read_next_token()?;
+            }
+
+            return Ok(Pattern::new(
+                pattern_position,
+                Patterns::EnumVariant(identifier_value, values),
+            ));
+        }
+
+        return Ok(Pattern::new(
+            pattern_position,
+            Patterns::Identifier(identifier_value),
+        ));
+    }
+
+    Err(Error::new_unknown_token(pattern_position))
+}
@@ -1,6 +1,9 @@
 use crate::{
-    ast::{DataType, DataTypes, Expression, Statement, Statements},
-    parser::{data_types, expressions},
+    ast::{
+        ClassField, ClassMethod, DataType, DataTypes, Expression, Expressions,
+        Pattern, Patterns, Statement, Statements, Variant,
+    },
+    parser::{data_types, expressions, patterns},
     Error, Parser, Position, Precedence, Token,
 };
 
@@ -38,88 +41,338 @@ pub fn parse_body(parser: &mut Parser) -> Result<Vec<Statement>, Error> {
     Ok(statements)
 }
 
-pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
-    // Parse functions:
-    // Check if the current token is a func.
-    if parser.current_token_is(Token::Func)? {
-        // Get the current token position as the function position.
-        let function_position = parser.get_current_token()?.get_position();
+/// Parse a method signature (like `describe(prefix: string): string`) of an
+/// interface as an argument with a function data type.
+fn parse_method_signature(
+    parser: &mut Parser,
+    method_name: String,
+) -> Result<Expression, Error> {
+    // Get the current token position as the method position.
+    let method_position = parser.get_current_token()?.get_position();
+
+    // Read the next token.
+    parser.read_next_token()?;
+
+    // Read the next token.
+    parser.read_next_token()?;
+
+    // Initialize the method arguments data types list.
+    let mut method_arguments: Vec<DataType> = Vec::new();
+
+    while !parser.current_token_is(Token::RightParentheses)? {
+        // Parse expression:
+        // Get the current argument expression.
+        let method_argument = expressions::parse(parser, Precedence::Lowest)?;
+
+        match method_argument.node.get_argument() {
+            Some((_, _, Some(value))) => {
+                return Err(Error::new_lexical(
+                    value.get_position(),
+                    "Cannot set a value here.",
+                ));
+            }
+            Some((argument_name, argument_data_type, None)) => {
+                method_arguments.push(DataType::new(
+                    method_argument.get_position(),
+                    DataTypes::Argument {
+                        name: argument_name,
+                        data_type: argument_data_type,
+                        is_optional: false,
+                    },
+                ));
+            }
+            None => {
+                return Err(Error::new_expect_token(
+                    method_argument.get_position(),
+                    "Argument",
+                    &method_argument.to_string(),
+                ));
+            }
+        }
+
+        // Check if the next token is a comma and read the next token.
+        parser.expect_token(Token::Comma)?;
 
         // Read the next token.
         parser.read_next_token()?;
+    }
 
-        // Parse expression:
-        // Get the name with arguments expression (Call).
-        let function_name_and_arguments =
-            expressions::parse(parser, Precedence::Lowest)?;
+    // Initialize the method return data type.
+    let mut method_return_type: DataType = DataType::new(
+        parser.get_current_token()?.get_position(),
+        DataTypes::Void,
+    );
 
-        // Check if the function name and arguments is not a call expression.
-        if function_name_and_arguments.node.get_call().is_none() {
-            return Err(Error::new_expect_token(
-                function_name_and_arguments.get_position(),
-                "Identifier",
-                &function_name_and_arguments.to_string(),
+    // Check if the next token is a colon and read the next token.
+    if parser.expect_token(Token::Colon)? {
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Parse data type:
+        // Get the method return data type.
+        method_return_type = data_types::parse(parser)?;
+    }
+
+    Ok(Expression::new(
+        method_position.clone(),
+        Expressions::Argument {
+            name: method_name,
+            data_type: Box::new(DataType::new(
+                method_position,
+                DataTypes::Function(
+                    method_arguments,
+                    Box::new(method_return_type),
+                ),
+            )),
+            value: None,
+        },
+    ))
+}
+
+/// Parse the type parameters, arguments, return data type and body of a
+/// function after its name.
+fn parse_function(
+    parser: &mut Parser,
+    function_position: Position,
+    function_name: Box<Expression>,
+) -> Result<Statement, Error> {
+    // Parse data type parameters:
+    // Get the function type parameters.
+    let function_type_parameters = data_types::parse_parameters(parser)?;
+
+    // Check if the next token is not a left parentheses.
+    if !parser.expect_token(Token::LeftParentheses)? {
+        return Err(Error::new_expect_token(
+            parser.get_next_token()?.get_position(),
+            "(",
+            &parser.get_next_token()?.get_token().to_string(),
+        ));
+    }
+
+    // Read the next token.
+    parser.read_next_token()?;
+
+    // Initialize the function arguments expressions list.
+    let mut function_arguments: Vec<Expression> = Vec::new();
+
+    // Initialize the destructuring statements list.
+    let mut function_destructuring: Vec<Statement> = Vec::new();
+
+    while !parser.current_token_is(Token::RightParentheses)? {
+        // Parse destructuring argument:
+        // Check if the current token is a left bracket, a left brace or a
+        // left parentheses.
+        if parser.current_token_is(Token::LeftBracket)?
+            || parser.current_token_is(Token::LeftBrace)?
+            || parser.current_token_is(Token::LeftParentheses)?
+        {
+            // Parse pattern:
+            // Get the current argument pattern.
+            let argument_pattern = patterns::parse(parser)?;
+
+            // Check if the next token is not a colon.
+            if !parser.expect_token(Token::Colon)? {
+                return Err(Error::new_expect_token(
+                    parser.get_next_token()?.get_position(),
+                    ":",
+                    &parser.get_next_token()?.get_token().to_string(),
+                ));
+            }
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Parse data type:
+            // Get the argument data type.
+            let argument_data_type = data_types::parse(parser)?;
+
+            // Initialize the optional argument value.
+            let mut argument_value: Option<Box<Expression>> = None;
+
+            // Check if the next token is an equal and read the next token.
+            if parser.expect_token(Token::Equal)? {
+                // Read the next token.
+                parser.read_next_token()?;
+
+                // Set the argument value.
+                argument_value = Some(Box::new(expressions::parse(
+                    parser,
+                    Precedence::Lowest,
+                )?));
+            }
+
+            // The argument is named after its pattern, so it cannot be
+            // referenced from the body or from a named argument.
+            let argument_name = argument_pattern.to_string();
+
+            // Append the argument to the function arguments list.
+            function_arguments.push(Expression::new(
+                argument_pattern.get_position(),
+                Expressions::Argument {
+                    name: argument_name.clone(),
+                    data_type: Box::new(argument_data_type),
+                    value: argument_value,
+                },
+            ));
+
+            // Append the pattern variable to the destructuring list.
+            function_destructuring.push(Statement::new(
+                argument_pattern.get_position(),
+                Statements::Variable {
+                    is_mutable: false,
+                    pattern: argument_pattern.clone(),
+                    data_type: None,
+                    value: Some(Expression::new(
+                        argument_pattern.get_position(),
+                        Expressions::Identifier(argument_name),
+                    )),
+                },
             ));
+
+            // Check if the next token is a comma and read the next token.
+            parser.expect_token(Token::Comma)?;
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            continue;
         }
 
-        // Get the function name expression.
-        let function_name: Box<Expression> =
-            function_name_and_arguments.node.get_call().unwrap().0;
+        // Parse self argument:
+        // Check if the current token is the first argument and it is a
+        // `self` without data type.
+        if function_arguments.is_empty()
+            && parser.get_current_token()?.get_token().get_identifier()
+                == Some(String::from("self"))
+            && (parser.next_token_is(Token::Comma)?
+                || parser.next_token_is(Token::RightParentheses)?)
+        {
+            // Get the current token position as the self position.
+            let self_position = parser.get_current_token()?.get_position();
 
-        // Check if the function name is not an identifier expression.
-        if function_name.node.get_identifier().is_none() {
-            return Err(Error::new_expect_token(
-                function_name.get_position(),
-                "Identifier",
-                &function_name.to_string(),
+            // The self argument has the data type of the impl block.
+            function_arguments.push(Expression::new(
+                self_position.clone(),
+                Expressions::Argument {
+                    name: String::from("self"),
+                    data_type: Box::new(DataType::new(
+                        self_position,
+                        DataTypes::Identifier(String::from("Self")),
+                    )),
+                    value: None,
+                },
             ));
+
+            // Check if the next token is a comma and read the next token.
+            parser.expect_token(Token::Comma)?;
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            continue;
         }
 
-        // Get the function arguments expressions list.
-        let function_arguments: Vec<Expression> =
-            function_name_and_arguments.node.get_call().unwrap().1;
+        // Parse expression:
+        // Get the current argument expression.
+        let function_argument = expressions::parse(parser, Precedence::Lowest)?;
 
-        // Check if has arguments.
-        if function_arguments.len() > 0 {
-            for function_argument in function_arguments.iter() {
-                // Check if the argument is not an argument expression.
-                if function_argument.node.get_argument().is_none() {
-                    return Err(Error::new_expect_token(
-                        function_argument.get_position(),
-                        "Argument",
-                        &function_argument.to_string(),
-                    ));
-                }
-            }
+        // Check if the argument is not an argument or a rest argument expression.
+        if function_argument.node.get_argument().is_none()
+            && function_argument
+                .node
+                .get_spread()
+                .and_then(|value| value.node.get_argument())
+                .is_none()
+        {
+            return Err(Error::new_expect_token(
+                function_argument.get_position(),
+                "Argument",
+                &function_argument.to_string(),
+            ));
         }
 
-        // Initialize the function data type.
-        let mut function_return_type: DataType =
-            DataType::new(Position::new(0, 0, 1, 1), DataTypes::Unknown);
+        // Append the argument to the function arguments list.
+        function_arguments.push(function_argument);
 
-        // Check if the next token is a colon and read the next token.
-        if parser.expect_token(Token::Colon)? {
-            // Read the next token.
-            parser.read_next_token()?;
+        // Check if the next token is a comma and read the next token.
+        parser.expect_token(Token::Comma)?;
+
+        // Read the next token.
+        parser.read_next_token()?;
+    }
+
+    // Initialize the function data type.
+    let mut function_return_type: DataType =
+        DataType::new(Position::new(0, 0, 1, 1), DataTypes::Unknown);
+
+    // Check if the next token is a colon and read the next token.
+    if parser.expect_token(Token::Colon)? {
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Pase data type:
+        // Get the function return data type.
+        function_return_type = data_types::parse(parser)?;
+    }
 
-            // Pase data type:
-            // Get the function return data type.
-            function_return_type = data_types::parse(parser)?;
+    // Parse body statements:
+    // Get the function body between braces.
+    let mut function_body = parse_body(parser)?;
+
+    // Destructure the pattern arguments before the body statements.
+    function_destructuring.append(&mut function_body);
+
+    Ok(Statement::new(
+        function_position,
+        Statements::Function {
+            name: function_name,
+            type_parameters: function_type_parameters,
+            arguments: function_arguments,
+            return_type: Box::new(function_return_type),
+            body: function_destructuring,
+        },
+    ))
+}
+
+pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
+    // Parse functions:
+    // Check if the current token is a func.
+    if parser.current_token_is(Token::Func)? {
+        // Get the current token position as the function position.
+        let function_position = parser.get_current_token()?.get_position();
+
+        // Check if the next token is not an identifier.
+        if parser
+            .get_next_token()?
+            .get_token()
+            .get_identifier()
+            .is_none()
+        {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "Identifier",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
         }
 
-        // Parse body statements:
-        // Get the function body between braces.
-        let function_body = parse_body(parser)?;
+        // Read the next token.
+        parser.read_next_token()?;
 
-        Ok(Statement::new(
-            function_position,
-            Statements::Function {
-                name: function_name,
-                arguments: function_arguments,
-                return_type: Box::new(function_return_type),
-                body: function_body,
-            },
-        ))
+        // Get the current token as the function name expression.
+        let function_name: Box<Expression> = Box::new(Expression::new(
+            parser.get_current_token()?.get_position(),
+            Expressions::Identifier(
+                parser
+                    .get_current_token()?
+                    .get_token()
+                    .get_identifier()
+                    .unwrap(),
+            ),
+        ));
+
+        // Parse function:
+        // Get the function from its type parameters.
+        parse_function(parser, function_position, function_name)
     }
     // Parse interface:
     // Check if the current token is an interface.
@@ -150,6 +403,30 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
             .get_identifier()
             .unwrap();
 
+        // Parse data type parameters:
+        // Get the interface type parameters.
+        let interface_type_parameters = data_types::parse_parameters(parser)?;
+
+        // Initialize the extended interfaces list.
+        let mut interface_extends: Vec<DataType> = Vec::new();
+
+        // Check if the next token is an extends and read the next token.
+        if parser.expect_token(Token::Extends)? {
+            loop {
+                // Read the next token.
+                parser.read_next_token()?;
+
+                // Parse data type:
+                // Get the extended interface and append it to the list.
+                interface_extends.push(data_types::parse(parser)?);
+
+                // Check if the next token is not a comma.
+                if !parser.expect_token(Token::Comma)? {
+                    break;
+                }
+            }
+        }
+
         if !parser.expect_token(Token::LeftBrace)? {
             return Err(Error::new_expect_token(
                 parser.get_next_token()?.get_position(),
@@ -164,28 +441,54 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
         // Ignore the end of lines.
         parser.skip_eol()?;
 
+        // Initialize the properties expressions list.
+        let mut properties: Vec<Expression> = Vec::new();
+
         // Initialize the methods expressions list.
         let mut methods: Vec<Expression> = Vec::new();
 
         while !parser.current_token_is(Token::RightBrace)? {
+            // Parse method signature:
+            // Check if the current token is an identifier and the next token
+            // is a left parentheses.
+            if let Some(method_name) =
+                parser.get_current_token()?.get_token().get_identifier()
+            {
+                if parser.next_token_is(Token::LeftParentheses)? {
+                    // Append the method signature to the methods list.
+                    methods.push(parse_method_signature(parser, method_name)?);
+
+                    // Check if the next token is a semicolon and read the next token.
+                    parser.expect_token(Token::Semicolon)?;
+
+                    // Read the next token.
+                    parser.read_next_token()?;
+
+                    // Ignore the end of lines.
+                    parser.skip_eol()?;
+
+                    continue;
+                }
+            }
+
             // Parse expression:
             // Get the current expression.
-            let method = expressions::parse(parser, Precedence::Lowest)?;
+            let property = expressions::parse(parser, Precedence::Lowest)?;
 
-            // Check if the method is not an argument expression.
-            if method.node.get_argument().is_none() {
+            // Check if the property is not an argument expression.
+            if property.node.get_argument().is_none() {
                 return Err(Error::new_expect_token(
-                    method.get_position(),
+                    property.get_position(),
                     "Argument",
-                    &method.to_string(),
+                    &property.to_string(),
                 ));
             }
 
             // Get the argument expression.
             let (_identifier, _data_type, value) =
-                method.node.get_argument().unwrap();
+                property.node.get_argument().unwrap();
 
-            // Check if the method argument has a value.
+            // Check if the property argument has a value.
             if value.is_some() {
                 return Err(Error::new_lexical(
                     value.unwrap().get_position(),
@@ -193,8 +496,8 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
                 ));
             }
 
-            // Append the method to the methods list.
-            methods.push(method);
+            // Append the property to the properties list.
+            properties.push(property);
 
             // Check if the next token is a semicolon and read the next token.
             parser.expect_token(Token::Semicolon)?;
@@ -208,7 +511,280 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
 
         Ok(Statement::new(
             interface_position,
-            Statements::Interface(interface_name, methods),
+            Statements::Interface {
+                name: interface_name,
+                type_parameters: interface_type_parameters,
+                extends: interface_extends,
+                properties,
+                methods,
+            },
+        ))
+    }
+    // Parse class:
+    // Check if the current token is a class.
+    else if parser.current_token_is(Token::Class)? {
+        // Get the current token position as the class position.
+        let class_position = parser.get_current_token()?.get_position();
+
+        // Check if the next token is not an identifier.
+        if parser
+            .get_next_token()?
+            .get_token()
+            .get_identifier()
+            .is_none()
+        {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "Identifier",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Get the current token as the class name.
+        let class_name = parser
+            .get_current_token()?
+            .get_token()
+            .get_identifier()
+            .unwrap();
+
+        // Check if the next token is not a left brace.
+        if !parser.expect_token(Token::LeftBrace)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "{",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Ignore the end of lines.
+        parser.skip_eol()?;
+
+        // Initialize the class fields list.
+        let mut fields: Vec<ClassField> = Vec::new();
+
+        // Initialize the class constructor.
+        let mut constructor: Option<Box<Statement>> = None;
+
+        // Initialize the class methods list.
+        let mut methods: Vec<ClassMethod> = Vec::new();
+
+        while !parser.current_token_is(Token::RightBrace)? {
+            // Check if the current token is a visibility keyword.
+            let has_visibility = parser.current_token_is(Token::Public)?
+                || parser.current_token_is(Token::Private)?;
+
+            // The class members are public by default.
+            let is_public = !parser.current_token_is(Token::Private)?;
+
+            if has_visibility {
+                // Read the next token.
+                parser.read_next_token()?;
+            }
+
+            // Parse constructor:
+            // Check if the current token is a constructor.
+            if parser.current_token_is(Token::Constructor)? {
+                // Get the current token position as the constructor position.
+                let constructor_position =
+                    parser.get_current_token()?.get_position();
+
+                if has_visibility {
+                    return Err(Error::new_lexical(
+                        constructor_position,
+                        "A constructor cannot have a visibility.",
+                    ));
+                }
+
+                if constructor.is_some() {
+                    return Err(Error::new_lexical(
+                        constructor_position,
+                        "A class can only have one constructor.",
+                    ));
+                }
+
+                // Parse function:
+                // Get the constructor as a function named `constructor`.
+                constructor = Some(Box::new(parse_function(
+                    parser,
+                    constructor_position.clone(),
+                    Box::new(Expression::new(
+                        constructor_position,
+                        Expressions::Identifier(String::from("constructor")),
+                    )),
+                )?));
+            }
+            // Parse method:
+            // Check if the current token is a func.
+            else if parser.current_token_is(Token::Func)? {
+                // Append the method to the methods list.
+                methods.push((is_public, parse(parser)?));
+            }
+            // Parse field:
+            else {
+                // Parse expression:
+                // Get the current field expression.
+                let field = expressions::parse(parser, Precedence::Lowest)?;
+
+                // Check if the field is not an argument expression.
+                if field.node.get_argument().is_none() {
+                    return Err(Error::new_expect_token(
+                        field.get_position(),
+                        "Argument",
+                        &field.to_string(),
+                    ));
+                }
+
+                // Append the field to the fields list.
+                fields.push((is_public, field));
+
+                // Check if the next token is a semicolon and read the next token.
+                parser.expect_token(Token::Semicolon)?;
+            }
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Ignore the end of lines.
+            parser.skip_eol()?;
+        }
+
+        Ok(Statement::new(
+            class_position,
+            Statements::Class {
+                name: class_name,
+                fields,
+                constructor,
+                methods,
+            },
+        ))
+    }
+    // Parse enum:
+    // Check if the current token is an enum.
+    else if parser.current_token_is(Token::Enum)? {
+        // Get the current token position as the enum position.
+        let enum_position = parser.get_current_token()?.get_position();
+
+        // Check if the next token is not an identifier.
+        if parser
+            .get_next_token()?
+            .get_token()
+            .get_identifier()
+            .is_none()
+        {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "Identifier",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Get the current token as the enum name.
+        let enum_name = parser
+            .get_current_token()?
+            .get_token()
+            .get_identifier()
+            .unwrap();
+
+        // Check if the next token is not a left brace.
+        if !parser.expect_token(Token::LeftBrace)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "{",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Ignore the end of lines.
+        parser.skip_eol()?;
+
+        // Initialize the enum variants list.
+        let mut variants: Vec<Variant> = Vec::new();
+
+        while !parser.current_token_is(Token::RightBrace)? {
+            // Get the current token as the variant name.
+            let variant_name = match parser
+                .get_current_token()?
+                .get_token()
+                .get_identifier()
+            {
+                Some(variant_name) => variant_name,
+                None => {
+                    return Err(Error::new_expect_token(
+                        parser.get_current_token()?.get_position(),
+                        "Identifier",
+                        &parser.get_current_token()?.get_token().to_string(),
+                    ))
+                }
+            };
+
+            // Initialize the variant arguments list.
+            let mut variant_arguments: Vec<Expression> = Vec::new();
+
+            // Check if the next token is a left parentheses and read the next token.
+            if parser.expect_token(Token::LeftParentheses)? {
+                // Read the next token.
+                parser.read_next_token()?;
+
+                while !parser.current_token_is(Token::RightParentheses)? {
+                    // Parse expression:
+                    // Get the current argument expression.
+                    let variant_argument =
+                        expressions::parse(parser, Precedence::Lowest)?;
+
+                    // Check if the argument is not an argument without value.
+                    match variant_argument.node.get_argument() {
+                        Some((_, _, Some(value))) => {
+                            return Err(Error::new_lexical(
+                                value.get_position(),
+                                "Cannot set a value here.",
+                            ));
+                        }
+                        Some(_) => variant_arguments.push(variant_argument),
+                        None => {
+                            return Err(Error::new_expect_token(
+                                variant_argument.get_position(),
+                                "Argument",
+                                &variant_argument.to_string(),
+                            ));
+                        }
+                    }
+
+                    // Check if the next token is a comma and read the next token.
+                    parser.expect_token(Token::Comma)?;
+
+                    // Read the next token.
+                    parser.read_next_token()?;
+                }
+            }
+
+            // Append the variant to the variants list.
+            variants.push((variant_name, variant_arguments));
+
+            // Check if the next token is a comma and read the next token.
+            parser.expect_token(Token::Comma)?;
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Ignore the end of lines.
+            parser.skip_eol()?;
+        }
+
+        Ok(Statement::new(
+            enum_position,
+            Statements::Enum(enum_name, variants),
         ))
     }
     // Parse return:
@@ -240,6 +816,93 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
             Statements::Return(return_value),
         ))
     }
+    // Parse impl:
+    // Check if the current token is an impl.
+    else if parser.current_token_is(Token::Impl)? {
+        // Get the current token position as the impl position.
+        let impl_position = parser.get_current_token()?.get_position();
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Parse data type:
+        // Get the data type that implements the methods.
+        let impl_data_type = data_types::parse(parser)?;
+
+        // Parse body statements:
+        // Get the methods between braces.
+        let impl_methods = parse_body(parser)?;
+
+        // Check if every statement is a function.
+        for impl_method in impl_methods.iter() {
+            if impl_method.node.get_function().is_none() {
+                return Err(Error::new_expect_token(
+                    impl_method.get_position(),
+                    "func",
+                    &impl_method.to_string(),
+                ));
+            }
+        }
+
+        Ok(Statement::new(
+            impl_position,
+            Statements::Impl(impl_data_type, impl_methods),
+        ))
+    }
+    // Parse type alias:
+    // Check if the current token is a type.
+    else if parser.current_token_is(Token::Type)? {
+        // Get the current token position as the type alias position.
+        let alias_position = parser.get_current_token()?.get_position();
+
+        // Check if the next token is not an identifier.
+        if parser
+            .get_next_token()?
+            .get_token()
+            .get_identifier()
+            .is_none()
+        {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "Identifier",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Get the current token as the type alias name.
+        let alias_name = parser
+            .get_current_token()?
+            .get_token()
+            .get_identifier()
+            .unwrap();
+
+        // Check if the next token is not an equal.
+        if !parser.expect_token(Token::Equal)? {
+            return Err(Error::new_expect_token(
+                parser.get_next_token()?.get_position(),
+                "=",
+                &parser.get_next_token()?.get_token().to_string(),
+            ));
+        }
+
+        // Read the next token.
+        parser.read_next_token()?;
+
+        // Parse data type:
+        // Get the type alias data type.
+        let alias_data_type = data_types::parse(parser)?;
+
+        // Check if the next token is a semicolon and read the next token.
+        parser.expect_token(Token::Semicolon)?;
+
+        Ok(Statement::new(
+            alias_position,
+            Statements::TypeAlias(alias_name, alias_data_type),
+        ))
+    }
     // Parse variables:
     // Check if the current token is a let or a const.
     else if parser.current_token_is(Token::Let)?
@@ -254,6 +917,61 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
         // Read the next token.
         parser.read_next_token()?;
 
+        // Parse destructuring:
+        // Check if the current token is a left bracket, a left brace or a left
+        // parentheses.
+        if parser.current_token_is(Token::LeftBracket)?
+            || parser.current_token_is(Token::LeftBrace)?
+            || parser.current_token_is(Token::LeftParentheses)?
+        {
+            // Parse pattern:
+            // Get the variable pattern.
+            let variable_pattern = patterns::parse(parser)?;
+
+            // Initialize the variable data type.
+            let mut variable_data_type: Option<DataType> = None;
+
+            // Check if the next token is a colon and read the next token.
+            if parser.expect_token(Token::Colon)? {
+                // Read the next token.
+                parser.read_next_token()?;
+
+                // Parse data type:
+                // Get the variable data type.
+                variable_data_type = Some(data_types::parse(parser)?);
+            }
+
+            // Check if the next token is not an equal.
+            if !parser.expect_token(Token::Equal)? {
+                return Err(Error::new_expect_token(
+                    parser.get_next_token()?.get_position(),
+                    "=",
+                    &parser.get_next_token()?.get_token().to_string(),
+                ));
+            }
+
+            // Read the next token.
+            parser.read_next_token()?;
+
+            // Parse expression:
+            // Get the variable value.
+            let variable_value =
+                expressions::parse(parser, Precedence::Lowest)?;
+
+            // Check if the next token is a semicolon and read the next token.
+            parser.expect_token(Token::Semicolon)?;
+
+            return Ok(Statement::new(
+                variable_position,
+                Statements::Variable {
+                    is_mutable: variable_mutable,
+                    pattern: variable_pattern,
+                    data_type: variable_data_type,
+                    value: Some(variable_value),
+                },
+            ));
+        }
+
         let variable_data = expressions::parse(parser, Precedence::Lowest)?;
 
         // Check if the next token is a semicolon and read the next token.
@@ -266,7 +984,10 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
                 variable_position,
                 Statements::Variable {
                     is_mutable: variable_mutable,
-                    name: identifier,
+                    pattern: Pattern::new(
+                        variable_data.get_position(),
+                        Patterns::Identifier(identifier),
+                    ),
                     data_type: Some(*data_type),
                     value: if let Some(value) = value {
                         Some(*value)
@@ -275,6 +996,32 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
                     },
                 },
             ))
+        }
+        // Parse variable without data type:
+        // Check if the variable data is an assignment to an identifier.
+        else if let Some((identifier, Token::Equal, value)) =
+            variable_data.node.get_assignment()
+        {
+            if let Some(identifier_value) = identifier.node.get_identifier() {
+                Ok(Statement::new(
+                    variable_position,
+                    Statements::Variable {
+                        is_mutable: variable_mutable,
+                        pattern: Pattern::new(
+                            identifier.get_position(),
+                            Patterns::Identifier(identifier_value),
+                        ),
+                        data_type: None,
+                        value: Some(*value),
+                    },
+                ))
+            } else {
+                Err(Error::new_expect_token(
+                    identifier.get_position(),
+                    "Identifier",
+                    &identifier.to_string(),
+                ))
+            }
         } else {
             Err(Error::new_expect_token(
                 variable_data.get_position(),
@@ -286,12 +1033,19 @@ pub fn parse(parser: &mut Parser) -> Result<Statement, Error> {
     // Parse expression:
     // See `/parser/parser/expressions.rs` for more information.
     else {
+        // Get the current token position as the expression position.
+        let expression_position = parser.get_current_token()?.get_position();
+
+        // Parse expression:
+        // Get the current expression.
+        let expression = expressions::parse(parser, Precedence::Lowest)?;
+
+        // Check if the next token is a semicolon and read the next token.
+        parser.expect_token(Token::Semicolon)?;
+
         Ok(Statement::new(
-            parser.get_current_token()?.get_position(),
-            Statements::Expression(Box::new(expressions::parse(
-                parser,
-                Precedence::Lowest,
-            )?)),
+            expression_position,
+            Statements::Expression(Box::new(expression)),
         ))
     }
 }
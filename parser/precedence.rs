@@ -5,15 +5,17 @@ use std::fmt;
 pub enum Precedence {
     Lowest = 0,
     Assignment = 1,
-    Equals = 2,
-    LessGreater = 3,
-    Sum = 4,
-    Product = 5,
-    Exponent = 6,
-    Prefix = 7,
-    Call = 8,
-    Index = 9,
-    Method = 10,
+    Pipeline = 2,
+    Conditional = 3,
+    Equals = 4,
+    LessGreater = 5,
+    Sum = 6,
+    Product = 7,
+    Exponent = 8,
+    Prefix = 9,
+    Call = 10,
+    Index = 11,
+    Method = 12,
 }
 
 impl Precedence {
@@ -38,6 +40,10 @@ impl Precedence {
             | Token::PercentEqual
             | Token::DoubleStarEqual => Self::Assignment,
 
+            Token::VBarGreater => Self::Pipeline,
+
+            Token::Question => Self::Conditional,
+
             Token::DoubleEqual
             | Token::NotEqual
             | Token::LessEqual
@@ -66,6 +72,8 @@ impl Precedence {
         (match self {
             Self::Lowest => "Lowest",
             Self::Assignment => "Assignment",
+            Self::Pipeline => "Pipeline",
+            Self::Conditional => "Conditional",
             Self::Equals => "Equals",
             Self::LessGreater => "LessGreater",
             Self::Sum => "Sum",
@@ -120,6 +128,9 @@ fn test_precedence() {
     equal_precedence!(Token::If, Precedence::Lowest);
     equal_precedence!(Token::Else, Precedence::Lowest);
 
+    equal_precedence!(Token::Try, Precedence::Lowest);
+    equal_precedence!(Token::Catch, Precedence::Lowest);
+
     equal_precedence!(Token::Interface, Precedence::Lowest);
 
     equal_precedence!(Token::Boolean, Precedence::Lowest);
@@ -127,6 +138,8 @@ fn test_precedence() {
     equal_precedence!(Token::Number, Precedence::Lowest);
     equal_precedence!(Token::Void, Precedence::Lowest);
 
+    equal_precedence!(Token::TripleDot, Precedence::Lowest);
+    equal_precedence!(Token::VBar, Precedence::Lowest);
     equal_precedence!(Token::Comma, Precedence::Lowest);
     equal_precedence!(Token::Colon, Precedence::Lowest);
     equal_precedence!(Token::Semicolon, Precedence::Lowest);
@@ -155,6 +168,12 @@ fn test_precedence() {
     equal_precedence!(Token::PercentEqual, Precedence::Assignment);
     equal_precedence!(Token::DoubleStarEqual, Precedence::Assignment);
 
+    // Pipeline
+    equal_precedence!(Token::VBarGreater, Precedence::Pipeline);
+
+    // Conditional
+    equal_precedence!(Token::Question, Precedence::Conditional);
+
     // Equals
     equal_precedence!(Token::DoubleEqual, Precedence::Equals);
     equal_precedence!(Token::NotEqual, Precedence::Equals);
@@ -10,6 +10,7 @@ pub enum Token {
 
     True,
     False,
+    Null,
 
     // Keywords
     Let,
@@ -18,10 +19,25 @@ pub enum Token {
     Func,
     Return,
 
+    Class,
+    Constructor,
+    Public,
+    Private,
+
     If,
     Else,
+    Match,
+
+    Try,
+    Catch,
 
     Interface,
+    Extends,
+    Impl,
+    Enum,
+
+    Type,
+    Typeof,
 
     // Data Types
     Boolean,
@@ -31,9 +47,11 @@ pub enum Token {
 
     // Signs
     Dot,
+    TripleDot,
     Comma,
     Colon,
     Semicolon,
+    Question,
 
     Equal,
     DoubleEqual,
@@ -74,8 +92,10 @@ pub enum Token {
     LeftBracket,
     RightBracket,
 
+    VBar,
     DoubleVBar,
     DoubleAmper,
+    VBarGreater,
 
     // Other
     EndOfLine,
@@ -86,6 +106,11 @@ impl Token {
     /// Get the keyword if exists in the value or return it as an identifier.
     pub fn get_identifier_or_keyword(value: String) -> Token {
         match value.as_str() {
+            // General
+            "true" => Token::True,
+            "false" => Token::False,
+            "null" => Token::Null,
+
             // Keywords
             "let" => Token::Let,
             "const" => Token::Const,
@@ -93,10 +118,25 @@ impl Token {
             "func" => Token::Func,
             "return" => Token::Return,
 
+            "class" => Token::Class,
+            "constructor" => Token::Constructor,
+            "public" => Token::Public,
+            "private" => Token::Private,
+
             "if" => Token::If,
             "else" => Token::Else,
+            "match" => Token::Match,
+
+            "try" => Token::Try,
+            "catch" => Token::Catch,
 
             "interface" => Token::Interface,
+            "extends" => Token::Extends,
+            "impl" => Token::Impl,
+            "enum" => Token::Enum,
+
+            "type" => Token::Type,
+            "typeof" => Token::Typeof,
 
             // Data Types
             "boolean" => Token::Boolean,
@@ -140,6 +180,7 @@ impl Token {
 
             Self::True => String::from("true"),
             Self::False => String::from("false"),
+            Self::Null => String::from("null"),
 
             // Keywords
             Self::Let => String::from("let"),
@@ -148,10 +189,25 @@ impl Token {
             Self::Func => String::from("func"),
             Self::Return => String::from("return"),
 
+            Self::Class => String::from("class"),
+            Self::Constructor => String::from("constructor"),
+            Self::Public => String::from("public"),
+            Self::Private => String::from("private"),
+
             Self::If => String::from("if"),
             Self::Else => String::from("else"),
+            Self::Match => String::from("match"),
+
+            Self::Try => String::from("try"),
+            Self::Catch => String::from("catch"),
 
             Self::Interface => String::from("interface"),
+            Self::Extends => String::from("extends"),
+            Self::Impl => String::from("impl"),
+            Self::Enum => String::from("enum"),
+
+            Self::Type => String::from("type"),
+            Self::Typeof => String::from("typeof"),
 
             // Data Types
             Self::Boolean => String::from("boolean"),
@@ -161,9 +217,11 @@ impl Token {
 
             // Signs
             Self::Dot => String::from("."),
+            Self::TripleDot => String::from("..."),
             Self::Comma => String::from(","),
             Self::Colon => String::from(":"),
             Self::Semicolon => String::from(";"),
+            Self::Question => String::from("?"),
 
             Self::Equal => String::from("="),
             Self::DoubleEqual => String::from("=="),
@@ -204,8 +262,10 @@ impl Token {
             Self::LeftBracket => String::from("["),
             Self::RightBracket => String::from("]"),
 
+            Self::VBar => String::from("|"),
             Self::DoubleVBar => String::from("||"),
             Self::DoubleAmper => String::from("&&"),
+            Self::VBarGreater => String::from("|>"),
 
             // Other
             Self::EndOfLine => String::from("\n"),